@@ -59,7 +59,7 @@ fn paused_blocks_write_paths() {
     assert!(client.try_pause(&admin).is_ok());
 
     // For functions returning Result<(), SavingsError>
-    match client.try_initialize_user(&user) {
+    match client.try_initialize_user(&user, &None) {
         Err(Ok(e)) => assert_eq!(e, SavingsError::ContractPaused),
         _ => panic!("Expected ContractPaused"),
     }
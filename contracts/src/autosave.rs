@@ -1,15 +1,33 @@
 use crate::errors::SavingsError;
 use crate::flexi;
-use crate::storage_types::{AutoSave, DataKey};
+use crate::goal;
+use crate::governance;
+use crate::group;
+use crate::keeper;
+use crate::lock;
+use crate::storage_types::{
+    AutoSave, AutoSaveCursor, AutoSaveDuePage, AutoSaveTarget, CatchUpPolicy, DataKey,
+    KeeperFeeSource, LegacyAutoSave,
+};
 use crate::ttl;
 use crate::users;
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{
+    panic_with_error, symbol_short, Address, Env, Map, Symbol, TryFromVal, Val, Vec,
+};
 
-/// Creates a new AutoSave schedule for recurring Flexi deposits
+/// Consecutive batch failures tolerated before a schedule is deactivated,
+/// unless overridden with `set_autosave_failure_threshold`
+pub const DEFAULT_MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Width of a due-index bucket in seconds
+pub const DUE_BUCKET_SECONDS: u64 = 3600;
+
+/// Creates a new AutoSave schedule for recurring deposits
 ///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `user` - The user creating the schedule
+/// * `target` - The plan that receives each deposit (Flexi, Goal, Group or a new Lock)
 /// * `amount` - The amount to deposit on each execution (must be > 0)
 /// * `interval_seconds` - How often the schedule runs in seconds (must be > 0)
 /// * `start_time` - Unix timestamp for the first execution
@@ -20,6 +38,7 @@ use soroban_sdk::{Address, Env, Vec};
 pub fn create_autosave(
     env: &Env,
     user: Address,
+    target: AutoSaveTarget,
     amount: i128,
     interval_seconds: u64,
     start_time: u64,
@@ -41,6 +60,9 @@ pub fn create_autosave(
         return Err(SavingsError::UserNotFound);
     }
 
+    // Ensure the target plan exists and accepts deposits from this user
+    validate_target(env, &user, &target)?;
+
     // Generate unique schedule ID
     let schedule_id = get_next_schedule_id(env);
 
@@ -52,12 +74,18 @@ pub fn create_autosave(
         interval_seconds,
         next_execution_time: start_time,
         is_active: true,
+        target,
+        last_keeper: None,
+        is_paused: false,
+        runs_completed: 0,
+        max_runs: 0,
+        end_time: 0,
+        catch_up: CatchUpPolicy::SkipMissed,
+        consecutive_failures: 0,
     };
 
-    // Store the schedule
-    env.storage()
-        .persistent()
-        .set(&DataKey::AutoSave(schedule_id), &schedule);
+    // Store the schedule and add it to the due index
+    save_schedule(env, &schedule);
 
     // Link schedule to user
     add_schedule_to_user(env, &user, schedule_id);
@@ -74,23 +102,23 @@ pub fn create_autosave(
 
 /// Executes an AutoSave schedule if it's due
 ///
+/// If the schedule's target has been completed or closed since the schedule was
+/// created, no deposit is made and the schedule is deactivated instead. Late
+/// executions follow the schedule's `CatchUpPolicy`.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `schedule_id` - The ID of the schedule to execute
 ///
 /// # Returns
-/// * `Ok(())` - If execution succeeds
-/// * `Err(SavingsError)` - If the schedule is not found, inactive, or not yet due
+/// * `Ok(())` - If execution succeeds or the schedule was deactivated
+/// * `Err(SavingsError)` - If the schedule is not found, inactive, paused, or not yet due
 pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError> {
     // Fetch the schedule
-    let mut schedule: AutoSave = env
-        .storage()
-        .persistent()
-        .get(&DataKey::AutoSave(schedule_id))
-        .ok_or(SavingsError::PlanNotFound)?;
+    let mut schedule = read_autosave(env, schedule_id).ok_or(SavingsError::PlanNotFound)?;
 
-    // Ensure schedule is active
-    if !schedule.is_active {
+    // Ensure schedule is active and not paused
+    if !schedule.is_active || schedule.is_paused {
         return Err(SavingsError::InvalidPlanConfig);
     }
 
@@ -100,19 +128,33 @@ pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError>
         return Err(SavingsError::InvalidTimestamp);
     }
 
-    // Perform Flexi deposit
-    flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount)?;
+    // Stop the schedule if its target no longer accepts deposits
+    if !target_accepts_deposits(env, &schedule) {
+        deactivate_schedule(env, &mut schedule, symbol_short!("closed"));
+        save_schedule(env, &schedule);
+        return Ok(());
+    }
 
-    // Update next execution time
-    schedule.next_execution_time += schedule.interval_seconds;
+    // Stop the schedule if the due slot lies past its end date
+    let runs = runs_due(&schedule, current_time);
+    if runs == 0 {
+        deactivate_schedule(env, &mut schedule, symbol_short!("end_time"));
+        save_schedule(env, &schedule);
+        return Ok(());
+    }
 
-    // Save updated schedule
-    env.storage()
-        .persistent()
-        .set(&DataKey::AutoSave(schedule_id), &schedule);
+    // Perform the deposit into the target plan
+    let deposit_amount = schedule
+        .amount
+        .checked_mul(runs as i128)
+        .ok_or(SavingsError::Overflow)?;
+    deposit_to_target(env, &schedule, deposit_amount)?;
 
-    // Extend TTL on execution (active schedule gets full extension)
-    ttl::extend_autosave_ttl(env, schedule_id);
+    // Advance to the next slot and apply end conditions
+    finish_run(env, &mut schedule, runs, current_time);
+
+    // Save updated schedule (also extends its TTL and moves it in the due index)
+    save_schedule(env, &schedule);
 
     Ok(())
 }
@@ -126,19 +168,31 @@ pub fn execute_autosave(env: &Env, schedule_id: u64) -> Result<(), SavingsError>
 /// # Arguments
 /// * `env` - The contract environment
 /// * `schedule_ids` - A vector of schedule IDs to attempt execution on
+/// * `keeper` - The caller to credit with executions and bounties, if any
 ///
 /// # Returns
 /// A `Vec<bool>` where each element corresponds to the schedule at the same
 /// index in `schedule_ids`:
 /// - `true`  — the schedule was due and executed successfully
-/// - `false` — the schedule was skipped (not found, inactive, not yet due, or deposit failed)
+/// - `false` — the schedule was skipped (not found, inactive, paused, not yet due,
+///   target closed, or deposit failed)
 ///
 /// # Guarantees
 /// - One failed or skipped schedule does **not** revert the entire batch.
 /// - Only schedules whose `next_execution_time <= current_ledger_timestamp` are executed.
-/// - For each executed schedule, a Flexi deposit is performed and `next_execution_time` is
-///   advanced by `interval_seconds`.
-pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
+/// - For each executed schedule, a deposit into its target is performed and
+///   `next_execution_time` is moved to the next future slot; missed slots are
+///   made up according to the schedule's `CatchUpPolicy`.
+/// - Schedules that reach `max_runs` or pass `end_time` are deactivated.
+/// - Failed deposits are counted; a schedule is deactivated after the configured
+///   number of consecutive failures.
+/// - Schedules whose target has been completed or closed are deactivated.
+/// - A keeper bounty is only paid for schedules that were due and executed.
+pub fn execute_due_autosaves(
+    env: &Env,
+    schedule_ids: Vec<u64>,
+    keeper: Option<&Address>,
+) -> Vec<bool> {
     let current_time = env.ledger().timestamp();
     let mut results = Vec::new(env);
 
@@ -146,12 +200,9 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
         let schedule_id = schedule_ids.get(i).unwrap();
 
         // Attempt to fetch the schedule; skip if not found
-        let maybe_schedule: Option<AutoSave> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AutoSave(schedule_id));
+        let maybe_schedule = read_autosave(env, schedule_id);
 
-        let schedule = match maybe_schedule {
+        let mut schedule = match maybe_schedule {
             Some(s) => s,
             None => {
                 results.push_back(false);
@@ -159,8 +210,8 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
             }
         };
 
-        // Skip inactive schedules
-        if !schedule.is_active {
+        // Skip inactive or paused schedules
+        if !schedule.is_active || schedule.is_paused {
             results.push_back(false);
             continue;
         }
@@ -171,21 +222,62 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
             continue;
         }
 
-        // Attempt the Flexi deposit; if it fails, mark as false and continue
-        let deposit_result =
-            flexi::flexi_deposit(env.clone(), schedule.user.clone(), schedule.amount);
+        // Deactivate schedules whose target was completed or closed
+        if !target_accepts_deposits(env, &schedule) {
+            deactivate_schedule(env, &mut schedule, symbol_short!("closed"));
+            save_schedule(env, &schedule);
+            results.push_back(false);
+            continue;
+        }
+
+        // Deactivate schedules whose due slot lies past their end date
+        let runs = runs_due(&schedule, current_time);
+        if runs == 0 {
+            deactivate_schedule(env, &mut schedule, symbol_short!("end_time"));
+            save_schedule(env, &schedule);
+            results.push_back(false);
+            continue;
+        }
+
+        // Work out the keeper bounty before depositing; `bounty_for` only grants one the
+        // pool can cover, and a deposit-funded bounty is withheld from the deposit
+        let (bounty, source) = match keeper {
+            Some(keeper) => keeper::bounty_for(env, keeper, &schedule),
+            None => (0, KeeperFeeSource::ProtocolPool),
+        };
+        let deposit_amount = match schedule.amount.checked_mul(runs as i128) {
+            Some(total) if source == KeeperFeeSource::UserDeposit => Some(total - bounty),
+            total => total,
+        };
+
+        // Attempt the deposit; if it fails, record the failure and continue
+        let deposit_result = match deposit_amount {
+            Some(amount) => deposit_to_target(env, &schedule, amount),
+            None => Err(SavingsError::Overflow),
+        };
 
-        if deposit_result.is_err() {
+        if let Err(err) = deposit_result {
+            record_failure(env, &mut schedule, err);
             results.push_back(false);
             continue;
         }
 
         // Update next execution time and persist
         let mut updated_schedule = schedule.clone();
-        updated_schedule.next_execution_time += updated_schedule.interval_seconds;
-        env.storage()
-            .persistent()
-            .set(&DataKey::AutoSave(schedule_id), &updated_schedule);
+        finish_run(env, &mut updated_schedule, runs, current_time);
+        if let Some(keeper) = keeper {
+            updated_schedule.last_keeper = Some(keeper.clone());
+        }
+        save_schedule(env, &updated_schedule);
+
+        // Pay the keeper and update its execution tally. The bounty was checked above,
+        // so only an overflow can fail here; it reverts the batch rather than report
+        // `false` for a schedule that was already deposited
+        if let Some(keeper) = keeper {
+            if let Err(err) = keeper::record_execution(env, keeper, schedule_id, bounty, source) {
+                panic_with_error!(env, err);
+            }
+        }
 
         results.push_back(true);
     }
@@ -204,37 +296,276 @@ pub fn execute_due_autosaves(env: &Env, schedule_ids: Vec<u64>) -> Vec<bool> {
 /// * `Ok(())` - If cancellation succeeds
 /// * `Err(SavingsError)` - If the schedule is not found or user is not the owner
 pub fn cancel_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
-    user.require_auth();
-
-    // Fetch the schedule
-    let mut schedule: AutoSave = env
-        .storage()
-        .persistent()
-        .get(&DataKey::AutoSave(schedule_id))
-        .ok_or(SavingsError::PlanNotFound)?;
-
-    // Ensure caller owns the schedule
-    if schedule.user != user {
-        return Err(SavingsError::Unauthorized);
-    }
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
 
     // Deactivate the schedule
     schedule.is_active = false;
 
     // Save updated schedule
+    save_schedule(env, &schedule);
+
+    Ok(())
+}
+
+/// Pauses an AutoSave schedule without losing its ID or settings
+///
+/// # Errors
+/// * `PlanNotFound` / `Unauthorized` - If the schedule is missing or not owned by `user`
+/// * `InvalidPlanConfig` - If the schedule has been cancelled or has ended
+pub fn pause_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
+    if !schedule.is_active {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    schedule.is_paused = true;
+    save_schedule(env, &schedule);
+
+    env.events()
+        .publish((symbol_short!("as_pause"), user, schedule_id), ());
+    Ok(())
+}
+
+/// Resumes a paused AutoSave schedule
+///
+/// Missed intervals are not replayed: `next_execution_time` is moved forward to
+/// the first slot at or after the current ledger time.
+///
+/// # Errors
+/// * `PlanNotFound` / `Unauthorized` - If the schedule is missing or not owned by `user`
+/// * `InvalidPlanConfig` - If the schedule is not paused or is no longer active
+pub fn resume_autosave(env: &Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
+    if !schedule.is_active || !schedule.is_paused {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+
+    schedule.is_paused = false;
+    schedule.next_execution_time = next_slot_from(
+        schedule.next_execution_time,
+        schedule.interval_seconds,
+        env.ledger().timestamp(),
+    );
+    save_schedule(env, &schedule);
+
+    env.events().publish(
+        (symbol_short!("as_resume"), user, schedule_id),
+        schedule.next_execution_time,
+    );
+    Ok(())
+}
+
+/// Changes the deposit amount and interval of an AutoSave schedule
+///
+/// The next execution time is kept; the new interval applies from the following run.
+///
+/// # Errors
+/// * `PlanNotFound` / `Unauthorized` - If the schedule is missing or not owned by `user`
+/// * `InvalidAmount` - If amount <= 0
+/// * `InvalidTimestamp` - If interval_seconds == 0
+pub fn amend_autosave(
+    env: &Env,
+    user: Address,
+    schedule_id: u64,
+    amount: i128,
+    interval_seconds: u64,
+) -> Result<(), SavingsError> {
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
+
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if interval_seconds == 0 {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    schedule.amount = amount;
+    schedule.interval_seconds = interval_seconds;
+    save_schedule(env, &schedule);
+
+    env.events()
+        .publish((symbol_short!("as_amend"), user, schedule_id), amount);
+    Ok(())
+}
+
+/// Sets when an AutoSave schedule ends
+///
+/// # Arguments
+/// * `max_runs` - Total runs after which the schedule ends (0 = unlimited)
+/// * `end_time` - Timestamp after which no run is scheduled (0 = no end date)
+///
+/// # Errors
+/// * `PlanNotFound` / `Unauthorized` - If the schedule is missing or not owned by `user`
+/// * `InvalidPlanConfig` - If `max_runs` is already reached
+/// * `InvalidTimestamp` - If `end_time` is in the past
+pub fn set_autosave_end(
+    env: &Env,
+    user: Address,
+    schedule_id: u64,
+    max_runs: u32,
+    end_time: u64,
+) -> Result<(), SavingsError> {
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
+
+    if max_runs > 0 && max_runs <= schedule.runs_completed {
+        return Err(SavingsError::InvalidPlanConfig);
+    }
+    if end_time > 0 && end_time < env.ledger().timestamp() {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    schedule.max_runs = max_runs;
+    schedule.end_time = end_time;
+    save_schedule(env, &schedule);
+    Ok(())
+}
+
+/// Sets how an AutoSave schedule handles missed slots when executed late
+///
+/// # Errors
+/// * `PlanNotFound` / `Unauthorized` - If the schedule is missing or not owned by `user`
+pub fn set_autosave_catch_up(
+    env: &Env,
+    user: Address,
+    schedule_id: u64,
+    policy: CatchUpPolicy,
+) -> Result<(), SavingsError> {
+    let mut schedule = get_owned_schedule(env, &user, schedule_id)?;
+    schedule.catch_up = policy;
+    save_schedule(env, &schedule);
+    Ok(())
+}
+
+/// Sets how many consecutive batch failures deactivate a schedule. Admin only
+/// until governance is active, then only through a proposal.
+///
+/// # Errors
+/// * `Unauthorized` - If the caller is not the admin or governance is active
+/// * `InvalidAmount` - If the threshold is zero
+pub fn set_failure_threshold(
+    env: &Env,
+    caller: Address,
+    threshold: u32,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_failure_threshold_unchecked(env, threshold)
+}
+
+/// Sets the failure threshold without the admin check; used by governance
+pub(crate) fn set_failure_threshold_unchecked(
+    env: &Env,
+    threshold: u32,
+) -> Result<(), SavingsError> {
+    if threshold == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::AutoSaveMaxFailures, &threshold);
+    env.events()
+        .publish((symbol_short!("set_asfl"),), threshold);
+    Ok(())
+}
+
+/// Returns the consecutive-failure threshold for AutoSave schedules
+pub fn get_failure_threshold(env: &Env) -> u32 {
     env.storage()
+        .instance()
+        .get(&DataKey::AutoSaveMaxFailures)
+        .unwrap_or(DEFAULT_MAX_CONSECUTIVE_FAILURES)
+}
+
+/// Lists active, unpaused schedules whose `next_execution_time <= now`
+///
+/// Schedules are indexed by due-time bucket, alongside a sorted list of the
+/// buckets that hold any. A page walks that list from the cursor up to
+/// `now`'s bucket, so every bucket read is non-empty and only the last one
+/// can hold schedules not yet due; the schedules themselves are never read.
+/// Results come in due-time bucket order. Executing schedules shifts bucket
+/// offsets, so keepers should restart from the default cursor after executing
+/// a page.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `now` - Timestamp to evaluate against (usually the current ledger time)
+/// * `cursor` - The default cursor for the first page, then the previous page's `next_cursor`
+/// * `limit` - Maximum number of IDs to return; 0 returns an empty, final page
+pub fn get_due_autosaves(
+    env: &Env,
+    now: u64,
+    cursor: AutoSaveCursor,
+    limit: u32,
+) -> AutoSaveDuePage {
+    let mut schedule_ids = Vec::new(env);
+    let buckets = get_due_buckets(env);
+    let last_bucket = now / DUE_BUCKET_SECONDS;
+
+    let start = match buckets.binary_search(cursor.bucket) {
+        Ok(i) | Err(i) => i,
+    };
+    for i in start..buckets.len() {
+        let bucket = buckets.get(i).unwrap();
+        if bucket > last_bucket || limit == 0 {
+            break;
+        }
+        let offset = if bucket == cursor.bucket {
+            cursor.offset
+        } else {
+            0
+        };
+
+        let entries = get_due_bucket(env, bucket);
+        for j in offset..entries.len() {
+            if schedule_ids.len() == limit {
+                return AutoSaveDuePage {
+                    schedule_ids,
+                    next_cursor: AutoSaveCursor { bucket, offset: j },
+                    has_more: true,
+                };
+            }
+            let (schedule_id, due_time) = entries.get(j).unwrap();
+            if due_time <= now {
+                schedule_ids.push_back(schedule_id);
+            }
+        }
+    }
+
+    AutoSaveDuePage {
+        schedule_ids,
+        next_cursor: AutoSaveCursor::default(),
+        has_more: false,
+    }
+}
+
+/// Reads a stored schedule.
+///
+/// Schedules stored as `LegacyAutoSave` are read as unpaused, unlimited Flexi
+/// schedules. They join the due index the next time they are saved.
+pub fn read_autosave(env: &Env, schedule_id: u64) -> Option<AutoSave> {
+    let raw: Map<Symbol, Val> = env
+        .storage()
         .persistent()
-        .set(&DataKey::AutoSave(schedule_id), &schedule);
+        .get(&DataKey::AutoSave(schedule_id))?;
+    if is_legacy(&raw) {
+        LegacyAutoSave::try_from_val(env, &raw.to_val())
+            .ok()
+            .map(AutoSave::from)
+    } else {
+        AutoSave::try_from_val(env, &raw.to_val()).ok()
+    }
+}
 
-    Ok(())
+/// Whether a stored schedule predates `AutoSave::target`. Checked up front
+/// because decoding a struct from a map with other fields traps.
+fn is_legacy(raw: &Map<Symbol, Val>) -> bool {
+    !raw.contains_key(symbol_short!("target"))
 }
 
 /// Gets an AutoSave schedule by ID
 pub fn get_autosave(env: &Env, schedule_id: u64) -> Option<AutoSave> {
-    let schedule = env
-        .storage()
-        .persistent()
-        .get(&DataKey::AutoSave(schedule_id));
+    let schedule = read_autosave(env, schedule_id);
 
     if schedule.is_some() {
         // Extend TTL on read
@@ -263,6 +594,257 @@ pub fn get_user_autosaves(env: &Env, user: &Address) -> Vec<u64> {
 
 // ========== Helper Functions ==========
 
+/// Checks that a target can receive deposits from `user` when a schedule is created
+fn validate_target(env: &Env, user: &Address, target: &AutoSaveTarget) -> Result<(), SavingsError> {
+    match target {
+        AutoSaveTarget::Flexi => Ok(()),
+        AutoSaveTarget::Goal(goal_id) => {
+            let goal_save = goal::get_goal_save(env, *goal_id).ok_or(SavingsError::PlanNotFound)?;
+            if goal_save.owner != *user {
+                return Err(SavingsError::Unauthorized);
+            }
+            if goal_save.is_completed || goal_save.is_withdrawn {
+                return Err(SavingsError::PlanCompleted);
+            }
+            Ok(())
+        }
+        AutoSaveTarget::Group(group_id) => {
+            let group_save =
+                group::get_group_save(env, *group_id).ok_or(SavingsError::PlanNotFound)?;
+            if !group::get_group_members(env, *group_id).contains(user) {
+                return Err(SavingsError::NotGroupMember);
+            }
+            if group_save.is_completed {
+                return Err(SavingsError::PlanCompleted);
+            }
+            Ok(())
+        }
+        AutoSaveTarget::NewLock(duration) => {
+            if *duration == 0 {
+                return Err(SavingsError::InvalidTimestamp);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns false once the schedule's target has been completed, withdrawn or closed
+fn target_accepts_deposits(env: &Env, schedule: &AutoSave) -> bool {
+    validate_target(env, &schedule.user, &schedule.target).is_ok()
+}
+
+/// Performs a single deposit of `amount` into the schedule's target
+fn deposit_to_target(env: &Env, schedule: &AutoSave, amount: i128) -> Result<(), SavingsError> {
+    let user = schedule.user.clone();
+    match schedule.target {
+        AutoSaveTarget::Flexi => flexi::flexi_deposit(env.clone(), user, amount),
+        AutoSaveTarget::Goal(goal_id) => goal::deposit_to_goal_save(env, user, goal_id, amount),
+        AutoSaveTarget::Group(group_id) => {
+            group::contribute_to_group_save(env, user, group_id, amount)
+        }
+        AutoSaveTarget::NewLock(duration) => {
+            lock::create_lock_save(env, user, amount, duration).map(|_| ())
+        }
+    }
+}
+
+/// Loads a schedule and checks that `user` owns it
+fn get_owned_schedule(
+    env: &Env,
+    user: &Address,
+    schedule_id: u64,
+) -> Result<AutoSave, SavingsError> {
+    user.require_auth();
+
+    let schedule = read_autosave(env, schedule_id).ok_or(SavingsError::PlanNotFound)?;
+
+    if schedule.user != *user {
+        return Err(SavingsError::Unauthorized);
+    }
+
+    Ok(schedule)
+}
+
+/// Persists a schedule and keeps the due index in sync with its new state
+fn save_schedule(env: &Env, schedule: &AutoSave) {
+    let key = DataKey::AutoSave(schedule.id);
+    // Schedules still in the legacy layout predate the due index
+    let previous = env
+        .storage()
+        .persistent()
+        .get::<_, Map<Symbol, Val>>(&key)
+        .filter(|raw| !is_legacy(raw))
+        .and_then(|raw| AutoSave::try_from_val(env, &raw.to_val()).ok());
+
+    let was_indexed = previous
+        .as_ref()
+        .filter(|s| is_indexed(s))
+        .map(|s| s.next_execution_time);
+    let now_indexed = Some(schedule.next_execution_time).filter(|_| is_indexed(schedule));
+
+    if was_indexed != now_indexed {
+        if let Some(time) = was_indexed {
+            index_remove(env, schedule.id, time);
+        }
+        if let Some(time) = now_indexed {
+            index_add(env, schedule.id, time);
+        }
+    }
+
+    env.storage().persistent().set(&key, schedule);
+    ttl::extend_autosave_ttl(env, schedule.id);
+}
+
+// ========== Due Index ==========
+
+/// Only schedules that can still fire are kept in the due index
+fn is_indexed(schedule: &AutoSave) -> bool {
+    schedule.is_active && !schedule.is_paused
+}
+
+/// Sorted IDs of the due-index buckets that hold schedules
+fn get_due_buckets(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AutoSaveDueBuckets)
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_due_buckets(env: &Env, buckets: &Vec<u64>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AutoSaveDueBuckets, buckets);
+    ttl::extend_autosave_index_ttl(env, &DataKey::AutoSaveDueBuckets);
+}
+
+fn get_due_bucket(env: &Env, bucket: u64) -> Vec<(u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AutoSaveDueBucket(bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+fn index_add(env: &Env, schedule_id: u64, due_time: u64) {
+    let bucket = due_time / DUE_BUCKET_SECONDS;
+    let bucket_key = DataKey::AutoSaveDueBucket(bucket);
+    let mut entries = get_due_bucket(env, bucket);
+
+    if entries.is_empty() {
+        let mut buckets = get_due_buckets(env);
+        if let Err(pos) = buckets.binary_search(bucket) {
+            buckets.insert(pos, bucket);
+            set_due_buckets(env, &buckets);
+        }
+    }
+
+    entries.push_back((schedule_id, due_time));
+    env.storage().persistent().set(&bucket_key, &entries);
+    ttl::extend_autosave_index_ttl(env, &bucket_key);
+}
+
+fn index_remove(env: &Env, schedule_id: u64, due_time: u64) {
+    let bucket = due_time / DUE_BUCKET_SECONDS;
+    let bucket_key = DataKey::AutoSaveDueBucket(bucket);
+    let mut entries = get_due_bucket(env, bucket);
+
+    if let Some(pos) = entries.first_index_of((schedule_id, due_time)) {
+        entries.remove(pos);
+    }
+
+    // Empty buckets leave the index so listings never read them
+    if entries.is_empty() {
+        env.storage().persistent().remove(&bucket_key);
+        let mut buckets = get_due_buckets(env);
+        if let Ok(pos) = buckets.binary_search(bucket) {
+            buckets.remove(pos);
+            set_due_buckets(env, &buckets);
+        }
+    } else {
+        env.storage().persistent().set(&bucket_key, &entries);
+        ttl::extend_autosave_index_ttl(env, &bucket_key);
+    }
+}
+
+/// Returns the first slot `next + k * interval` (k >= 0) that is at or after `now`
+fn next_slot_from(next: u64, interval: u64, now: u64) -> u64 {
+    if now <= next {
+        return next;
+    }
+    let missed = (now - next).div_ceil(interval);
+    next.saturating_add(missed.saturating_mul(interval))
+}
+
+/// Number of runs a due schedule performs now: the current slot plus any missed
+/// slots allowed by its catch-up policy, limited by `max_runs` and `end_time`.
+/// Returns 0 when the due slot itself lies past `end_time`.
+fn runs_due(schedule: &AutoSave, now: u64) -> u32 {
+    let due_slot = schedule.next_execution_time;
+    let missed = (now - due_slot) / schedule.interval_seconds;
+
+    let mut runs = 1u64.saturating_add(match schedule.catch_up {
+        CatchUpPolicy::SkipMissed => 0,
+        CatchUpPolicy::ExecuteMissed(limit) => missed.min(limit as u64),
+    });
+
+    if schedule.end_time > 0 {
+        if due_slot > schedule.end_time {
+            return 0;
+        }
+        let slots_before_end = (schedule.end_time - due_slot) / schedule.interval_seconds + 1;
+        runs = runs.min(slots_before_end);
+    }
+
+    if schedule.max_runs > 0 {
+        let remaining = schedule.max_runs.saturating_sub(schedule.runs_completed) as u64;
+        runs = runs.min(remaining);
+    }
+
+    runs as u32
+}
+
+/// Records `runs` successful runs, moves to the first slot after `now`, and ends
+/// the schedule once its end conditions are met
+fn finish_run(env: &Env, schedule: &mut AutoSave, runs: u32, now: u64) {
+    schedule.next_execution_time = next_slot_from(
+        schedule.next_execution_time,
+        schedule.interval_seconds,
+        now.saturating_add(1),
+    );
+    schedule.runs_completed = schedule.runs_completed.saturating_add(runs);
+    schedule.consecutive_failures = 0;
+
+    if schedule.max_runs > 0 && schedule.runs_completed >= schedule.max_runs {
+        deactivate_schedule(env, schedule, symbol_short!("max_runs"));
+    } else if schedule.end_time > 0 && schedule.next_execution_time > schedule.end_time {
+        deactivate_schedule(env, schedule, symbol_short!("end_time"));
+    }
+}
+
+/// Counts a failed batch execution and deactivates the schedule at the threshold
+fn record_failure(env: &Env, schedule: &mut AutoSave, err: SavingsError) {
+    schedule.consecutive_failures = schedule.consecutive_failures.saturating_add(1);
+
+    env.events().publish(
+        (symbol_short!("as_fail"), schedule.user.clone(), schedule.id),
+        (err as u32, schedule.consecutive_failures),
+    );
+
+    if schedule.consecutive_failures >= get_failure_threshold(env) {
+        deactivate_schedule(env, schedule, symbol_short!("failures"));
+    }
+    save_schedule(env, schedule);
+}
+
+/// Marks a schedule inactive and emits the reason; the caller persists it
+fn deactivate_schedule(env: &Env, schedule: &mut AutoSave, reason: Symbol) {
+    schedule.is_active = false;
+
+    env.events().publish(
+        (symbol_short!("as_end"), schedule.user.clone(), schedule.id),
+        reason,
+    );
+}
+
 fn get_next_schedule_id(env: &Env) -> u64 {
     let counter_key = DataKey::NextAutoSaveId;
     let id = env.storage().persistent().get(&counter_key).unwrap_or(1);
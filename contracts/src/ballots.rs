@@ -0,0 +1,133 @@
+//! Off-chain signed ballots: ballot keys, domain-bound signing messages and
+//! relayed submission, so voters do not pay fees to vote.
+
+use crate::errors::GovernanceError;
+use crate::governance;
+use crate::signer;
+use crate::storage_types::{SignerKey, SignerProof};
+use crate::ttl::{EXTEND_TO, LOW_THRESHOLD};
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+/// Most ballots a relayer can submit in one call.
+pub const MAX_BALLOTS_PER_BATCH: u32 = 20;
+
+/// Storage keys for ballots; variant names must not clash with `DataKey` or
+/// the other key enums, which share the same storage namespace
+#[contracttype]
+#[derive(Clone)]
+pub enum BallotKey {
+    /// Key a voter signs ballots with
+    BallotSigner(Address),
+    /// Set once the ballot with this (voter, nonce) has been counted
+    BallotNonceUsed(Address, u64),
+}
+
+/// A vote signed off-chain by the voter's ballot key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Ballot {
+    pub voter: Address,
+    pub proposal_id: u64,
+    /// 1 = for, 2 = against, 3 = abstain
+    pub vote_type: u32,
+    /// Voter-chosen value; each (voter, nonce) can be submitted once
+    pub nonce: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SignedBallot {
+    pub ballot: Ballot,
+    pub signature: SignerProof,
+}
+
+/// The message a voter actually signs.
+///
+/// Binding the ballot to this contract and to the network passphrase hash
+/// stops it from being replayed on another deployment or network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BallotMessage {
+    pub contract: Address,
+    pub network_id: BytesN<32>,
+    pub ballot: Ballot,
+}
+
+/// Returns the XDR bytes the voter's ballot key must sign for `ballot`
+pub fn signing_message(env: &Env, ballot: &Ballot) -> Bytes {
+    BallotMessage {
+        contract: env.current_contract_address(),
+        network_id: env.ledger().network_id(),
+        ballot: ballot.clone(),
+    }
+    .to_xdr(env)
+}
+
+pub fn get_ballot_key(env: &Env, voter: &Address) -> Option<SignerKey> {
+    env.storage()
+        .persistent()
+        .get(&BallotKey::BallotSigner(voter.clone()))
+}
+
+/// Registers the key `voter` signs ballots with, or removes it with `None`
+pub fn set_ballot_key(env: &Env, voter: Address, public_key: Option<SignerKey>) {
+    voter.require_auth();
+
+    let key = BallotKey::BallotSigner(voter);
+    match public_key {
+        Some(public_key) => {
+            env.storage().persistent().set(&key, &public_key);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, LOW_THRESHOLD, EXTEND_TO);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Returns true if the ballot with this nonce has already been counted
+pub fn is_ballot_nonce_used(env: &Env, voter: &Address, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&BallotKey::BallotNonceUsed(voter.clone(), nonce))
+}
+
+/// Counts a batch of signed ballots; anyone can relay them.
+///
+/// Ballots are counted in order and the batch is all-or-nothing, so relayers
+/// should drop ballots whose voter `has_voted` before submitting. A bad
+/// cryptographic signature aborts the invocation.
+///
+/// # Errors
+/// * `GovernanceError::TooManyBallots` - If there are more than `MAX_BALLOTS_PER_BATCH`
+/// * `GovernanceError::NoBallotKey` - If a voter has not registered a ballot key
+/// * `GovernanceError::BallotNonceUsed` - If a ballot was already submitted
+/// * `GovernanceError::InvalidBallotSignature` - If a proof does not fit the voter's key or ballot
+/// * Any error `vote` returns for the ballot's proposal and choice
+pub fn submit_ballots(env: &Env, ballots: Vec<SignedBallot>) -> Result<(), GovernanceError> {
+    if ballots.len() > MAX_BALLOTS_PER_BATCH {
+        return Err(GovernanceError::TooManyBallots);
+    }
+
+    for signed in ballots.iter() {
+        let ballot = signed.ballot;
+        let public_key = get_ballot_key(env, &ballot.voter).ok_or(GovernanceError::NoBallotKey)?;
+        if is_ballot_nonce_used(env, &ballot.voter, ballot.nonce) {
+            return Err(GovernanceError::BallotNonceUsed);
+        }
+
+        let message = signing_message(env, &ballot);
+        signer::verify(env, &public_key, &message, &signed.signature)
+            .map_err(|_| GovernanceError::InvalidBallotSignature)?;
+
+        let nonce_key = BallotKey::BallotNonceUsed(ballot.voter.clone(), ballot.nonce);
+        env.storage().persistent().set(&nonce_key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&nonce_key, LOW_THRESHOLD, EXTEND_TO);
+
+        governance::cast_vote(env, ballot.proposal_id, ballot.vote_type, ballot.voter)?;
+    }
+
+    Ok(())
+}
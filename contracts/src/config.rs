@@ -1,4 +1,5 @@
 use crate::errors::SavingsError;
+use crate::governance;
 use crate::storage_types::DataKey;
 use soroban_sdk::{contracttype, symbol_short, Address, Env};
 
@@ -145,10 +146,15 @@ pub fn get_config(env: &Env) -> Result<Config, SavingsError> {
 /// * `new_treasury` - The new treasury address
 ///
 /// # Errors
-/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or governance is active
 pub fn set_treasury(env: &Env, admin: Address, new_treasury: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    governance::require_admin_before_governance(env, &admin)?;
+    set_treasury_unchecked(env, new_treasury)
+}
 
+/// Updates the treasury without the admin check; used by governance
+pub(crate) fn set_treasury_unchecked(env: &Env, new_treasury: Address) -> Result<(), SavingsError> {
     env.storage()
         .instance()
         .set(&DataKey::Treasury, &new_treasury);
@@ -167,11 +173,16 @@ pub fn set_treasury(env: &Env, admin: Address, new_treasury: Address) -> Result<
 /// * `new_fee_bps` - The new fee in basis points (0-10000)
 ///
 /// # Errors
-/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or governance is active
 /// * `SavingsError::InvalidFeeBps` - If fee exceeds 10000 bps
 pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    governance::require_admin_before_governance(env, &admin)?;
+    set_protocol_fee_unchecked(env, new_fee_bps)
+}
 
+/// Updates the protocol fee without the admin check; used by governance
+pub(crate) fn set_protocol_fee_unchecked(env: &Env, new_fee_bps: u32) -> Result<(), SavingsError> {
     if new_fee_bps > MAX_FEE_BPS {
         return Err(SavingsError::InvalidFeeBps);
     }
@@ -186,6 +197,57 @@ pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(
     Ok(())
 }
 
+/// Sets the fee charged on the principal of early Goal breaks; used by
+/// `set_early_break_fee_bps` and governance.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If bps exceeds 10000
+pub(crate) fn set_early_break_fee_bps_unchecked(env: &Env, bps: u32) -> Result<(), SavingsError> {
+    if bps > MAX_FEE_BPS {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::EarlyBreakFeeBps, &bps);
+    env.events().publish((symbol_short!("set_brk"),), bps);
+    Ok(())
+}
+
+/// Sets the address receiving deposit, withdrawal and early-break fees; used
+/// by `set_fee_recipient` and governance.
+pub(crate) fn set_fee_recipient_unchecked(
+    env: &Env,
+    recipient: Address,
+) -> Result<(), SavingsError> {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeRecipient, &recipient);
+    env.events().publish((symbol_short!("set_fee"),), recipient);
+    Ok(())
+}
+
+/// Sets the fee charged on deposits and withdrawals; used by
+/// `set_protocol_fee_bps` and governance.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If bps exceeds 10000
+pub(crate) fn set_platform_fee_bps_unchecked(env: &Env, bps: u32) -> Result<(), SavingsError> {
+    if bps > MAX_FEE_BPS {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage().instance().set(&DataKey::PlatformFee, &bps);
+    env.events().publish((symbol_short!("set_pfee"),), bps);
+    Ok(())
+}
+
+/// Replaces the admin address; used by `set_admin` and governance.
+pub(crate) fn set_admin_unchecked(env: &Env, new_admin: Address) -> Result<(), SavingsError> {
+    env.storage().instance().set(&DataKey::Admin, &new_admin);
+    env.events()
+        .publish((symbol_short!("set_admin"),), new_admin);
+    Ok(())
+}
+
 /// Pauses the contract, blocking all state-changing operations.
 ///
 /// # Arguments
@@ -193,9 +255,10 @@ pub fn set_protocol_fee(env: &Env, admin: Address, new_fee_bps: u32) -> Result<(
 /// * `admin` - The admin calling this function
 ///
 /// # Errors
-/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or governance is active
 pub fn pause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    governance::require_admin_before_governance(env, &admin)?;
 
     env.storage().persistent().set(&DataKey::Paused, &true);
 
@@ -211,9 +274,10 @@ pub fn pause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
 /// * `admin` - The admin calling this function
 ///
 /// # Errors
-/// * `SavingsError::Unauthorized` - If caller is not the admin
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or governance is active
 pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), SavingsError> {
     require_admin(env, &admin)?;
+    governance::require_admin_before_governance(env, &admin)?;
 
     env.storage().persistent().set(&DataKey::Paused, &false);
 
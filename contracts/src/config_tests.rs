@@ -320,7 +320,7 @@ fn test_pause_blocks_deposit_flexi() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
     client.pause_contract(&admin);
 
     assert_savings_error(
@@ -335,7 +335,7 @@ fn test_pause_blocks_withdraw_flexi() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
     client.deposit_flexi(&user, &100);
     client.pause_contract(&admin);
 
@@ -351,7 +351,7 @@ fn test_pause_blocks_create_autosave() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
     client.pause_contract(&admin);
 
     assert_savings_error(
@@ -368,7 +368,7 @@ fn test_pause_blocks_execute_autosave() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
 
     // Create autosave first while not paused
     let schedule_id = client.create_autosave(&user, &100, &3600, &0);
@@ -392,7 +392,7 @@ fn test_unpause_restores_operations() {
 
     // Verify paused
     assert_savings_error(
-        client.try_initialize_user(&user).unwrap_err(),
+        client.try_initialize_user(&user, &None).unwrap_err(),
         SavingsError::ContractPaused,
     );
 
@@ -400,7 +400,7 @@ fn test_unpause_restores_operations() {
     client.unpause_contract(&admin);
 
     // Should work now
-    assert!(client.try_initialize_user(&user).is_ok());
+    assert!(client.try_initialize_user(&user, &None).is_ok());
 }
 
 // ========== Combined Admin Flow ==========
@@ -239,6 +239,109 @@ pub enum SavingsError {
     ///
     /// E.g. the actual returned amount is 0 or negative when a positive value was expected.
     InvalidStrategyResponse = 98,
+
+    /// Returned when a redemption catalog item does not exist.
+    RedemptionItemNotFound = 99,
+
+    /// Returned when a catalog item is retired or its offer has expired.
+    RedemptionUnavailable = 100,
+
+    /// Returned when a user has used up their redemptions of a catalog item.
+    RedemptionLimitReached = 101,
+
+    /// Returned when a referrer is the user themselves or is not a registered user.
+    InvalidReferrer = 102,
+
+    /// Returned when starting a season while the previous one is not finalized.
+    SeasonActive = 103,
+
+    /// Returned when the requested rewards season does not exist.
+    SeasonNotFound = 104,
+
+    /// Returned when finalizing a season before its end time.
+    SeasonNotEnded = 105,
+
+    /// Returned when finalizing a season that has already been finalized.
+    SeasonFinalized = 106,
+
+    /// Returned when a mint signer set is empty, has duplicates or an unreachable threshold.
+    InvalidSignerSet = 107,
+}
+
+/// Errors from the proposal lifecycle: creating, voting on, queueing,
+/// executing and canceling proposals.
+///
+/// Codes start at 200 so they never collide with `SavingsError`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GovernanceError {
+    /// Returned when the voting configuration has not been initialized.
+    NotConfigured = 200,
+
+    /// Returned when no proposal has the requested ID.
+    ProposalNotFound = 201,
+
+    /// Returned when the proposal is not in a state that allows the operation.
+    InvalidProposalState = 202,
+
+    /// Returned when the creator's voting power is below `proposal_threshold`.
+    BelowProposalThreshold = 203,
+
+    /// Returned when a vote type is not 1 (for), 2 (against) or 3 (abstain).
+    InvalidVoteType = 204,
+
+    /// Returned when the voter has already voted on the proposal.
+    AlreadyVoted = 205,
+
+    /// Returned when the voter held no voting power when voting opened.
+    NoVotingPower = 206,
+
+    /// Returned when voting on a proposal that is not active.
+    VotingClosed = 207,
+
+    /// Returned when queueing a proposal whose voting period has not ended.
+    VotingNotEnded = 208,
+
+    /// Returned when too little of the total voting power took part.
+    QuorumNotMet = 209,
+
+    /// Returned when `for` votes fell short of the action's pass threshold.
+    ThresholdNotMet = 210,
+
+    /// Returned when executing a queued proposal before its timelock has passed.
+    TimelockNotElapsed = 211,
+
+    /// Returned when executing a queued proposal after its grace period.
+    ProposalExpired = 212,
+
+    /// Returned when someone other than the creator cancels a proposal.
+    NotProposer = 213,
+
+    /// Returned when vote arithmetic overflows.
+    Overflow = 214,
+
+    /// Returned when an action proposal is created without any actions.
+    NoActions = 215,
+
+    /// Returned when a proposal carries more than `MAX_PROPOSAL_ACTIONS` actions.
+    TooManyActions = 216,
+
+    /// Returned when someone other than the guardian vetoes a proposal.
+    NotGuardian = 217,
+
+    /// Returned when a ballot's voter has not registered a ballot key.
+    NoBallotKey = 218,
+
+    /// Returned when a ballot's (voter, nonce) pair has already been submitted.
+    BallotNonceUsed = 219,
+
+    /// Returned when a batch holds more than `MAX_BALLOTS_PER_BATCH` ballots.
+    TooManyBallots = 220,
+
+    /// Returned when a ballot's proof does not fit the voter's key type or
+    /// is a passkey assertion over a different message.
+    InvalidBallotSignature = 221,
 }
 
 #[cfg(test)]
@@ -289,6 +392,15 @@ mod tests {
             SavingsError::StrategyDisabled as u32,
             SavingsError::ReentrancyDetected as u32,
             SavingsError::InvalidStrategyResponse as u32,
+            SavingsError::RedemptionItemNotFound as u32,
+            SavingsError::RedemptionUnavailable as u32,
+            SavingsError::RedemptionLimitReached as u32,
+            SavingsError::InvalidReferrer as u32,
+            SavingsError::SeasonActive as u32,
+            SavingsError::SeasonNotFound as u32,
+            SavingsError::SeasonNotEnded as u32,
+            SavingsError::SeasonFinalized as u32,
+            SavingsError::InvalidSignerSet as u32,
         ];
 
         let mut sorted = errors.clone();
@@ -318,4 +430,39 @@ mod tests {
         assert_eq!(SavingsError::GroupFull as u32, 70);
         assert_eq!(SavingsError::MissingParameter as u32, 80);
     }
+
+    #[test]
+    fn test_governance_error_codes_are_unique() {
+        let errors = std::vec![
+            GovernanceError::NotConfigured as u32,
+            GovernanceError::ProposalNotFound as u32,
+            GovernanceError::InvalidProposalState as u32,
+            GovernanceError::BelowProposalThreshold as u32,
+            GovernanceError::InvalidVoteType as u32,
+            GovernanceError::AlreadyVoted as u32,
+            GovernanceError::NoVotingPower as u32,
+            GovernanceError::VotingClosed as u32,
+            GovernanceError::VotingNotEnded as u32,
+            GovernanceError::QuorumNotMet as u32,
+            GovernanceError::ThresholdNotMet as u32,
+            GovernanceError::TimelockNotElapsed as u32,
+            GovernanceError::ProposalExpired as u32,
+            GovernanceError::NotProposer as u32,
+            GovernanceError::Overflow as u32,
+            GovernanceError::NoActions as u32,
+            GovernanceError::TooManyActions as u32,
+            GovernanceError::NotGuardian as u32,
+            GovernanceError::NoBallotKey as u32,
+            GovernanceError::BallotNonceUsed as u32,
+            GovernanceError::TooManyBallots as u32,
+            GovernanceError::InvalidBallotSignature as u32,
+        ];
+
+        let mut sorted = errors.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(errors.len(), sorted.len(), "Duplicate error codes detected");
+        assert!(errors.iter().all(|&code| code >= 200));
+    }
 }
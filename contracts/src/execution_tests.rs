@@ -1,11 +1,16 @@
 #[cfg(test)]
 mod execution_tests {
-    use crate::governance::ProposalAction;
+    use crate::governance::{ProposalAction, ProposalFailure, ProposalState, MAX_PROPOSAL_ACTIONS};
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
+    use crate::{
+        GovernanceError, KeeperConfig, KeeperFeeSource, MintConfig, MintSignerSet, MintTarget,
+        NesteraContract, NesteraContractClient, PlanType, RedemptionBenefit, RedemptionItem,
+        ReferralConfig, SavingsError, SavingsTier, SignerKey, WebAuthnRelyingParty,
+    };
     use soroban_sdk::{
+        symbol_short,
         testutils::{Address as _, Ledger},
-        Address, BytesN, Env, String,
+        vec, Address, BytesN, Env, String, Vec,
     };
 
     fn setup_contract() -> (Env, NesteraContractClient<'static>, Address) {
@@ -43,7 +48,7 @@ mod execution_tests {
         let creator = Address::generate(&env);
         let description = String::from_str(&env, "Test proposal");
 
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
         let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000); // Meets threshold
 
         let action = ProposalAction::SetFlexiRate(500);
@@ -56,8 +61,8 @@ mod execution_tests {
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
 
-        client.initialize_user(&voter1);
-        client.initialize_user(&voter2);
+        client.initialize_user(&voter1, &None);
+        client.initialize_user(&voter2, &None);
 
         let _ = client.create_savings_plan(&voter1, &PlanType::Flexi, &3000);
         let _ = client.create_savings_plan(&voter2, &PlanType::Flexi, &2000);
@@ -105,7 +110,7 @@ mod execution_tests {
         let creator = Address::generate(&env);
         let description = String::from_str(&env, "Test proposal");
 
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
         let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
 
         let action = ProposalAction::SetFlexiRate(500);
@@ -116,7 +121,7 @@ mod execution_tests {
 
         // Vote against
         let voter = Address::generate(&env);
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
         client.vote(&proposal_id, &2, &voter);
 
@@ -239,7 +244,7 @@ mod execution_tests {
         let creator = Address::generate(&env);
         let description = String::from_str(&env, "Change flexi rate");
 
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
         let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
 
         let action = ProposalAction::SetFlexiRate(750);
@@ -251,8 +256,8 @@ mod execution_tests {
         // Vote
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
-        client.initialize_user(&voter1);
-        client.initialize_user(&voter2);
+        client.initialize_user(&voter1, &None);
+        client.initialize_user(&voter2, &None);
         let _ = client.create_savings_plan(&voter1, &PlanType::Flexi, &4000);
         let _ = client.create_savings_plan(&voter2, &PlanType::Flexi, &3000);
 
@@ -291,7 +296,7 @@ mod execution_tests {
         let creator = Address::generate(&env);
         let description = String::from_str(&env, "Pause contract");
 
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
         let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
 
         let action = ProposalAction::PauseContract;
@@ -301,7 +306,7 @@ mod execution_tests {
             .unwrap();
 
         let voter = Address::generate(&env);
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &5000);
         client.vote(&proposal_id, &1, &voter);
 
@@ -317,4 +322,885 @@ mod execution_tests {
 
         assert!(client.is_paused());
     }
+
+    /// Passes `action` through vote, queue and timelock, then executes it
+    fn pass_action(
+        env: &Env,
+        client: &NesteraContractClient,
+        action: ProposalAction,
+    ) -> Result<(), ()> {
+        let creator = Address::generate(env);
+        client.initialize_user(&creator, &None);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &5000);
+        // Voting power is read from before the proposal's creation ledger
+        env.ledger().with_mut(|li| li.timestamp += 1);
+
+        let proposal_id = client.create_action_proposal(
+            &creator,
+            &String::from_str(env, "Parameter change"),
+            &action,
+        );
+        client.vote(&proposal_id, &1, &creator);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 604800 + 1;
+        });
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+
+        match client.try_execute_proposal(&proposal_id) {
+            Ok(Ok(())) => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    fn setup_governance() -> (Env, NesteraContractClient<'static>, Address) {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        // Each `pass_action` adds a fresh depositor, so keep quorum low enough
+        // for one of them to carry a vote on their own
+        client.init_voting_config(&admin, &1000, &604800, &86400, &100, &10_000);
+        client.initialize_config(&admin, &admin, &0);
+        client.activate_governance(&admin);
+        (env, client, admin)
+    }
+
+    #[test]
+    fn test_execute_fee_actions() {
+        let (env, client, _admin) = setup_governance();
+        let recipient = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        pass_action(&env, &client, ProposalAction::SetPlatformFee(150)).unwrap();
+        pass_action(&env, &client, ProposalAction::SetProtocolFee(250)).unwrap();
+        pass_action(&env, &client, ProposalAction::SetEarlyBreakFee(400)).unwrap();
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetFeeRecipient(recipient.clone()),
+        )
+        .unwrap();
+        pass_action(&env, &client, ProposalAction::SetTreasury(treasury.clone())).unwrap();
+
+        assert_eq!(client.get_protocol_fee_bps(), 150);
+        assert_eq!(client.get_early_break_fee_bps(), 400);
+        assert_eq!(client.get_fee_recipient(), Some(recipient));
+        let config = client.get_config();
+        assert_eq!(config.protocol_fee_bps, 250);
+        assert_eq!(config.treasury, treasury);
+    }
+
+    #[test]
+    fn test_execute_action_validates_parameters() {
+        let (env, client, _admin) = setup_governance();
+
+        assert!(pass_action(&env, &client, ProposalAction::SetPlatformFee(10_001)).is_err());
+        assert_eq!(client.get_protocol_fee_bps(), 0);
+    }
+
+    #[test]
+    fn test_execute_set_admin_action() {
+        let (env, client, _admin) = setup_governance();
+        let new_admin = Address::generate(&env);
+
+        pass_action(&env, &client, ProposalAction::SetAdmin(new_admin.clone())).unwrap();
+        assert_eq!(client.get_config().admin, new_admin);
+    }
+
+    #[test]
+    fn test_execute_rewards_config_action() {
+        let (env, client, _admin) = setup_governance();
+        let mut config = client.get_rewards_config();
+        config.points_per_token = 25;
+
+        pass_action(&env, &client, ProposalAction::SetRewardsConfig(config)).unwrap();
+        assert_eq!(client.get_rewards_config().points_per_token, 25);
+    }
+
+    #[test]
+    fn test_execute_strategy_actions() {
+        let (env, client, _admin) = setup_governance();
+        let strategy = Address::generate(&env);
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::RegisterStrategy(strategy.clone(), 3),
+        )
+        .unwrap();
+        let info = client.get_strategy(&strategy);
+        assert!(info.enabled);
+        assert_eq!(info.risk_level, 3);
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::DisableStrategy(strategy.clone()),
+        )
+        .unwrap();
+        assert!(!client.get_strategy(&strategy).enabled);
+    }
+
+    #[test]
+    fn test_execute_protocol_settings_actions() {
+        let (env, client, _admin) = setup_governance();
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetKeeperConfig(KeeperConfig {
+                fee: 7,
+                source: KeeperFeeSource::ProtocolPool,
+                min_interval_seconds: 0,
+                min_amount: 0,
+            }),
+        )
+        .unwrap();
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetAutoSaveFailureThreshold(5),
+        )
+        .unwrap();
+        pass_action(&env, &client, ProposalAction::SetPointsExpiryDays(90)).unwrap();
+        pass_action(&env, &client, ProposalAction::SetBalanceVoteWeight(5_000)).unwrap();
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetExecutionGracePeriod(3_600),
+        )
+        .unwrap();
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetMintConfig(MintConfig {
+                target: MintTarget::Flexi,
+                per_user_cap: 1_000,
+                global_cap: 0,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(client.get_keeper_config().unwrap().fee, 7);
+        assert_eq!(client.get_autosave_failure_threshold(), 5);
+        assert_eq!(client.get_points_expiry_days(), 90);
+        assert_eq!(client.get_balance_vote_weight(), 5_000);
+        assert_eq!(client.get_execution_grace_period(), 3_600);
+        assert_eq!(client.get_mint_config().per_user_cap, 1_000);
+    }
+
+    #[test]
+    fn test_execute_rotate_admin_key_action() {
+        let (env, client, _admin) = setup_governance();
+        let new_key = SignerKey::Ed25519(BytesN::from_array(&env, &[9u8; 32]));
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::RotateAdminKey(new_key.clone(), 3600),
+        )
+        .unwrap();
+        assert_eq!(client.get_admin_public_key(), Some(new_key));
+    }
+
+    #[test]
+    fn test_execute_mint_signer_actions() {
+        let (env, client, _admin) = setup_governance();
+        let signer_set = MintSignerSet {
+            signers: vec![
+                &env,
+                SignerKey::Ed25519(BytesN::from_array(&env, &[9u8; 32])),
+            ],
+            threshold: 1,
+            large_amount: 10_000,
+        };
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetMintSigners(signer_set.clone()),
+        )
+        .unwrap();
+        assert_eq!(client.get_mint_signers(), Some(signer_set));
+        pass_action(&env, &client, ProposalAction::ClearMintSigners).unwrap();
+        assert_eq!(client.get_mint_signers(), None);
+    }
+
+    fn depositor(env: &Env, client: &NesteraContractClient, amount: i128) -> Address {
+        let user = Address::generate(env);
+        client.initialize_user(&user, &None);
+        client.create_savings_plan(&user, &PlanType::Flexi, &amount);
+        user
+    }
+
+    fn end_voting(env: &Env) {
+        env.ledger().with_mut(|li| {
+            li.timestamp += 604800 + 1;
+        });
+    }
+
+    #[test]
+    fn test_total_voting_power_tracks_deposits() {
+        let (env, client, _admin) = setup_governance();
+        assert_eq!(client.get_total_voting_power(), 0);
+
+        let alice = depositor(&env, &client, 3000);
+        depositor(&env, &client, 2000);
+        client.create_savings_plan(&alice, &PlanType::Flexi, &1000);
+
+        assert_eq!(client.get_total_voting_power(), 6000);
+        assert_eq!(client.get_voting_power(&alice), 4000);
+    }
+
+    #[test]
+    fn test_queue_rejected_below_quorum() {
+        let (env, client, _admin) = setup_governance();
+        let voter = depositor(&env, &client, 900);
+        depositor(&env, &client, 9_100);
+
+        let proposal_id = client.create_proposal(&voter, &String::from_str(&env, "Low turnout"));
+        client.vote(&proposal_id, &1, &voter);
+        assert_eq!(client.get_proposal_failure_reason(&proposal_id), None);
+
+        end_voting(&env);
+        assert!(client.try_queue_proposal(&proposal_id).is_err());
+        assert_eq!(
+            client.get_proposal_failure_reason(&proposal_id),
+            Some(ProposalFailure::QuorumNotMet)
+        );
+    }
+
+    #[test]
+    fn test_abstain_votes_count_toward_quorum() {
+        let (env, client, _admin) = setup_governance();
+        let for_voter = depositor(&env, &client, 500);
+        let abstainer = depositor(&env, &client, 1000);
+        depositor(&env, &client, 8_500);
+
+        let proposal_id = client.create_action_proposal(
+            &for_voter,
+            &String::from_str(&env, "Rate change"),
+            &ProposalAction::SetFlexiRate(300),
+        );
+        client.vote(&proposal_id, &1, &for_voter);
+        client.vote(&proposal_id, &3, &abstainer);
+
+        end_voting(&env);
+        assert_eq!(client.get_proposal_failure_reason(&proposal_id), None);
+        client.queue_proposal(&proposal_id);
+    }
+
+    #[test]
+    fn test_rate_change_passes_on_simple_majority() {
+        let (env, client, _admin) = setup_governance();
+        let yes = depositor(&env, &client, 6000);
+        let no = depositor(&env, &client, 4000);
+
+        let proposal_id = client.create_action_proposal(
+            &yes,
+            &String::from_str(&env, "Rate change"),
+            &ProposalAction::SetFlexiRate(300),
+        );
+        client.vote(&proposal_id, &1, &yes);
+        client.vote(&proposal_id, &2, &no);
+
+        end_voting(&env);
+        client.queue_proposal(&proposal_id);
+    }
+
+    #[test]
+    fn test_upgrade_needs_supermajority() {
+        let (env, client, _admin) = setup_governance();
+        let yes = depositor(&env, &client, 6000);
+        let no = depositor(&env, &client, 4000);
+
+        let proposal_id = client.create_action_proposal(
+            &yes,
+            &String::from_str(&env, "Upgrade"),
+            &ProposalAction::Upgrade(BytesN::from_array(&env, &[3u8; 32])),
+        );
+        client.vote(&proposal_id, &1, &yes);
+        client.vote(&proposal_id, &2, &no);
+
+        end_voting(&env);
+        assert!(client.try_queue_proposal(&proposal_id).is_err());
+        assert_eq!(
+            client.get_proposal_failure_reason(&proposal_id),
+            Some(ProposalFailure::ThresholdNotMet)
+        );
+    }
+
+    #[test]
+    fn test_treasury_move_passes_with_supermajority() {
+        let (env, client, _admin) = setup_governance();
+        let yes = depositor(&env, &client, 7000);
+        let no = depositor(&env, &client, 3000);
+        let treasury = Address::generate(&env);
+
+        let proposal_id = client.create_action_proposal(
+            &yes,
+            &String::from_str(&env, "Move treasury"),
+            &ProposalAction::SetTreasury(treasury.clone()),
+        );
+        client.vote(&proposal_id, &1, &yes);
+        client.vote(&proposal_id, &2, &no);
+
+        end_voting(&env);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+        client.execute_proposal(&proposal_id);
+        assert_eq!(client.get_config().treasury, treasury);
+    }
+
+    #[test]
+    fn test_failure_reason_for_unknown_proposal() {
+        let (_env, client, _admin) = setup_governance();
+        assert!(client.try_get_proposal_failure_reason(&99).is_err());
+    }
+
+    #[test]
+    fn test_voting_power_follows_balance_history() {
+        let (env, client, _admin) = setup_governance();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = Address::generate(&env);
+        client.initialize_user(&user, &None);
+        client.deposit_flexi(&user, &5000);
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        client.withdraw_flexi(&user, &2000);
+
+        assert_eq!(client.get_voting_power(&user), 3000);
+        assert_eq!(client.get_voting_power_at(&user, &999), 0);
+        assert_eq!(client.get_voting_power_at(&user, &1_500), 5000);
+        assert_eq!(client.get_voting_power_at(&user, &2_000), 3000);
+        assert_eq!(client.get_total_voting_power(), 3000);
+    }
+
+    #[test]
+    fn test_deposit_after_proposal_creation_does_not_count() {
+        let (env, client, _admin) = setup_governance();
+        let creator = depositor(&env, &client, 1000);
+        let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Snapshot"));
+
+        env.ledger().with_mut(|li| li.timestamp += 10);
+        let latecomer = depositor(&env, &client, 9000);
+
+        let result = client.try_vote(&proposal_id, &1, &latecomer);
+        assert!(result.is_err());
+        client.vote(&proposal_id, &1, &creator);
+        assert_eq!(client.get_proposal_votes(&proposal_id), (1000, 0, 0));
+    }
+
+    #[test]
+    fn test_deposit_in_proposal_creation_ledger_does_not_count() {
+        let (env, client, _admin) = setup_governance();
+        let creator = depositor(&env, &client, 1000);
+        env.ledger().with_mut(|li| li.timestamp += 10);
+
+        let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Snapshot"));
+        let latecomer = depositor(&env, &client, 19_000);
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &latecomer),
+            Err(Ok(GovernanceError::NoVotingPower))
+        );
+
+        client.vote(&proposal_id, &1, &creator);
+        assert_eq!(client.get_proposal_votes(&proposal_id), (1000, 0, 0));
+        end_voting(&env);
+        assert_eq!(client.get_proposal_failure_reason(&proposal_id), None);
+    }
+
+    #[test]
+    fn test_withdrawn_funds_do_not_count() {
+        let (env, client, _admin) = setup_governance();
+        let creator = depositor(&env, &client, 1000);
+        let user = Address::generate(&env);
+        client.initialize_user(&user, &None);
+        client.deposit_flexi(&user, &5000);
+        client.withdraw_flexi(&user, &5000);
+
+        env.ledger().with_mut(|li| li.timestamp += 10);
+        let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Snapshot"));
+        assert!(client.try_vote(&proposal_id, &1, &user).is_err());
+    }
+
+    fn pass_batch(env: &Env, client: &NesteraContractClient, actions: Vec<ProposalAction>) -> u64 {
+        let creator = depositor(env, client, 5000);
+        let proposal_id = client.create_batch_proposal(
+            &creator,
+            &String::from_str(env, "Batch change"),
+            &actions,
+        );
+        client.vote(&proposal_id, &1, &creator);
+        end_voting(env);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+        proposal_id
+    }
+
+    #[test]
+    fn test_batch_proposal_runs_all_actions() {
+        let (env, client, _admin) = setup_governance();
+        let actions = vec![
+            &env,
+            ProposalAction::SetLockRate(30, 400),
+            ProposalAction::SetLockRate(90, 600),
+            ProposalAction::SetLockRate(180, 800),
+            ProposalAction::SetGoalRate(550),
+        ];
+        let proposal_id = pass_batch(&env, &client, actions.clone());
+        assert_eq!(
+            client.get_action_proposal(&proposal_id).unwrap().actions,
+            actions
+        );
+
+        client.execute_proposal(&proposal_id);
+
+        assert_eq!(client.get_lock_rate(&30), 400);
+        assert_eq!(client.get_lock_rate(&90), 600);
+        assert_eq!(client.get_lock_rate(&180), 800);
+        assert_eq!(client.get_goal_rate(), 550);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Executed
+        );
+    }
+
+    #[test]
+    fn test_failing_batch_action_rolls_back_earlier_actions() {
+        let (env, client, _admin) = setup_governance();
+        let goal_rate = client.get_goal_rate();
+        let proposal_id = pass_batch(
+            &env,
+            &client,
+            vec![
+                &env,
+                ProposalAction::SetGoalRate(goal_rate + 100),
+                ProposalAction::SetPlatformFee(10_001),
+            ],
+        );
+
+        assert!(client.try_execute_proposal(&proposal_id).is_err());
+        assert_eq!(client.get_goal_rate(), goal_rate);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Queued
+        );
+    }
+
+    #[test]
+    fn test_batch_proposal_length_limits() {
+        let (env, client, _admin) = setup_governance();
+        let creator = depositor(&env, &client, 5000);
+        let description = String::from_str(&env, "Batch change");
+
+        let result = client.try_create_batch_proposal(&creator, &description, &Vec::new(&env));
+        assert_eq!(result, Err(Ok(GovernanceError::NoActions)));
+
+        let mut actions = Vec::new(&env);
+        for _ in 0..MAX_PROPOSAL_ACTIONS {
+            actions.push_back(ProposalAction::SetFlexiRate(100));
+        }
+        client.create_batch_proposal(&creator, &description, &actions);
+
+        actions.push_back(ProposalAction::SetFlexiRate(100));
+        let result = client.try_create_batch_proposal(&creator, &description, &actions);
+        assert_eq!(result, Err(Ok(GovernanceError::TooManyActions)));
+    }
+
+    #[test]
+    fn test_batch_with_critical_action_needs_supermajority() {
+        let (env, client, _admin) = setup_governance();
+        let yes = depositor(&env, &client, 6000);
+        let no = depositor(&env, &client, 4000);
+
+        let proposal_id = client.create_batch_proposal(
+            &yes,
+            &String::from_str(&env, "Rate and treasury"),
+            &vec![
+                &env,
+                ProposalAction::SetFlexiRate(300),
+                ProposalAction::SetTreasury(Address::generate(&env)),
+            ],
+        );
+        client.vote(&proposal_id, &1, &yes);
+        client.vote(&proposal_id, &2, &no);
+
+        end_voting(&env);
+        assert_eq!(
+            client.get_proposal_failure_reason(&proposal_id),
+            Some(ProposalFailure::ThresholdNotMet)
+        );
+    }
+
+    /// Charges a 10% deposit fee to the treasury and returns the accrued fees
+    fn accrue_fees(env: &Env, client: &NesteraContractClient, admin: &Address) -> i128 {
+        pass_action(env, client, ProposalAction::SetFeeRecipient(admin.clone())).unwrap();
+        pass_action(env, client, ProposalAction::SetPlatformFee(1000)).unwrap();
+        let user = Address::generate(env);
+        client.initialize_user(&user, &None);
+        client.deposit_flexi(&user, &10_000);
+        client.get_treasury_balance()
+    }
+
+    #[test]
+    fn test_treasury_transfer_pays_recipient_and_records_spend() {
+        let (env, client, admin) = setup_governance();
+        let recipient = depositor(&env, &client, 5000);
+        assert_eq!(accrue_fees(&env, &client, &admin), 1000);
+
+        let proposal_id = client.create_action_proposal(
+            &recipient,
+            &String::from_str(&env, "Grant"),
+            &ProposalAction::TreasuryTransfer(recipient.clone(), 400),
+        );
+        client.vote(&proposal_id, &1, &recipient);
+        end_voting(&env);
+        client.queue_proposal(&proposal_id);
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(GovernanceError::TimelockNotElapsed))
+        );
+
+        env.ledger().with_mut(|li| {
+            li.timestamp += 86400 + 1;
+        });
+        let flexi_before = client.get_flexi_balance(&recipient);
+        client.execute_proposal(&proposal_id);
+
+        assert_eq!(client.get_treasury_balance(), 600);
+        assert_eq!(client.get_flexi_balance(&recipient), flexi_before + 400);
+        assert_eq!(client.get_treasury_spend_count(), 1);
+        let spend = client.get_treasury_spends(&0, &10).get(0).unwrap();
+        assert_eq!(spend.proposal_id, proposal_id);
+        assert_eq!(spend.recipient, recipient);
+        assert_eq!(spend.amount, 400);
+        assert_eq!(spend.executed_at, env.ledger().timestamp());
+    }
+
+    #[test]
+    fn test_treasury_transfer_limited_to_accrued_fees() {
+        let (env, client, admin) = setup_governance();
+        accrue_fees(&env, &client, &admin);
+        let recipient = depositor(&env, &client, 100);
+
+        assert!(pass_action(
+            &env,
+            &client,
+            ProposalAction::TreasuryTransfer(recipient.clone(), 1001),
+        )
+        .is_err());
+        assert!(pass_action(
+            &env,
+            &client,
+            ProposalAction::TreasuryTransfer(recipient.clone(), 0),
+        )
+        .is_err());
+        assert_eq!(client.get_treasury_balance(), 1000);
+        assert_eq!(client.get_treasury_spend_count(), 0);
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::TreasuryTransfer(recipient.clone(), 1000),
+        )
+        .unwrap();
+        assert_eq!(client.get_treasury_balance(), 0);
+        assert_eq!(client.get_treasury_spends(&1, &10).len(), 0);
+    }
+
+    fn redemption_item() -> RedemptionItem {
+        RedemptionItem {
+            benefit: RedemptionBenefit::EarlyBreakWaiver,
+            cost: 100,
+            duration_seconds: 86400,
+            available_until: 0,
+            max_per_user: 0,
+            active: true,
+        }
+    }
+
+    /// Admin and an arbitrary address, neither of which may call setters
+    /// directly once governance is active
+    fn direct_callers(env: &Env, admin: &Address) -> [Address; 2] {
+        [admin.clone(), Address::generate(env)]
+    }
+
+    #[test]
+    fn test_keeper_config_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let config = KeeperConfig {
+            fee: 1_000_000,
+            source: KeeperFeeSource::ProtocolPool,
+            min_interval_seconds: 0,
+            min_amount: 0,
+        };
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_keeper_config(&caller, &config),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_failure_threshold_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_autosave_failure_threshold(&caller, &1),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_redemption_catalog_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::AddCatalogItem(redemption_item()),
+        )
+        .unwrap();
+        assert_eq!(client.get_redemption_item_count(), 1);
+
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_add_redemption_item(&caller, &redemption_item()),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_redemption_item_active(&caller, &1, &false),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetCatalogItemActive(1, false),
+        )
+        .unwrap();
+        assert!(!client.get_redemption_item(&1).unwrap().active);
+    }
+
+    #[test]
+    fn test_savings_tiers_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let tiers = vec![
+            &env,
+            SavingsTier {
+                name: symbol_short!("gold"),
+                min_lifetime_deposited: 1,
+                points_multiplier_bps: 50_000,
+                fee_discount_bps: 10_000,
+                rate_bonus_bps: 0,
+            },
+        ];
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_savings_tiers(&caller, &tiers),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_referral_config_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let config = ReferralConfig {
+            deposit_threshold: 0,
+            referrer_points: 1_000_000,
+            fee_share_bps: 10_000,
+        };
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_referral_config(&caller, &config),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_start_season_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let end_time = env.ledger().timestamp() + 10 * 604800;
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_start_season(&caller, &end_time, &vec![&env, 1_000_000u128]),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::StartSeason(end_time, vec![&env, 100u128]),
+        )
+        .unwrap();
+        assert_eq!(client.get_current_season(), 1);
+    }
+
+    #[test]
+    fn test_points_expiry_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_points_expiry_days(&caller, &1),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_balance_vote_weight_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_balance_vote_weight(&caller, &0),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+        assert_eq!(client.get_balance_vote_weight(), 10_000);
+    }
+
+    #[test]
+    fn test_execution_grace_period_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_execution_grace_period(&caller, &1),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_fee_setters_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        assert_eq!(
+            client.try_set_early_break_fee_bps(&100),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_fee_recipient(&Address::generate(&env)),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_protocol_fee_bps(&100),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_protocol_fee(&caller, &100),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_admin_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_admin(&caller, &Address::generate(&env)),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+        assert_eq!(client.get_config().admin, admin);
+    }
+
+    #[test]
+    fn test_set_treasury_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_treasury(&caller, &Address::generate(&env)),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+        assert_eq!(client.get_config().treasury, admin);
+    }
+
+    #[test]
+    fn test_rewards_config_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let mut config = client.get_rewards_config();
+        config.points_per_token = 25;
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_update_rewards_config(&caller, &config),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_upgrade_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_upgrade(&caller, &wasm_hash),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_upgrade_requires_stored_admin() {
+        let (env, client, _admin) = setup_contract();
+        let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+        assert_eq!(
+            client.try_upgrade(&Address::generate(&env), &wasm_hash),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_strategy_registry_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let strategy = Address::generate(&env);
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_register_strategy(&caller, &strategy, &1),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+            assert_eq!(
+                client.try_disable_strategy(&caller, &strategy),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+    }
+
+    #[test]
+    fn test_webauthn_relying_party_closed_after_activation() {
+        let (env, client, admin) = setup_governance();
+        let relying_party = WebAuthnRelyingParty {
+            rp_id_hash: BytesN::from_array(&env, &[5u8; 32]),
+            origin: String::from_str(&env, "https://nestera.app"),
+        };
+        for caller in direct_callers(&env, &admin) {
+            assert_eq!(
+                client.try_set_webauthn_relying_party(&caller, &relying_party),
+                Err(Ok(SavingsError::Unauthorized))
+            );
+        }
+        assert_eq!(client.get_webauthn_relying_party(), None);
+
+        pass_action(
+            &env,
+            &client,
+            ProposalAction::SetWebAuthnRelyingParty(relying_party.clone()),
+        )
+        .unwrap();
+        assert_eq!(client.get_webauthn_relying_party(), Some(relying_party));
+    }
 }
@@ -1,11 +1,13 @@
 // New/Correct
-use crate::calculate_fee;
+use crate::calculate_user_fee;
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
 use crate::invariants;
+use crate::referral;
 use crate::rewards;
 use crate::storage_types::{DataKey, User};
 use crate::ttl;
+use crate::users;
 use soroban_sdk::{symbol_short, Address, Env};
 
 /// Handles depositing funds into the Flexi Save pool.
@@ -27,7 +29,7 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
         .get(&DataKey::PlatformFee)
         .unwrap_or(0);
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    let fee_amount = calculate_user_fee(&env, &user, amount, fee_bps)?;
     let net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -50,7 +52,7 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
             .total_balance
             .checked_add(net_amount)
             .ok_or(SavingsError::Overflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
+        users::save_user(&env, &user, &user_data);
     } else {
         return Err(SavingsError::UserNotFound);
     }
@@ -61,6 +63,9 @@ pub fn flexi_deposit(env: Env, user: Address, amount: i128) -> Result<(), Saving
     // 6. Award deposit points (streak, rewards)
     rewards::storage::award_deposit_points(&env, user.clone(), amount)?;
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(&env, &user, fee_amount)?;
+
     // 7. Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
@@ -111,7 +116,7 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
         .get(&DataKey::PlatformFee)
         .unwrap_or(0);
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    let fee_amount = calculate_user_fee(&env, &user, amount, fee_bps)?;
     let _net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -138,12 +143,15 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
             .total_balance
             .checked_sub(amount)
             .ok_or(SavingsError::Underflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
+        users::save_user(&env, &user, &user_data);
     }
 
     // Extend TTL on user interaction
     ttl::extend_user_ttl(&env, &user);
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(&env, &user, fee_amount)?;
+
     // 6. Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
@@ -168,6 +176,56 @@ pub fn flexi_withdraw(env: Env, user: Address, amount: i128) -> Result<(), Savin
 
     Ok(())
 }
+/// Credits `amount` to a user's Flexi balance without charging protocol fees.
+///
+/// Used for internal payouts (e.g. keeper bounties) rather than user deposits.
+pub fn credit_flexi_balance(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let user_key = DataKey::User(user.clone());
+    let mut user_data: User = env
+        .storage()
+        .persistent()
+        .get(&user_key)
+        .ok_or(SavingsError::UserNotFound)?;
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    let new_balance = balance.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+
+    env.storage().persistent().set(&flexi_key, &new_balance);
+    users::save_user(env, user, &user_data);
+    ttl::extend_user_ttl(env, user);
+    Ok(())
+}
+
+/// Debits `amount` from a user's Flexi balance without charging protocol fees.
+pub fn debit_flexi_balance(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let user_key = DataKey::User(user.clone());
+    let mut user_data: User = env
+        .storage()
+        .persistent()
+        .get(&user_key)
+        .ok_or(SavingsError::UserNotFound)?;
+
+    let flexi_key = DataKey::FlexiBalance(user.clone());
+    let balance: i128 = env.storage().persistent().get(&flexi_key).unwrap_or(0);
+    invariants::assert_sufficient_balance(balance, amount)?;
+    user_data.total_balance = user_data
+        .total_balance
+        .checked_sub(amount)
+        .ok_or(SavingsError::Underflow)?;
+
+    env.storage()
+        .persistent()
+        .set(&flexi_key, &(balance - amount));
+    users::save_user(env, user, &user_data);
+    ttl::extend_user_ttl(env, user);
+    Ok(())
+}
+
 /// Returns the user's Flexi Save balance.
 /// This is a read-only (view) function.
 pub fn get_flexi_balance(env: &Env, user: Address) -> Result<i128, SavingsError> {
@@ -226,7 +284,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&500).is_ok()); // 5%
 
@@ -244,7 +302,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let deposit_amount = 10_000i128;
         client.deposit_flexi(&user, &deposit_amount);
@@ -260,7 +318,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&250).is_ok()); // 2.5%
 
@@ -282,7 +340,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         client.deposit_flexi(&user, &10_000);
         client.withdraw_flexi(&user, &3_000);
@@ -297,7 +355,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&125).is_ok()); // 1.25%
 
@@ -316,7 +374,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&100).is_ok()); // 1%
 
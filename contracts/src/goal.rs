@@ -1,9 +1,12 @@
 use soroban_sdk::{symbol_short, Address, Env, Vec};
 
-use crate::calculate_fee;
+use crate::calculate_user_fee;
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
-use crate::rewards::storage;
+use crate::rates;
+use crate::referral;
+use crate::rewards::storage_types::RatePlan;
+use crate::rewards::{redemption, storage};
 use crate::storage_types::{DataKey, GoalSave, User};
 use crate::ttl;
 use crate::users;
@@ -37,7 +40,7 @@ pub fn create_goal_save(
         .get(&DataKey::PlatformFee)
         .unwrap_or(0);
 
-    let fee_amount = calculate_fee(initial_deposit, fee_bps)?;
+    let fee_amount = calculate_user_fee(env, &user, initial_deposit, fee_bps)?;
     let net_initial_deposit = initial_deposit
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -51,7 +54,7 @@ pub fn create_goal_save(
         goal_name: goal_name.clone(),
         target_amount,
         current_amount: net_initial_deposit,
-        interest_rate: 500,
+        interest_rate: rates::plan_interest_rate(env, &user, RatePlan::Goal),
         start_time: current_time,
         is_completed: net_initial_deposit >= target_amount,
         is_withdrawn: false,
@@ -65,6 +68,9 @@ pub fn create_goal_save(
         storage::award_goal_completion_bonus(env, user.clone())?;
     }
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(env, &user, fee_amount)?;
+
     // Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
@@ -132,7 +138,7 @@ pub fn deposit_to_goal_save(
         .get(&DataKey::PlatformFee)
         .unwrap_or(0);
 
-    let fee_amount = calculate_fee(amount, fee_bps)?;
+    let fee_amount = calculate_user_fee(env, &user, amount, fee_bps)?;
     let net_amount = amount
         .checked_sub(fee_amount)
         .ok_or(SavingsError::Underflow)?;
@@ -159,6 +165,9 @@ pub fn deposit_to_goal_save(
     ttl::extend_goal_ttl(env, goal_id);
     ttl::extend_user_ttl(env, &user);
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(env, &user, fee_amount)?;
+
     // Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
@@ -222,7 +231,7 @@ pub fn withdraw_completed_goal_save(
         .get(&DataKey::PlatformFee)
         .unwrap_or(0);
 
-    let fee_amount = calculate_fee(goal_save.current_amount, fee_bps)?;
+    let fee_amount = calculate_user_fee(env, &user, goal_save.current_amount, fee_bps)?;
     let net_amount = goal_save
         .current_amount
         .checked_sub(fee_amount)
@@ -240,13 +249,16 @@ pub fn withdraw_completed_goal_save(
             .total_balance
             .checked_add(net_amount)
             .ok_or(SavingsError::Overflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
+        users::save_user(env, &user, &user_data);
     }
 
     // Extend TTL (withdrawn goals get shorter extension)
     ttl::extend_goal_ttl(env, goal_id);
     ttl::extend_user_ttl(env, &user);
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(env, &user, fee_amount)?;
+
     // Transfer fee to treasury if fee > 0
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
@@ -306,6 +318,13 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
         return Err(SavingsError::InvalidAmount);
     }
 
+    // A redeemed waiver covers the whole early-break fee, once
+    let fee_bps = if fee_bps > 0 && redemption::consume_early_break_waiver(env, &user) {
+        0
+    } else {
+        fee_bps
+    };
+
     let fee_amount = if fee_bps == 0 {
         0
     } else {
@@ -333,9 +352,12 @@ pub fn break_goal_save(env: &Env, user: Address, goal_id: u64) -> Result<i128, S
             .total_balance
             .checked_add(net_amount)
             .ok_or(SavingsError::Overflow)?;
-        env.storage().persistent().set(&user_key, &user_data);
+        users::save_user(env, &user, &user_data);
     }
 
+    // Referrer takes their share before the rest goes to the treasury
+    let fee_amount = referral::share_fee(env, &user, fee_amount)?;
+
     if fee_amount > 0 {
         if let Some(fee_recipient) = env
             .storage()
@@ -555,7 +577,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "vacation");
         let target = 10000i128;
@@ -578,7 +600,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "house");
         let target = 5000i128;
@@ -598,7 +620,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "laptop");
         let target = 5000i128;
@@ -618,7 +640,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "car");
         let target = 1000i128;
@@ -643,7 +665,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "bike");
         let target = 5000i128;
@@ -661,7 +683,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "fund");
         let target = 1000i128;
@@ -680,8 +702,8 @@ mod tests {
         let user2 = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user1);
-        client.initialize_user(&user2);
+        client.initialize_user(&user1, &None);
+        client.initialize_user(&user2, &None);
 
         let goal_name = Symbol::new(&env, "test");
         let target = 1000i128;
@@ -697,7 +719,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "emergency");
         let target = 5000i128;
@@ -721,7 +743,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "done");
         let target = 1000i128;
@@ -738,7 +760,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_early_break_fee_bps(&500).is_ok()); // 5%
 
@@ -760,7 +782,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_early_break_fee_bps(&125).is_ok()); // 1.25%
 
@@ -784,8 +806,8 @@ mod tests {
         let user2 = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user1);
-        client.initialize_user(&user2);
+        client.initialize_user(&user1, &None);
+        client.initialize_user(&user2, &None);
 
         let goal_name = Symbol::new(&env, "other");
         let target = 5000i128;
@@ -802,7 +824,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "invalid");
         let target = 0i128;
@@ -833,7 +855,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&500).is_ok()); // 5%
 
@@ -856,7 +878,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&300).is_ok()); // 3%
 
@@ -885,7 +907,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&250).is_ok()); // 2.5%
 
@@ -913,7 +935,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "car");
         let target = 5_000i128;
@@ -935,7 +957,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&1000).is_ok()); // 10%
 
@@ -958,7 +980,7 @@ mod tests {
         let treasury = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         assert!(client.try_set_fee_recipient(&treasury).is_ok());
         assert!(client.try_set_protocol_fee_bps(&100).is_ok()); // 1%
 
@@ -981,7 +1003,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "bonusgoal");
         let goal_id = client.create_goal_save(&user, &goal_name, &5_000, &4_000);
@@ -1004,7 +1026,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "nobonus");
         let goal_id = client.create_goal_save(&user, &goal_name, &5_000, &4_999);
@@ -1023,7 +1045,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "instant");
         let goal_id = client.create_goal_save(&user, &goal_name, &5_000, &5_000);
@@ -1043,7 +1065,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "disabled");
         let _goal_id = client.create_goal_save(&user, &goal_name, &5_000, &5_000);
@@ -1059,7 +1081,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "breakcase");
         let goal_id = client.create_goal_save(&user, &goal_name, &10_000, &2_000);
@@ -34,11 +34,22 @@ pub fn get_proposal_votes(env: &Env, proposal_id: u64) -> (u128, u128, u128) {
         (0, 0, 0)
     }
 }
-use crate::errors::SavingsError;
+use crate::errors::{GovernanceError, SavingsError};
 use crate::governance_events::*;
-use crate::rewards::storage::get_user_rewards;
-use crate::storage_types::DataKey;
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use crate::rewards::storage_types::{RedemptionItem, RewardsConfig, SavingsTier};
+use crate::storage_types::{
+    DataKey, KeeperConfig, MintConfig, MintSignerSet, ReferralConfig, SignerKey,
+    WebAuthnRelyingParty,
+};
+use crate::voting_power::{get_total_voting_power_at, get_voting_power, get_voting_power_at};
+use crate::{
+    autosave, config, keeper, mint, referral, rewards, signer, strategy, treasury, upgrade,
+    voting_power,
+};
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, Address, BytesN, Env, Map, String, Symbol,
+    TryFromVal, Val, Vec,
+};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -49,16 +60,74 @@ pub struct ActionProposal {
     pub start_time: u64,
     pub end_time: u64,
     pub executed: bool,
+    pub canceled: bool,
+    pub vetoed: bool,
     pub for_votes: u128,
     pub against_votes: u128,
     pub abstain_votes: u128,
-    pub action: ProposalAction,
+    /// Run in order when the proposal executes; all take effect or none do
+    pub actions: Vec<ProposalAction>,
     pub queued_time: u64,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Proposal {
+    pub id: u64,
+    pub creator: Address,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub executed: bool,
+    pub canceled: bool,
+    pub vetoed: bool,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub abstain_votes: u128,
+    pub queued_time: u64,
+}
+
+/// Layout of `ActionProposal` stored before batches, cancellation and vetoes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyActionProposal {
+    pub id: u64,
+    pub creator: Address,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub executed: bool,
+    pub for_votes: u128,
+    pub against_votes: u128,
+    pub abstain_votes: u128,
+    pub action: ProposalAction,
+    pub queued_time: u64,
+}
+
+impl LegacyActionProposal {
+    fn into_current(self, env: &Env) -> ActionProposal {
+        ActionProposal {
+            id: self.id,
+            creator: self.creator,
+            description: self.description,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            executed: self.executed,
+            canceled: false,
+            vetoed: false,
+            for_votes: self.for_votes,
+            against_votes: self.against_votes,
+            abstain_votes: self.abstain_votes,
+            actions: Vec::from_array(env, [self.action]),
+            queued_time: self.queued_time,
+        }
+    }
+}
+
+/// Layout of `Proposal` stored before cancellation and vetoes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProposal {
     pub id: u64,
     pub creator: Address,
     pub description: String,
@@ -71,6 +140,25 @@ pub struct Proposal {
     pub queued_time: u64,
 }
 
+impl From<LegacyProposal> for Proposal {
+    fn from(legacy: LegacyProposal) -> Self {
+        Proposal {
+            id: legacy.id,
+            creator: legacy.creator,
+            description: legacy.description,
+            start_time: legacy.start_time,
+            end_time: legacy.end_time,
+            executed: legacy.executed,
+            canceled: false,
+            vetoed: false,
+            for_votes: legacy.for_votes,
+            against_votes: legacy.against_votes,
+            abstain_votes: legacy.abstain_votes,
+            queued_time: legacy.queued_time,
+        }
+    }
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VotingConfig {
@@ -91,8 +179,28 @@ pub enum GovernanceKey {
     AllProposals,
     GovernanceActive,
     VoterRecord(u64, Address),
+    ExecutionGracePeriod,
+    Guardian,
+}
+
+/// Why a proposal whose voting period has ended cannot be queued.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalFailure {
+    /// Votes cast were below `VotingConfig.quorum` of the total voting power
+    QuorumNotMet,
+    /// `for` votes did not reach the threshold required by the proposal's action
+    ThresholdNotMet,
 }
 
+/// Share of `for` votes (out of `for` + `against`) that protocol-critical
+/// actions need, in basis points.
+pub const SUPERMAJORITY_BPS: u128 = 6_667;
+
+/// A change an executed proposal applies to the protocol.
+///
+/// Together these cover every admin-settable parameter, so the DAO can run
+/// the protocol once governance is active.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProposalAction {
@@ -102,23 +210,402 @@ pub enum ProposalAction {
     SetLockRate(u64, i128),
     PauseContract,
     UnpauseContract,
+    /// Fee charged on deposits and withdrawals (`set_protocol_fee_bps`)
+    SetPlatformFee(u32),
+    /// Protocol fee in the global config (`set_protocol_fee`)
+    SetProtocolFee(u32),
+    SetEarlyBreakFee(u32),
+    SetFeeRecipient(Address),
+    SetTreasury(Address),
+    SetAdmin(Address),
+    SetRewardsConfig(RewardsConfig),
+    /// Registers a strategy with a risk level
+    RegisterStrategy(Address, u32),
+    DisableStrategy(Address),
+    /// Upgrades the contract to an uploaded WASM hash
+    Upgrade(BytesN<32>),
+    SetKeeperConfig(KeeperConfig),
+    SetAutoSaveFailureThreshold(u32),
+    SetPointsExpiryDays(u64),
+    SetSavingsTiers(Vec<SavingsTier>),
+    /// Adds a benefit to the points redemption catalog
+    AddCatalogItem(RedemptionItem),
+    /// Lists or retires a redemption catalog item
+    SetCatalogItemActive(u32, bool),
+    /// Starts a rewards season ending at a timestamp, with per-rank prizes
+    StartSeason(u64, Vec<u128>),
+    SetReferralConfig(ReferralConfig),
+    SetMintConfig(MintConfig),
+    SetMintSigners(MintSignerSet),
+    /// Removes the mint signer set, so every mint needs only the admin key
+    ClearMintSigners,
+    /// Rotates the admin signing key with a grace period in seconds
+    RotateAdminKey(SignerKey, u64),
+    /// Weight of liquid balances in voting power, in basis points
+    SetBalanceVoteWeight(u32),
+    /// Seconds a queued proposal stays executable after its timelock
+    SetExecutionGracePeriod(u64),
+    /// Rotates the guardian, or removes it with `None`
+    SetGuardian(Option<Address>),
+    /// Pays accrued protocol fees from the treasury to a saver's Flexi balance
+    TreasuryTransfer(Address, i128),
+    /// Sets the relying party passkey assertions are checked against
+    SetWebAuthnRelyingParty(WebAuthnRelyingParty),
+}
+
+/// Most actions a single proposal can carry.
+pub const MAX_PROPOSAL_ACTIONS: u32 = 10;
+
+/// Returns true if `action` needs a supermajority rather than a simple majority.
+///
+/// Upgrades, admin and key changes, and anything that moves where protocol
+/// funds go or how tokens are minted are held to the higher bar.
+pub fn requires_supermajority(action: &ProposalAction) -> bool {
+    matches!(
+        action,
+        ProposalAction::Upgrade(_)
+            | ProposalAction::SetAdmin(_)
+            | ProposalAction::SetTreasury(_)
+            | ProposalAction::SetFeeRecipient(_)
+            | ProposalAction::SetMintConfig(_)
+            | ProposalAction::SetMintSigners(_)
+            | ProposalAction::ClearMintSigners
+            | ProposalAction::RotateAdminKey(_, _)
+            | ProposalAction::SetGuardian(_)
+            | ProposalAction::TreasuryTransfer(_, _)
+            | ProposalAction::SetWebAuthnRelyingParty(_)
+    )
+}
+
+/// Where a proposal is in its lifecycle.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalState {
+    /// Voting has not opened yet
+    Pending,
+    /// Open for votes
+    Active,
+    /// Voting ended without quorum or enough `for` votes
+    Defeated,
+    /// Voting ended and the proposal passed, but it is not queued yet
+    Succeeded,
+    /// Waiting out the timelock, or executable until the grace period ends
+    Queued,
+    /// Queued but not executed before the grace period ended
+    Expired,
+    Executed,
+    Canceled,
+    /// Vetoed by the guardian during its timelock
+    Vetoed,
+}
+
+/// How long a queued proposal stays executable after its timelock, by default
+pub const DEFAULT_EXECUTION_GRACE_PERIOD: u64 = 14 * 24 * 60 * 60;
+
+/// A stored proposal of either kind, so lifecycle rules are written once.
+enum StoredProposal {
+    Plain(Proposal),
+    Action(ActionProposal),
+}
+
+impl StoredProposal {
+    fn load(env: &Env, proposal_id: u64) -> Result<Self, GovernanceError> {
+        if let Some(proposal) = get_proposal(env, proposal_id) {
+            return Ok(StoredProposal::Plain(proposal));
+        }
+        get_action_proposal(env, proposal_id)
+            .map(StoredProposal::Action)
+            .ok_or(GovernanceError::ProposalNotFound)
+    }
+
+    fn save(&self, env: &Env) {
+        match self {
+            StoredProposal::Plain(p) => env
+                .storage()
+                .persistent()
+                .set(&GovernanceKey::Proposal(p.id), p),
+            StoredProposal::Action(p) => env
+                .storage()
+                .persistent()
+                .set(&GovernanceKey::ActionProposal(p.id), p),
+        }
+    }
+
+    fn actions(&self) -> Option<&Vec<ProposalAction>> {
+        match self {
+            StoredProposal::Plain(_) => None,
+            StoredProposal::Action(p) => Some(&p.actions),
+        }
+    }
+
+    fn creator(&self) -> &Address {
+        match self {
+            StoredProposal::Plain(p) => &p.creator,
+            StoredProposal::Action(p) => &p.creator,
+        }
+    }
+
+    fn start_time(&self) -> u64 {
+        match self {
+            StoredProposal::Plain(p) => p.start_time,
+            StoredProposal::Action(p) => p.start_time,
+        }
+    }
+
+    /// Timestamp voting power is read at: the second before the proposal was
+    /// created, so nothing deposited in the creation ledger counts
+    fn snapshot_time(&self) -> u64 {
+        self.start_time().saturating_sub(1)
+    }
+
+    fn end_time(&self) -> u64 {
+        match self {
+            StoredProposal::Plain(p) => p.end_time,
+            StoredProposal::Action(p) => p.end_time,
+        }
+    }
+
+    fn queued_time(&self) -> u64 {
+        match self {
+            StoredProposal::Plain(p) => p.queued_time,
+            StoredProposal::Action(p) => p.queued_time,
+        }
+    }
+
+    fn executed(&self) -> bool {
+        match self {
+            StoredProposal::Plain(p) => p.executed,
+            StoredProposal::Action(p) => p.executed,
+        }
+    }
+
+    fn canceled(&self) -> bool {
+        match self {
+            StoredProposal::Plain(p) => p.canceled,
+            StoredProposal::Action(p) => p.canceled,
+        }
+    }
+
+    /// `for`, `against` and `abstain` totals
+    fn votes(&self) -> (u128, u128, u128) {
+        match self {
+            StoredProposal::Plain(p) => (p.for_votes, p.against_votes, p.abstain_votes),
+            StoredProposal::Action(p) => (p.for_votes, p.against_votes, p.abstain_votes),
+        }
+    }
+
+    fn votes_mut(&mut self) -> (&mut u128, &mut u128, &mut u128) {
+        match self {
+            StoredProposal::Plain(p) => {
+                (&mut p.for_votes, &mut p.against_votes, &mut p.abstain_votes)
+            }
+            StoredProposal::Action(p) => {
+                (&mut p.for_votes, &mut p.against_votes, &mut p.abstain_votes)
+            }
+        }
+    }
+
+    fn set_queued_time(&mut self, queued_time: u64) {
+        match self {
+            StoredProposal::Plain(p) => p.queued_time = queued_time,
+            StoredProposal::Action(p) => p.queued_time = queued_time,
+        }
+    }
+
+    fn set_executed(&mut self) {
+        match self {
+            StoredProposal::Plain(p) => p.executed = true,
+            StoredProposal::Action(p) => p.executed = true,
+        }
+    }
+
+    fn vetoed(&self) -> bool {
+        match self {
+            StoredProposal::Plain(p) => p.vetoed,
+            StoredProposal::Action(p) => p.vetoed,
+        }
+    }
+
+    fn set_vetoed(&mut self) {
+        match self {
+            StoredProposal::Plain(p) => p.vetoed = true,
+            StoredProposal::Action(p) => p.vetoed = true,
+        }
+    }
+
+    fn set_canceled(&mut self) {
+        match self {
+            StoredProposal::Plain(p) => p.canceled = true,
+            StoredProposal::Action(p) => p.canceled = true,
+        }
+    }
+}
+
+/// Checks a finished vote against the quorum and the pass threshold.
+///
+/// Quorum is `VotingConfig.quorum` basis points of the total voting power when
+/// voting opened, counting `for`, `against` and `abstain` votes. Proposals
+/// without an action pass on a simple majority; a batch needs a supermajority
+/// if any of its actions does.
+fn evaluate_votes(
+    env: &Env,
+    proposal: &StoredProposal,
+) -> Result<Option<ProposalFailure>, GovernanceError> {
+    let config = voting_config(env)?;
+    let (for_votes, against_votes, abstain_votes) = proposal.votes();
+
+    let participation = for_votes
+        .checked_add(against_votes)
+        .and_then(|v| v.checked_add(abstain_votes))
+        .ok_or(GovernanceError::Overflow)?;
+    let required = get_total_voting_power_at(env, proposal.snapshot_time())
+        .checked_mul(config.quorum as u128)
+        .ok_or(GovernanceError::Overflow)?;
+    if participation.saturating_mul(10_000) < required {
+        return Ok(Some(ProposalFailure::QuorumNotMet));
+    }
+
+    let passed = match proposal.actions() {
+        Some(actions) if actions.iter().any(|a| requires_supermajority(&a)) => {
+            let decisive = for_votes
+                .checked_add(against_votes)
+                .ok_or(GovernanceError::Overflow)?;
+            for_votes > 0
+                && for_votes.saturating_mul(10_000) >= decisive.saturating_mul(SUPERMAJORITY_BPS)
+        }
+        _ => for_votes > against_votes,
+    };
+    if !passed {
+        return Ok(Some(ProposalFailure::ThresholdNotMet));
+    }
+
+    Ok(None)
+}
+
+fn state_of(env: &Env, proposal: &StoredProposal) -> Result<ProposalState, GovernanceError> {
+    let now = env.ledger().timestamp();
+
+    if proposal.canceled() {
+        return Ok(ProposalState::Canceled);
+    }
+    if proposal.vetoed() {
+        return Ok(ProposalState::Vetoed);
+    }
+    if proposal.executed() {
+        return Ok(ProposalState::Executed);
+    }
+    if now < proposal.start_time() {
+        return Ok(ProposalState::Pending);
+    }
+    if now <= proposal.end_time() {
+        return Ok(ProposalState::Active);
+    }
+    if proposal.queued_time() > 0 {
+        if now > execution_deadline(env, proposal)? {
+            return Ok(ProposalState::Expired);
+        }
+        return Ok(ProposalState::Queued);
+    }
+    match evaluate_votes(env, proposal)? {
+        Some(_) => Ok(ProposalState::Defeated),
+        None => Ok(ProposalState::Succeeded),
+    }
+}
+
+/// Earliest time a queued proposal can be executed
+fn execution_time(env: &Env, proposal: &StoredProposal) -> Result<u64, GovernanceError> {
+    proposal
+        .queued_time()
+        .checked_add(voting_config(env)?.timelock_duration)
+        .ok_or(GovernanceError::Overflow)
+}
+
+/// Last time a queued proposal can be executed
+fn execution_deadline(env: &Env, proposal: &StoredProposal) -> Result<u64, GovernanceError> {
+    execution_time(env, proposal)?
+        .checked_add(get_execution_grace_period(env))
+        .ok_or(GovernanceError::Overflow)
+}
+
+/// Returns the lifecycle state of a proposal
+///
+/// # Errors
+/// * `GovernanceError::ProposalNotFound` - If no proposal has this ID
+pub fn get_proposal_state(env: &Env, proposal_id: u64) -> Result<ProposalState, GovernanceError> {
+    state_of(env, &StoredProposal::load(env, proposal_id)?)
+}
+
+/// Returns why a proposal failed, or `None` if it passed or voting is still open
+///
+/// # Errors
+/// * `GovernanceError::ProposalNotFound` - If no proposal has this ID
+pub fn get_proposal_failure_reason(
+    env: &Env,
+    proposal_id: u64,
+) -> Result<Option<ProposalFailure>, GovernanceError> {
+    let proposal = StoredProposal::load(env, proposal_id)?;
+    if env.ledger().timestamp() <= proposal.end_time() {
+        return Ok(None);
+    }
+    evaluate_votes(env, &proposal)
+}
+
+/// Returns how long a queued proposal stays executable after its timelock
+pub fn get_execution_grace_period(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&GovernanceKey::ExecutionGracePeriod)
+        .unwrap_or(DEFAULT_EXECUTION_GRACE_PERIOD)
+}
+
+/// Sets how long a queued proposal stays executable after its timelock. Admin
+/// only until governance is active, then only through a proposal.
+pub fn set_execution_grace_period(
+    env: &Env,
+    caller: Address,
+    grace_period: u64,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    require_admin_before_governance(env, &caller)?;
+    set_execution_grace_period_unchecked(env, grace_period)
+}
+
+/// Sets the execution grace period without an authorization check.
+pub(crate) fn set_execution_grace_period_unchecked(
+    env: &Env,
+    grace_period: u64,
+) -> Result<(), SavingsError> {
+    if grace_period == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&GovernanceKey::ExecutionGracePeriod, &grace_period);
+    Ok(())
 }
 
-/// Calculates voting power for a user based on their lifetime deposited funds
-pub fn get_voting_power(env: &Env, user: &Address) -> u128 {
-    let rewards = get_user_rewards(env, user.clone());
-    rewards.lifetime_deposited.max(0) as u128
+/// Voting configuration for proposal lifecycle calls
+fn voting_config(env: &Env) -> Result<VotingConfig, GovernanceError> {
+    get_voting_config(env).map_err(|_| GovernanceError::NotConfigured)
 }
 
 /// Creates a new governance proposal
+///
+/// # Errors
+/// * `GovernanceError::BelowProposalThreshold` - If the creator's voting power
+///   is below `VotingConfig.proposal_threshold`
 pub fn create_proposal(
     env: &Env,
     creator: Address,
     description: String,
-) -> Result<u64, SavingsError> {
+) -> Result<u64, GovernanceError> {
     creator.require_auth();
 
-    let config = get_voting_config(env)?;
+    let config = voting_config(env)?;
+    if get_voting_power(env, &creator) < config.proposal_threshold {
+        return Err(GovernanceError::BelowProposalThreshold);
+    }
+
     let proposal_id = get_next_proposal_id(env);
     let now = env.ledger().timestamp();
 
@@ -129,6 +616,8 @@ pub fn create_proposal(
         start_time: now,
         end_time: now + config.voting_period,
         executed: false,
+        canceled: false,
+        vetoed: false,
         for_votes: 0,
         against_votes: 0,
         abstain_votes: 0,
@@ -165,12 +654,33 @@ pub fn create_action_proposal(
     creator: Address,
     description: String,
     action: ProposalAction,
-) -> Result<u64, SavingsError> {
+) -> Result<u64, GovernanceError> {
+    create_batch_proposal(env, creator, description, Vec::from_array(env, [action]))
+}
+
+/// Creates a governance proposal that runs several actions in order
+///
+/// # Errors
+/// * `GovernanceError::NoActions` - If `actions` is empty
+/// * `GovernanceError::TooManyActions` - If there are more than `MAX_PROPOSAL_ACTIONS`
+pub fn create_batch_proposal(
+    env: &Env,
+    creator: Address,
+    description: String,
+    actions: Vec<ProposalAction>,
+) -> Result<u64, GovernanceError> {
     creator.require_auth();
 
-    let config = get_voting_config(env)?;
+    if actions.is_empty() {
+        return Err(GovernanceError::NoActions);
+    }
+    if actions.len() > MAX_PROPOSAL_ACTIONS {
+        return Err(GovernanceError::TooManyActions);
+    }
+
+    let config = voting_config(env)?;
     if get_voting_power(env, &creator) < config.proposal_threshold {
-        return Err(SavingsError::InsufficientBalance);
+        return Err(GovernanceError::BelowProposalThreshold);
     }
 
     let proposal_id = get_next_proposal_id(env);
@@ -183,10 +693,12 @@ pub fn create_action_proposal(
         start_time: now,
         end_time: now + config.voting_period,
         executed: false,
+        canceled: false,
+        vetoed: false,
         for_votes: 0,
         against_votes: 0,
         abstain_votes: 0,
-        action,
+        actions,
         queued_time: 0,
     };
 
@@ -214,18 +726,38 @@ pub fn create_action_proposal(
     Ok(proposal_id)
 }
 
-/// Gets an action proposal by ID
+/// Gets an action proposal by ID, converting one stored in the legacy layout
 pub fn get_action_proposal(env: &Env, proposal_id: u64) -> Option<ActionProposal> {
-    env.storage()
+    let raw: Map<Symbol, Val> = env
+        .storage()
         .persistent()
-        .get(&GovernanceKey::ActionProposal(proposal_id))
+        .get(&GovernanceKey::ActionProposal(proposal_id))?;
+    if is_legacy_proposal(&raw) {
+        return LegacyActionProposal::try_from_val(env, &raw.to_val())
+            .ok()
+            .map(|legacy| legacy.into_current(env));
+    }
+    ActionProposal::try_from_val(env, &raw.to_val()).ok()
 }
 
-/// Gets a proposal by ID
+/// Gets a proposal by ID, converting one stored in the legacy layout
 pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<Proposal> {
-    env.storage()
+    let raw: Map<Symbol, Val> = env
+        .storage()
         .persistent()
-        .get(&GovernanceKey::Proposal(proposal_id))
+        .get(&GovernanceKey::Proposal(proposal_id))?;
+    if is_legacy_proposal(&raw) {
+        return LegacyProposal::try_from_val(env, &raw.to_val())
+            .ok()
+            .map(Proposal::from);
+    }
+    Proposal::try_from_val(env, &raw.to_val()).ok()
+}
+
+/// Whether a stored proposal predates cancellation. Checked up front because
+/// decoding a struct from a map with other fields traps.
+fn is_legacy_proposal(raw: &Map<Symbol, Val>) -> bool {
+    !raw.contains_key(symbol_short!("canceled"))
 }
 
 /// Lists all proposal IDs
@@ -266,7 +798,11 @@ pub fn init_voting_config(
         return Err(SavingsError::ConfigAlreadyInitialized);
     }
 
-    if config.voting_period == 0 || config.timelock_duration == 0 || config.max_voting_power == 0 {
+    if config.voting_period == 0
+        || config.timelock_duration == 0
+        || config.max_voting_power == 0
+        || config.quorum > 10_000
+    {
         return Err(SavingsError::InvalidAmount);
     }
 
@@ -293,106 +829,61 @@ pub fn vote(
     proposal_id: u64,
     vote_type: u32,
     voter: Address,
-) -> Result<(), SavingsError> {
+) -> Result<(), GovernanceError> {
     voter.require_auth();
+    cast_vote(env, proposal_id, vote_type, voter)
+}
 
+/// Records `voter`'s vote once their authorization has been checked, either
+/// by `require_auth` or by a signed ballot.
+pub(crate) fn cast_vote(
+    env: &Env,
+    proposal_id: u64,
+    vote_type: u32,
+    voter: Address,
+) -> Result<(), GovernanceError> {
     // Validate vote_type: 1=for, 2=against, 3=abstain
     if !(1..=3).contains(&vote_type) {
-        return Err(SavingsError::InvalidAmount);
-    }
-
-    let weight = get_voting_power(env, &voter);
-    if weight == 0 {
-        return Err(SavingsError::InsufficientBalance);
+        return Err(GovernanceError::InvalidVoteType);
     }
 
-    let config = get_voting_config(env)?;
-    let capped_weight = weight.min(config.max_voting_power);
+    let config = voting_config(env)?;
 
     let voter_key = GovernanceKey::VoterRecord(proposal_id, voter.clone());
     if env.storage().persistent().has(&voter_key) {
-        return Err(SavingsError::DuplicatePlanId);
+        return Err(GovernanceError::AlreadyVoted);
     }
 
-    let now = env.ledger().timestamp();
-
-    // Regular proposal
-    if let Some(mut proposal) = get_proposal(env, proposal_id) {
-        if now < proposal.start_time || now > proposal.end_time {
-            return Err(SavingsError::TooLate);
-        }
-
-        match vote_type {
-            1 => {
-                proposal.for_votes = proposal
-                    .for_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            2 => {
-                proposal.against_votes = proposal
-                    .against_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            3 => {
-                proposal.abstain_votes = proposal
-                    .abstain_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            _ => return Err(SavingsError::InvalidAmount),
-        }
-
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::Proposal(proposal_id), &proposal);
-        env.storage().persistent().set(&voter_key, &true);
-
-        emit_vote_cast(env, proposal_id, voter, vote_type, weight);
-
-        return Ok(());
+    let mut proposal = StoredProposal::load(env, proposal_id)?;
+    if state_of(env, &proposal)? != ProposalState::Active {
+        return Err(GovernanceError::VotingClosed);
     }
 
-    // Action proposal
-    if let Some(mut proposal) = get_action_proposal(env, proposal_id) {
-        if now < proposal.start_time || now > proposal.end_time {
-            return Err(SavingsError::TooLate);
-        }
-
-        match vote_type {
-            1 => {
-                proposal.for_votes = proposal
-                    .for_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            2 => {
-                proposal.against_votes = proposal
-                    .against_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            3 => {
-                proposal.abstain_votes = proposal
-                    .abstain_votes
-                    .checked_add(capped_weight)
-                    .ok_or(SavingsError::Overflow)?
-            }
-            _ => return Err(SavingsError::InvalidAmount),
-        }
+    // Power is taken from before voting opened, so deposits made after the
+    // proposal was created, even in the same ledger, do not count. The cap
+    // applies to the combined own and delegated weight.
+    let weight = get_voting_power_at(env, &voter, proposal.snapshot_time());
+    if weight == 0 {
+        return Err(GovernanceError::NoVotingPower);
+    }
+    let capped_weight = weight.min(config.max_voting_power);
 
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
-        env.storage().persistent().set(&voter_key, &true);
+    let (for_votes, against_votes, abstain_votes) = proposal.votes_mut();
+    let tally = match vote_type {
+        1 => for_votes,
+        2 => against_votes,
+        _ => abstain_votes,
+    };
+    *tally = tally
+        .checked_add(capped_weight)
+        .ok_or(GovernanceError::Overflow)?;
 
-        emit_vote_cast(env, proposal_id, voter, vote_type, weight);
+    proposal.save(env);
+    env.storage().persistent().set(&voter_key, &true);
 
-        return Ok(());
-    }
+    emit_vote_cast(env, proposal_id, voter, vote_type, weight);
 
-    Err(SavingsError::PlanNotFound)
+    Ok(())
 }
 
 /// Checks if a user has already voted on a proposal
@@ -402,134 +893,82 @@ pub fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
 }
 
 /// Queues a proposal for execution after timelock
-pub fn queue_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError> {
+///
+/// # Errors
+/// * `GovernanceError::VotingNotEnded` - If the proposal is still pending or active
+/// * `GovernanceError::QuorumNotMet` / `GovernanceError::ThresholdNotMet` - If it was defeated
+/// * `GovernanceError::InvalidProposalState` - If it was already queued, executed or canceled
+pub fn queue_proposal(env: &Env, proposal_id: u64) -> Result<(), GovernanceError> {
     let now = env.ledger().timestamp();
+    let mut proposal = StoredProposal::load(env, proposal_id)?;
 
-    if let Some(mut proposal) = get_proposal(env, proposal_id) {
-        if now <= proposal.end_time {
-            return Err(SavingsError::TooEarly);
-        }
-
-        if proposal.queued_time > 0 {
-            return Err(SavingsError::DuplicatePlanId);
-        }
-
-        if proposal.executed {
-            return Err(SavingsError::PlanCompleted);
+    match state_of(env, &proposal)? {
+        ProposalState::Succeeded => {}
+        ProposalState::Pending | ProposalState::Active => {
+            return Err(GovernanceError::VotingNotEnded)
         }
-
-        if proposal.for_votes <= proposal.against_votes {
-            return Err(SavingsError::InsufficientBalance);
+        ProposalState::Defeated => {
+            return Err(match evaluate_votes(env, &proposal)? {
+                Some(ProposalFailure::QuorumNotMet) => GovernanceError::QuorumNotMet,
+                _ => GovernanceError::ThresholdNotMet,
+            })
         }
-
-        proposal.queued_time = now;
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::Proposal(proposal_id), &proposal);
-
-        emit_proposal_queued(env, proposal_id, now);
-
-        return Ok(());
+        _ => return Err(GovernanceError::InvalidProposalState),
     }
 
-    if let Some(mut proposal) = get_action_proposal(env, proposal_id) {
-        if now <= proposal.end_time {
-            return Err(SavingsError::TooEarly);
-        }
-
-        if proposal.queued_time > 0 {
-            return Err(SavingsError::DuplicatePlanId);
-        }
-
-        if proposal.executed {
-            return Err(SavingsError::PlanCompleted);
-        }
-
-        if proposal.for_votes <= proposal.against_votes {
-            return Err(SavingsError::InsufficientBalance);
-        }
-
-        proposal.queued_time = now;
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
-
-        emit_proposal_queued(env, proposal_id, now);
+    proposal.set_queued_time(now);
+    proposal.save(env);
 
-        return Ok(());
-    }
+    emit_proposal_queued(env, proposal_id, now);
 
-    Err(SavingsError::PlanNotFound)
+    Ok(())
 }
 
 /// Executes a queued proposal after timelock period
-pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), SavingsError> {
+///
+/// Actions run in order. One that fails aborts the call with its own
+/// `SavingsError`, rolling back the actions before it and leaving the
+/// proposal queued.
+///
+/// # Errors
+/// * `GovernanceError::TimelockNotElapsed` - If the timelock has not passed yet
+/// * `GovernanceError::ProposalExpired` - If the grace period after the timelock has passed
+/// * `GovernanceError::InvalidProposalState` - If the proposal is not queued
+pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), GovernanceError> {
     let now = env.ledger().timestamp();
-    let config = get_voting_config(env)?;
-
-    if let Some(mut proposal) = get_action_proposal(env, proposal_id) {
-        if proposal.queued_time == 0 {
-            return Err(SavingsError::TooEarly);
-        }
-
-        if proposal.executed {
-            return Err(SavingsError::PlanCompleted);
-        }
-
-        let execution_time = proposal
-            .queued_time
-            .checked_add(config.timelock_duration)
-            .ok_or(SavingsError::Overflow)?;
+    let mut proposal = StoredProposal::load(env, proposal_id)?;
 
-        if now < execution_time {
-            return Err(SavingsError::TooEarly);
-        }
-
-        proposal.executed = true;
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
-
-        execute_action(env, &proposal.action)?;
-
-        emit_proposal_executed(env, proposal_id, now);
-
-        return Ok(());
+    match state_of(env, &proposal)? {
+        ProposalState::Queued => {}
+        ProposalState::Expired => return Err(GovernanceError::ProposalExpired),
+        _ => return Err(GovernanceError::InvalidProposalState),
+    }
+    if now < execution_time(env, &proposal)? {
+        return Err(GovernanceError::TimelockNotElapsed);
     }
 
-    if let Some(mut proposal) = get_proposal(env, proposal_id) {
-        if proposal.queued_time == 0 {
-            return Err(SavingsError::TooEarly);
-        }
-
-        if proposal.executed {
-            return Err(SavingsError::PlanCompleted);
-        }
-
-        let execution_time = proposal
-            .queued_time
-            .checked_add(config.timelock_duration)
-            .ok_or(SavingsError::Overflow)?;
+    proposal.set_executed();
+    proposal.save(env);
 
-        if now < execution_time {
-            return Err(SavingsError::TooEarly);
+    if let Some(actions) = proposal.actions() {
+        for action in actions.iter() {
+            if let Err(e) = execute_action(env, proposal_id, &action) {
+                panic_with_error!(env, e);
+            }
         }
-
-        proposal.executed = true;
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::Proposal(proposal_id), &proposal);
-
-        emit_proposal_executed(env, proposal_id, now);
-
-        return Ok(());
     }
 
-    Err(SavingsError::PlanNotFound)
+    emit_proposal_executed(env, proposal_id, now);
+
+    Ok(())
 }
 
 /// Executes a proposal action
-fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError> {
+fn execute_action(
+    env: &Env,
+    proposal_id: u64,
+    action: &ProposalAction,
+) -> Result<(), SavingsError> {
     match action {
         ProposalAction::SetFlexiRate(rate) => {
             if *rate < 0 {
@@ -571,55 +1010,190 @@ fn execute_action(env: &Env, action: &ProposalAction) -> Result<(), SavingsError
             crate::ttl::extend_config_ttl(env, &DataKey::Paused);
             Ok(())
         }
+        ProposalAction::SetPlatformFee(bps) => config::set_platform_fee_bps_unchecked(env, *bps),
+        ProposalAction::SetProtocolFee(bps) => config::set_protocol_fee_unchecked(env, *bps),
+        ProposalAction::SetEarlyBreakFee(bps) => {
+            config::set_early_break_fee_bps_unchecked(env, *bps)
+        }
+        ProposalAction::SetFeeRecipient(recipient) => {
+            config::set_fee_recipient_unchecked(env, recipient.clone())
+        }
+        ProposalAction::SetTreasury(treasury) => {
+            config::set_treasury_unchecked(env, treasury.clone())
+        }
+        ProposalAction::SetAdmin(admin) => config::set_admin_unchecked(env, admin.clone()),
+        ProposalAction::SetRewardsConfig(rewards_config) => {
+            rewards::config::update_rewards_config_unchecked(env, rewards_config.clone())
+        }
+        ProposalAction::RegisterStrategy(strategy_address, risk_level) => {
+            strategy::registry::register_strategy_unchecked(
+                env,
+                strategy_address.clone(),
+                *risk_level,
+            )
+        }
+        ProposalAction::DisableStrategy(strategy_address) => {
+            strategy::registry::disable_strategy_unchecked(env, strategy_address.clone())
+        }
+        ProposalAction::Upgrade(wasm_hash) => {
+            upgrade::upgrade_contract_unchecked(env, wasm_hash.clone());
+            Ok(())
+        }
+        ProposalAction::SetKeeperConfig(keeper_config) => {
+            keeper::set_keeper_config_unchecked(env, keeper_config.clone())
+        }
+        ProposalAction::SetAutoSaveFailureThreshold(threshold) => {
+            autosave::set_failure_threshold_unchecked(env, *threshold)
+        }
+        ProposalAction::SetPointsExpiryDays(days) => {
+            rewards::expiry::set_expiry_days_unchecked(env, *days)
+        }
+        ProposalAction::SetSavingsTiers(tiers) => {
+            rewards::tiers::set_tiers_unchecked(env, tiers.clone())
+        }
+        ProposalAction::AddCatalogItem(item) => {
+            rewards::redemption::add_catalog_item_unchecked(env, item.clone()).map(|_| ())
+        }
+        ProposalAction::SetCatalogItemActive(item_id, active) => {
+            rewards::redemption::set_catalog_item_active_unchecked(env, *item_id, *active)
+        }
+        ProposalAction::StartSeason(end_time, prizes) => {
+            rewards::seasons::start_season_unchecked(env, *end_time, prizes.clone()).map(|_| ())
+        }
+        ProposalAction::SetReferralConfig(referral_config) => {
+            referral::set_referral_config_unchecked(env, referral_config.clone())
+        }
+        ProposalAction::SetMintConfig(mint_config) => {
+            mint::set_config_unchecked(env, mint_config.clone())
+        }
+        ProposalAction::SetMintSigners(signer_set) => {
+            mint::set_signer_set_unchecked(env, signer_set.clone())
+        }
+        ProposalAction::ClearMintSigners => {
+            mint::clear_signer_set_unchecked(env);
+            Ok(())
+        }
+        ProposalAction::RotateAdminKey(new_public_key, grace_period) => {
+            mint::rotate_admin_key_unchecked(env, new_public_key.clone(), *grace_period)
+        }
+        ProposalAction::SetWebAuthnRelyingParty(relying_party) => {
+            signer::set_relying_party_unchecked(env, relying_party.clone())
+        }
+        ProposalAction::SetBalanceVoteWeight(weight_bps) => {
+            voting_power::set_balance_weight_bps_unchecked(env, *weight_bps)
+        }
+        ProposalAction::SetExecutionGracePeriod(grace_period) => {
+            set_execution_grace_period_unchecked(env, *grace_period)
+        }
+        ProposalAction::SetGuardian(guardian) => {
+            set_guardian_unchecked(env, guardian.clone());
+            Ok(())
+        }
+        ProposalAction::TreasuryTransfer(recipient, amount) => {
+            treasury::spend_unchecked(env, proposal_id, recipient, *amount)
+        }
     }
 }
 
-/// Cancels a proposal (creator or admin only)
-pub fn cancel_proposal(env: &Env, proposal_id: u64, caller: Address) -> Result<(), SavingsError> {
+/// Cancels a proposal that has not been queued yet (creator only)
+pub fn cancel_proposal(
+    env: &Env,
+    proposal_id: u64,
+    caller: Address,
+) -> Result<(), GovernanceError> {
     caller.require_auth();
 
-    // Try regular proposal
-    if let Some(mut proposal) = get_proposal(env, proposal_id) {
-        if proposal.creator != caller {
-            return Err(SavingsError::Unauthorized);
-        }
+    let mut proposal = StoredProposal::load(env, proposal_id)?;
+    if proposal.creator() != &caller {
+        return Err(GovernanceError::NotProposer);
+    }
 
-        if proposal.executed || proposal.queued_time > 0 {
-            return Err(SavingsError::TooLate);
-        }
+    match state_of(env, &proposal)? {
+        ProposalState::Pending
+        | ProposalState::Active
+        | ProposalState::Succeeded
+        | ProposalState::Defeated => {}
+        _ => return Err(GovernanceError::InvalidProposalState),
+    }
 
-        // Mark as canceled (you may want a separate canceled field later)
-        proposal.executed = true;
-        env.storage()
-            .persistent()
-            .set(&GovernanceKey::Proposal(proposal_id), &proposal);
+    proposal.set_canceled();
+    proposal.save(env);
 
-        emit_proposal_canceled(env, proposal_id, env.ledger().timestamp());
+    emit_proposal_canceled(env, proposal_id, env.ledger().timestamp());
 
-        return Ok(());
-    }
+    Ok(())
+}
 
-    // Try action proposal
-    if let Some(mut proposal) = get_action_proposal(env, proposal_id) {
-        if proposal.creator != caller {
-            return Err(SavingsError::Unauthorized);
-        }
+/// Returns the guardian, if one is set
+pub fn get_guardian(env: &Env) -> Option<Address> {
+    env.storage().persistent().get(&GovernanceKey::Guardian)
+}
 
-        if proposal.executed || proposal.queued_time > 0 {
-            return Err(SavingsError::TooLate);
-        }
+/// Returns true if `caller` is the guardian
+pub fn is_guardian(env: &Env, caller: &Address) -> bool {
+    get_guardian(env).as_ref() == Some(caller)
+}
 
-        proposal.executed = true;
-        env.storage()
+/// Sets or removes the guardian (admin, before governance is active)
+///
+/// Once governance is active the guardian can only be changed by a passed
+/// `SetGuardian` proposal, so it cannot be removed ahead of a bad proposal.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+pub fn set_guardian(
+    env: &Env,
+    caller: Address,
+    guardian: Option<Address>,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    require_admin_before_governance(env, &caller)?;
+    set_guardian_unchecked(env, guardian);
+    Ok(())
+}
+
+/// Sets or removes the guardian without an authorization check.
+pub(crate) fn set_guardian_unchecked(env: &Env, guardian: Option<Address>) {
+    let previous = get_guardian(env);
+    match &guardian {
+        Some(guardian) => env
+            .storage()
             .persistent()
-            .set(&GovernanceKey::ActionProposal(proposal_id), &proposal);
+            .set(&GovernanceKey::Guardian, guardian),
+        None => env.storage().persistent().remove(&GovernanceKey::Guardian),
+    }
+    emit_guardian_changed(env, previous, guardian);
+}
 
-        emit_proposal_canceled(env, proposal_id, env.ledger().timestamp());
+/// Vetoes a queued proposal before its timelock ends (guardian only)
+///
+/// # Errors
+/// * `GovernanceError::NotGuardian` - If the caller is not the guardian
+/// * `GovernanceError::InvalidProposalState` - If the proposal is not queued or
+///   its timelock has already elapsed
+pub fn veto_proposal(
+    env: &Env,
+    guardian: Address,
+    proposal_id: u64,
+) -> Result<(), GovernanceError> {
+    guardian.require_auth();
+    if !is_guardian(env, &guardian) {
+        return Err(GovernanceError::NotGuardian);
+    }
 
-        return Ok(());
+    let now = env.ledger().timestamp();
+    let mut proposal = StoredProposal::load(env, proposal_id)?;
+    if state_of(env, &proposal)? != ProposalState::Queued || now >= execution_time(env, &proposal)?
+    {
+        return Err(GovernanceError::InvalidProposalState);
     }
 
-    Err(SavingsError::PlanNotFound)
+    proposal.set_vetoed();
+    proposal.save(env);
+
+    emit_proposal_vetoed(env, proposal_id, guardian, now);
+
+    Ok(())
 }
 
 /// Checks if governance is active
@@ -651,6 +1225,21 @@ pub fn activate_governance(env: &Env, admin: Address) -> Result<(), SavingsError
     Ok(())
 }
 
+/// Validates a direct call to an admin setter
+///
+/// Before governance is active only the admin may make it. Afterwards the
+/// setting changes only through an executed proposal, which calls the
+/// `_unchecked` setter, so direct calls are rejected for every caller.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+pub fn require_admin_before_governance(env: &Env, caller: &Address) -> Result<(), SavingsError> {
+    if validate_admin_or_governance(env, caller)? {
+        return Err(SavingsError::Unauthorized);
+    }
+    Ok(())
+}
+
 /// Validates caller is admin or governance is active
 pub fn validate_admin_or_governance(env: &Env, caller: &Address) -> Result<bool, SavingsError> {
     if is_governance_active(env) {
@@ -38,6 +38,29 @@ pub struct ProposalCanceled {
     pub canceled_at: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVetoed {
+    pub proposal_id: u64,
+    pub guardian: Address,
+    pub vetoed_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianChanged {
+    pub previous: Option<Address>,
+    pub guardian: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DelegateChanged {
+    pub delegator: Address,
+    pub from_delegate: Address,
+    pub to_delegate: Address,
+}
+
 pub fn emit_proposal_created(env: &Env, proposal_id: u64, creator: Address, description: String) {
     let event = ProposalCreated {
         proposal_id,
@@ -89,3 +112,36 @@ pub fn emit_proposal_canceled(env: &Env, proposal_id: u64, canceled_at: u64) {
     env.events()
         .publish((symbol_short!("gov"), symbol_short!("canceled")), event);
 }
+
+pub fn emit_proposal_vetoed(env: &Env, proposal_id: u64, guardian: Address, vetoed_at: u64) {
+    let event = ProposalVetoed {
+        proposal_id,
+        guardian,
+        vetoed_at,
+    };
+    env.events()
+        .publish((symbol_short!("gov"), symbol_short!("vetoed")), event);
+}
+
+pub fn emit_guardian_changed(env: &Env, previous: Option<Address>, guardian: Option<Address>) {
+    let event = GuardianChanged { previous, guardian };
+    env.events()
+        .publish((symbol_short!("gov"), symbol_short!("guardian")), event);
+}
+
+pub fn emit_delegate_changed(
+    env: &Env,
+    delegator: Address,
+    from_delegate: Address,
+    to_delegate: Address,
+) {
+    let event = DelegateChanged {
+        delegator: delegator.clone(),
+        from_delegate,
+        to_delegate,
+    };
+    env.events().publish(
+        (symbol_short!("gov"), symbol_short!("delegate"), delegator),
+        event,
+    );
+}
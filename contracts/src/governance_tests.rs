@@ -7,11 +7,11 @@ mod governance_tests {
         env.mock_all_auths();
         client.init_voting_config(&admin, &5000, &10, &5, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
-        client.initialize_user(&voter1);
-        client.initialize_user(&voter2);
+        client.initialize_user(&voter1, &None);
+        client.initialize_user(&voter2, &None);
         client.create_savings_plan(&voter1, &PlanType::Flexi, &10000);
         client.create_savings_plan(&voter2, &PlanType::Flexi, &20000);
 
@@ -36,9 +36,9 @@ mod governance_tests {
         env.mock_all_auths();
         client.init_voting_config(&admin, &5000, &10, &5, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let attacker = Address::generate(&env);
-        client.initialize_user(&attacker);
+        client.initialize_user(&attacker, &None);
         client.create_savings_plan(&attacker, &PlanType::Flexi, &50); // Not enough power
 
         let desc = String::from_str(&env, "Attack proposal");
@@ -46,6 +46,7 @@ mod governance_tests {
 
         // Attacker tries to vote multiple times
         client.vote(&proposal_id, &1, &attacker);
+        client.vote(&proposal_id, &1, &creator);
         let result = client.try_vote(&proposal_id, &1, &attacker);
         assert!(result.is_err()); // No double voting
 
@@ -96,6 +97,14 @@ mod governance_tests {
         (env, client, admin)
     }
 
+    /// A user holding enough voting power to pass the proposal threshold
+    fn proposer(env: &Env, client: &NesteraContractClient) -> Address {
+        let creator = Address::generate(env);
+        client.initialize_user(&creator, &None);
+        client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
+        creator
+    }
+
     // ────────────────────────────────────────────────────────────────────────────────
     // Existing tests (kept + fixed unwrap usage)
     // ────────────────────────────────────────────────────────────────────────────────
@@ -115,7 +124,7 @@ mod governance_tests {
         let user = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
 
         let power = client.get_voting_power(&user);
@@ -128,7 +137,7 @@ mod governance_tests {
         let user = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
         let _ = client.create_savings_plan(&user, &PlanType::Flexi, &1000);
         let _ = client.create_savings_plan(&user, &PlanType::Flexi, &500);
 
@@ -157,7 +166,7 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let description = String::from_str(&env, "Test proposal");
 
         let proposal_id = client.create_proposal(&creator, &description);
@@ -172,7 +181,7 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let description = String::from_str(&env, "Test proposal");
         let proposal_id = client.create_proposal(&creator, &description);
 
@@ -194,7 +203,7 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let desc1 = String::from_str(&env, "Proposal 1");
         let desc2 = String::from_str(&env, "Proposal 2");
 
@@ -214,7 +223,7 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let description = String::from_str(&env, "Store test");
         let proposal_id = client.create_proposal(&creator, &description);
 
@@ -237,7 +246,7 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let description = String::from_str(&env, "Test proposal description");
 
         let proposal_id = client.create_proposal(&creator, &description);
@@ -273,10 +282,10 @@ mod governance_tests {
 
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
-        let creator = Address::generate(&env);
+        let creator = proposer(&env, &client);
         let voter = Address::generate(&env);
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         client.create_savings_plan(&voter, &PlanType::Flexi, &10000);
 
         let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Vote test"));
@@ -299,4 +308,407 @@ mod governance_tests {
         assert_eq!(event_data.vote_type, 1);
         assert!(event_data.weight > 0);
     }
+
+    use crate::governance::{ProposalAction, ProposalState};
+    use crate::GovernanceError;
+
+    const VOTING_PERIOD: u64 = 604800;
+    const TIMELOCK: u64 = 86400;
+
+    fn setup_with_voter() -> (Env, NesteraContractClient<'static>, Address, Address) {
+        let (env, client, admin) = setup_contract();
+        env.mock_all_auths();
+        client.init_voting_config(&admin, &5000, &VOTING_PERIOD, &TIMELOCK, &100, &10_000);
+
+        let voter = Address::generate(&env);
+        client.initialize_user(&voter, &None);
+        client.create_savings_plan(&voter, &PlanType::Flexi, &5000);
+        (env, client, admin, voter)
+    }
+
+    fn passed_proposal(env: &Env, client: &NesteraContractClient, voter: &Address) -> u64 {
+        let proposal_id = client.create_proposal(voter, &String::from_str(env, "Lifecycle"));
+        client.vote(&proposal_id, &1, voter);
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        proposal_id
+    }
+
+    #[test]
+    fn test_proposal_state_follows_lifecycle() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let proposal_id = client.create_proposal(&voter, &String::from_str(&env, "Lifecycle"));
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Active
+        );
+
+        client.vote(&proposal_id, &1, &voter);
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Succeeded
+        );
+
+        client.queue_proposal(&proposal_id);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Queued
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        client.execute_proposal(&proposal_id);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Executed
+        );
+    }
+
+    #[test]
+    fn test_proposal_without_votes_is_defeated() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let proposal_id = client.create_proposal(&voter, &String::from_str(&env, "Ignored"));
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Defeated
+        );
+        assert_eq!(
+            client.try_queue_proposal(&proposal_id),
+            Err(Ok(GovernanceError::QuorumNotMet))
+        );
+    }
+
+    #[test]
+    fn test_queued_proposal_expires_after_grace_period() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let proposal_id = passed_proposal(&env, &client, &voter);
+        client.queue_proposal(&proposal_id);
+
+        let grace = client.get_execution_grace_period();
+        env.ledger()
+            .with_mut(|li| li.timestamp += TIMELOCK + grace + 1);
+
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Expired
+        );
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(GovernanceError::ProposalExpired))
+        );
+    }
+
+    #[test]
+    fn test_execution_grace_period_is_configurable() {
+        let (env, client, admin, voter) = setup_with_voter();
+        client.set_execution_grace_period(&admin, &3600);
+        assert_eq!(client.get_execution_grace_period(), 3600);
+        assert!(client.try_set_execution_grace_period(&admin, &0).is_err());
+
+        let proposal_id = passed_proposal(&env, &client, &voter);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK + 3600);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Queued
+        );
+        client.execute_proposal(&proposal_id);
+    }
+
+    #[test]
+    fn test_cancel_proposal() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let other = Address::generate(&env);
+        let proposal_id = client.create_proposal(&voter, &String::from_str(&env, "Withdrawn"));
+
+        assert_eq!(
+            client.try_cancel_proposal(&other, &proposal_id),
+            Err(Ok(GovernanceError::NotProposer))
+        );
+        client.cancel_proposal(&voter, &proposal_id);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Canceled
+        );
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &voter),
+            Err(Ok(GovernanceError::VotingClosed))
+        );
+    }
+
+    #[test]
+    fn test_queued_proposal_cannot_be_canceled() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let proposal_id = passed_proposal(&env, &client, &voter);
+        client.queue_proposal(&proposal_id);
+
+        assert_eq!(
+            client.try_cancel_proposal(&voter, &proposal_id),
+            Err(Ok(GovernanceError::InvalidProposalState))
+        );
+    }
+
+    #[test]
+    fn test_governance_errors() {
+        let (env, client, _admin, voter) = setup_with_voter();
+        let proposal_id = client.create_proposal(&voter, &String::from_str(&env, "Errors"));
+
+        assert_eq!(
+            client.try_vote(&proposal_id, &4, &voter),
+            Err(Ok(GovernanceError::InvalidVoteType))
+        );
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &Address::generate(&env)),
+            Err(Ok(GovernanceError::NoVotingPower))
+        );
+        client.vote(&proposal_id, &1, &voter);
+        assert_eq!(
+            client.try_vote(&proposal_id, &1, &voter),
+            Err(Ok(GovernanceError::AlreadyVoted))
+        );
+        assert_eq!(
+            client.try_queue_proposal(&proposal_id),
+            Err(Ok(GovernanceError::VotingNotEnded))
+        );
+        assert_eq!(
+            client.try_get_proposal_state(&99),
+            Err(Ok(GovernanceError::ProposalNotFound))
+        );
+
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        client.queue_proposal(&proposal_id);
+        assert_eq!(
+            client.try_queue_proposal(&proposal_id),
+            Err(Ok(GovernanceError::InvalidProposalState))
+        );
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(GovernanceError::TimelockNotElapsed))
+        );
+    }
+
+    #[test]
+    fn test_action_proposal_below_threshold_is_rejected() {
+        let (env, client, _admin, _voter) = setup_with_voter();
+        let small = Address::generate(&env);
+        client.initialize_user(&small, &None);
+        client.create_savings_plan(&small, &PlanType::Flexi, &50);
+
+        assert_eq!(
+            client.try_create_action_proposal(
+                &small,
+                &String::from_str(&env, "Too small"),
+                &ProposalAction::SetFlexiRate(100),
+            ),
+            Err(Ok(GovernanceError::BelowProposalThreshold))
+        );
+    }
+
+    #[test]
+    fn test_plain_proposal_below_threshold_is_rejected() {
+        let (env, client, _admin, _voter) = setup_with_voter();
+        let small = Address::generate(&env);
+        client.initialize_user(&small, &None);
+        client.create_savings_plan(&small, &PlanType::Flexi, &50);
+
+        assert_eq!(
+            client.try_create_proposal(&small, &String::from_str(&env, "Too small")),
+            Err(Ok(GovernanceError::BelowProposalThreshold))
+        );
+        assert_eq!(
+            client.try_create_proposal(&Address::generate(&env), &String::from_str(&env, "None")),
+            Err(Ok(GovernanceError::BelowProposalThreshold))
+        );
+    }
+
+    fn with_guardian(env: &Env, client: &NesteraContractClient, admin: &Address) -> Address {
+        let guardian = Address::generate(env);
+        client.set_guardian(admin, &Some(guardian.clone()));
+        guardian
+    }
+
+    #[test]
+    fn test_guardian_vetoes_queued_proposal() {
+        let (env, client, admin, voter) = setup_with_voter();
+        let guardian = with_guardian(&env, &client, &admin);
+        let proposal_id = passed_proposal(&env, &client, &voter);
+        client.queue_proposal(&proposal_id);
+
+        assert_eq!(
+            client.try_veto_proposal(&voter, &proposal_id),
+            Err(Ok(GovernanceError::NotGuardian))
+        );
+        client.veto_proposal(&guardian, &proposal_id);
+        assert_eq!(
+            client.get_proposal_state(&proposal_id),
+            ProposalState::Vetoed
+        );
+
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        assert_eq!(
+            client.try_execute_proposal(&proposal_id),
+            Err(Ok(GovernanceError::InvalidProposalState))
+        );
+    }
+
+    #[test]
+    fn test_veto_only_during_timelock() {
+        let (env, client, admin, voter) = setup_with_voter();
+        let guardian = with_guardian(&env, &client, &admin);
+
+        let active = client.create_proposal(&voter, &String::from_str(&env, "Active"));
+        assert_eq!(
+            client.try_veto_proposal(&guardian, &active),
+            Err(Ok(GovernanceError::InvalidProposalState))
+        );
+
+        let proposal_id = passed_proposal(&env, &client, &voter);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        assert_eq!(
+            client.try_veto_proposal(&guardian, &proposal_id),
+            Err(Ok(GovernanceError::InvalidProposalState))
+        );
+        client.execute_proposal(&proposal_id);
+    }
+
+    #[test]
+    fn test_guardian_can_pause_but_not_unpause() {
+        let (env, client, admin, _voter) = setup_with_voter();
+        let guardian = with_guardian(&env, &client, &admin);
+
+        client.pause(&guardian);
+        assert!(client.is_paused());
+        assert!(client.try_unpause(&guardian).is_err());
+
+        client.set_guardian(&admin, &None);
+        assert_eq!(client.get_guardian(), None);
+        assert!(client.try_pause(&guardian).is_err());
+    }
+
+    #[test]
+    fn test_only_a_proposal_unpauses_once_governance_active() {
+        let (env, client, admin, voter) = setup_with_voter();
+        let guardian = with_guardian(&env, &client, &admin);
+        client.activate_governance(&admin);
+
+        let random = Address::generate(&env);
+        assert!(client.try_pause(&random).is_err());
+        assert!(client.try_pause(&admin).is_err());
+        assert!(client.try_pause_contract(&admin).is_err());
+        client.pause(&guardian);
+        assert!(client.is_paused());
+
+        assert!(client.try_unpause(&random).is_err());
+        assert!(client.try_unpause(&admin).is_err());
+        assert!(client.try_unpause_contract(&admin).is_err());
+        assert!(client.is_paused());
+
+        let proposal_id = client.create_action_proposal(
+            &voter,
+            &String::from_str(&env, "Unpause"),
+            &ProposalAction::UnpauseContract,
+        );
+        client.vote(&proposal_id, &1, &voter);
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        client.execute_proposal(&proposal_id);
+        assert!(!client.is_paused());
+    }
+
+    #[test]
+    fn test_legacy_proposals_still_load_and_execute() {
+        use crate::governance::{GovernanceKey, LegacyActionProposal, LegacyProposal};
+
+        let (env, client, _admin, voter) = setup_with_voter();
+        env.ledger().with_mut(|li| li.timestamp += 1);
+        let now = env.ledger().timestamp();
+        let description = String::from_str(&env, "Stored before batches");
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &GovernanceKey::Proposal(1),
+                &LegacyProposal {
+                    id: 1,
+                    creator: voter.clone(),
+                    description: description.clone(),
+                    start_time: now,
+                    end_time: now + VOTING_PERIOD,
+                    executed: false,
+                    for_votes: 0,
+                    against_votes: 0,
+                    abstain_votes: 0,
+                    queued_time: 0,
+                },
+            );
+            env.storage().persistent().set(
+                &GovernanceKey::ActionProposal(2),
+                &LegacyActionProposal {
+                    id: 2,
+                    creator: voter.clone(),
+                    description: description.clone(),
+                    start_time: now,
+                    end_time: now + VOTING_PERIOD,
+                    executed: false,
+                    for_votes: 0,
+                    against_votes: 0,
+                    abstain_votes: 0,
+                    action: ProposalAction::SetFlexiRate(250),
+                    queued_time: 0,
+                },
+            );
+        });
+
+        let proposal = client.get_proposal(&1).unwrap();
+        assert!(!proposal.canceled && !proposal.vetoed);
+        let action_proposal = client.get_action_proposal(&2).unwrap();
+        assert_eq!(
+            action_proposal.actions,
+            soroban_sdk::vec![&env, ProposalAction::SetFlexiRate(250)]
+        );
+
+        client.vote(&2, &1, &voter);
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        client.queue_proposal(&2);
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        client.execute_proposal(&2);
+        assert_eq!(client.get_flexi_rate(), 250);
+        assert!(client.get_action_proposal(&2).unwrap().executed);
+    }
+
+    #[test]
+    fn test_guardian_rotated_only_by_proposal_once_governance_active() {
+        let (env, client, admin, voter) = setup_with_voter();
+        let guardian = with_guardian(&env, &client, &admin);
+        client.activate_governance(&admin);
+        assert!(client
+            .try_set_guardian(&voter, &Some(voter.clone()))
+            .is_err());
+
+        let next = Address::generate(&env);
+        let proposal_id = client.create_action_proposal(
+            &voter,
+            &String::from_str(&env, "Rotate guardian"),
+            &ProposalAction::SetGuardian(Some(next.clone())),
+        );
+        client.vote(&proposal_id, &1, &voter);
+        env.ledger()
+            .with_mut(|li| li.timestamp += VOTING_PERIOD + 1);
+        client.queue_proposal(&proposal_id);
+        env.ledger().with_mut(|li| li.timestamp += TIMELOCK);
+        client.execute_proposal(&proposal_id);
+
+        assert_eq!(client.get_guardian(), Some(next));
+        assert_eq!(
+            client.try_veto_proposal(&guardian, &proposal_id),
+            Err(Ok(GovernanceError::NotGuardian))
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::rates;
+use crate::rewards::storage_types::RatePlan;
 use crate::storage_types::{DataKey, GroupSave};
 use crate::ttl;
 use crate::users;
@@ -133,7 +135,7 @@ pub fn create_group_save(
         start_time: now,
         last_deposit: 0,
         last_withdraw: 0,
-        interest_rate: 500, // Default 5%
+        interest_rate: rates::plan_interest_rate(env, &creator, RatePlan::Group),
         is_completed: false,
         is_withdrawn: false,
     };
@@ -320,7 +322,7 @@ pub fn join_group_save(env: &Env, user: Address, group_id: u64) -> Result<(), Sa
         start_time: now,
         last_deposit: 0,
         last_withdraw: 0,
-        interest_rate: 500, // Default 5%
+        interest_rate: rates::plan_interest_rate(env, &user, RatePlan::Group),
         is_completed: group.is_completed,
         is_withdrawn: false,
     };
@@ -445,7 +447,7 @@ pub fn contribute_to_group_save(
             start_time: now,
             last_deposit: now,
             last_withdraw: 0,
-            interest_rate: 500,
+            interest_rate: rates::plan_interest_rate(env, &user, RatePlan::Group),
             is_completed: group.is_completed,
             is_withdrawn: false,
         };
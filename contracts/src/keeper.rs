@@ -0,0 +1,167 @@
+use crate::errors::SavingsError;
+use crate::flexi;
+use crate::governance;
+use crate::invariants;
+use crate::storage_types::{AutoSave, DataKey, KeeperConfig, KeeperFeeSource, KeeperStats};
+use crate::ttl;
+use soroban_sdk::{symbol_short, Address, Env};
+
+/// Sets the bounty paid to keepers for executing due AutoSave schedules.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `caller` - The admin; once governance is active only a `SetKeeperConfig`
+///   proposal can change the config
+/// * `config` - The new keeper configuration
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidAmount` - If the fee or minimum amount is negative
+pub fn set_keeper_config(
+    env: &Env,
+    caller: Address,
+    config: KeeperConfig,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_keeper_config_unchecked(env, config)
+}
+
+/// Applies a keeper configuration without the admin check; used by governance
+pub(crate) fn set_keeper_config_unchecked(
+    env: &Env,
+    config: KeeperConfig,
+) -> Result<(), SavingsError> {
+    if config.fee < 0 || config.min_amount < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::KeeperConfig, &config);
+    env.events()
+        .publish((symbol_short!("set_kpr"),), config.fee);
+    Ok(())
+}
+
+/// Returns the keeper configuration, if one has been set
+pub fn get_keeper_config(env: &Env) -> Option<KeeperConfig> {
+    env.storage().instance().get(&DataKey::KeeperConfig)
+}
+
+/// Moves `amount` from the funder's Flexi balance into the keeper bounty pool.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If amount <= 0
+/// * `SavingsError::InsufficientBalance` - If the funder's Flexi balance is too low
+pub fn fund_keeper_pool(env: &Env, funder: Address, amount: i128) -> Result<(), SavingsError> {
+    funder.require_auth();
+    invariants::assert_non_negative(amount)?;
+
+    flexi::debit_flexi_balance(env, &funder, amount)?;
+
+    let pool = get_keeper_pool_balance(env)
+        .checked_add(amount)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&DataKey::KeeperPool, &pool);
+    ttl::extend_config_ttl(env, &DataKey::KeeperPool);
+
+    env.events()
+        .publish((symbol_short!("kpr_fund"), funder), amount);
+    Ok(())
+}
+
+/// Returns the balance available for paying keeper bounties from the pool
+pub fn get_keeper_pool_balance(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KeeperPool)
+        .unwrap_or(0)
+}
+
+/// Returns the execution tally and earnings for a keeper
+pub fn get_keeper_stats(env: &Env, keeper: &Address) -> KeeperStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::KeeperStats(keeper.clone()))
+        .unwrap_or_default()
+}
+
+/// Computes the bounty `keeper` would earn for executing `schedule` right now.
+///
+/// Callers must only use this for schedules that are due; the bounty is
+/// withheld entirely when:
+/// - no keeper fee is configured,
+/// - the keeper owns the schedule,
+/// - the schedule interval is shorter than `min_interval_seconds`,
+/// - the schedule deposits less than `min_amount` per run,
+/// - a deposit-funded fee would consume the whole deposit, or
+/// - the pool cannot cover a pool-funded fee.
+pub fn bounty_for(env: &Env, keeper: &Address, schedule: &AutoSave) -> (i128, KeeperFeeSource) {
+    let config = match get_keeper_config(env) {
+        Some(config) if config.fee > 0 => config,
+        _ => return (0, KeeperFeeSource::ProtocolPool),
+    };
+
+    if *keeper == schedule.user
+        || schedule.interval_seconds < config.min_interval_seconds
+        || schedule.amount < config.min_amount
+    {
+        return (0, config.source);
+    }
+
+    let affordable = match config.source {
+        KeeperFeeSource::UserDeposit => config.fee < schedule.amount,
+        KeeperFeeSource::ProtocolPool => config.fee <= get_keeper_pool_balance(env),
+    };
+
+    if affordable {
+        (config.fee, config.source)
+    } else {
+        (0, config.source)
+    }
+}
+
+/// Records a successful execution by `keeper` and pays out `fee`.
+///
+/// For `ProtocolPool` the fee is drawn from the pool; for `UserDeposit` it has
+/// already been withheld from the scheduled deposit. Either way it is credited
+/// to the keeper's Flexi balance.
+pub fn record_execution(
+    env: &Env,
+    keeper: &Address,
+    schedule_id: u64,
+    fee: i128,
+    source: KeeperFeeSource,
+) -> Result<(), SavingsError> {
+    if fee > 0 {
+        if source == KeeperFeeSource::ProtocolPool {
+            let pool = get_keeper_pool_balance(env);
+            invariants::assert_sufficient_balance(pool, fee)?;
+            env.storage()
+                .persistent()
+                .set(&DataKey::KeeperPool, &(pool - fee));
+            ttl::extend_config_ttl(env, &DataKey::KeeperPool);
+        }
+        flexi::credit_flexi_balance(env, keeper, fee)?;
+    }
+
+    let stats_key = DataKey::KeeperStats(keeper.clone());
+    let mut stats = get_keeper_stats(env, keeper);
+    stats.executions = stats
+        .executions
+        .checked_add(1)
+        .ok_or(SavingsError::Overflow)?;
+    stats.total_earned = stats
+        .total_earned
+        .checked_add(fee)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&stats_key, &stats);
+    ttl::extend_config_ttl(env, &stats_key);
+
+    env.events().publish(
+        (symbol_short!("kpr_exec"), keeper.clone(), schedule_id),
+        fee,
+    );
+    Ok(())
+}
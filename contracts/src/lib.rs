@@ -1,11 +1,12 @@
 #![no_std]
 #![allow(non_snake_case)]
 use soroban_sdk::{
-    contract, contractimpl, panic_with_error, symbol_short, xdr::ToXdr, Address, Bytes, BytesN,
-    Env, String, Symbol, Vec,
+    contract, contractimpl, panic_with_error, symbol_short, Address, Bytes, BytesN, Env, String,
+    Symbol, Vec,
 };
 
 mod autosave;
+mod ballots;
 mod config;
 mod errors;
 mod flexi;
@@ -14,28 +15,43 @@ mod governance;
 mod governance_events;
 mod group;
 mod invariants;
+mod keeper;
 mod lock;
+mod mint;
+mod referral;
 
 pub mod rewards;
 mod storage_types;
 pub mod strategy;
+mod treasury;
 mod ttl;
 mod upgrade;
 mod users;
+mod voting_power;
 
 mod security;
+mod signer;
 
 mod rates;
 mod views;
 
+pub use crate::ballots::{Ballot, BallotMessage, SignedBallot};
 pub use crate::config::Config;
-pub use crate::errors::SavingsError;
+pub use crate::errors::{GovernanceError, SavingsError};
+pub use crate::mint::MintMessage;
+pub use crate::rewards::{
+    ActiveBenefit, PointLot, RatePlan, RedemptionBenefit, RedemptionItem, SavingsTier, Season,
+};
 pub use crate::storage_types::{
-    AutoSave, DataKey, GoalSave, GoalSaveView, GroupSave, GroupSaveView, LockSave, LockSaveView,
-    MintPayload, PlanType, SavingsPlan, StrategyPerformance, User,
+    AutoSave, AutoSaveCursor, AutoSaveDuePage, AutoSaveTarget, CatchUpPolicy, DataKey, GoalSave,
+    GoalSaveView, GroupSave, GroupSaveView, KeeperConfig, KeeperFeeSource, KeeperStats, LockSave,
+    LockSaveView, MintConfig, MintPayload, MintSignature, MintSignerSet, MintTarget, PlanType,
+    ReferralConfig, ReferralStats, RetiredAdminKey, SavingsPlan, SignerKey, SignerProof,
+    StrategyPerformance, User, WebAuthnAssertion, WebAuthnRelyingParty,
 };
 pub use crate::strategy::registry::StrategyInfo;
 pub use crate::strategy::routing::{StrategyPosition, StrategyPositionKey};
+pub use crate::treasury::TreasurySpend;
 
 /// Custom error codes for the contract administration
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -45,6 +61,12 @@ pub enum ContractError {
     NotInitialized = 2,
     InvalidSignature = 3,
     SignatureExpired = 4,
+    MintPayloadAlreadyUsed = 5,
+    InvalidMintAmount = 6,
+    UserMintCapExceeded = 7,
+    GlobalMintCapExceeded = 8,
+    UnknownSigner = 9,
+    InsufficientSignatures = 10,
 }
 
 impl From<ContractError> for soroban_sdk::Error {
@@ -82,6 +104,21 @@ pub(crate) fn calculate_fee(amount: i128, fee_bps: u32) -> Result<i128, SavingsE
     Ok(total / 10_000)
 }
 
+/// Protocol fee owed by `user`, after the better of their tier discount and any
+/// fee discount they have redeemed points for
+pub(crate) fn calculate_user_fee(
+    env: &Env,
+    user: &Address,
+    amount: i128,
+    fee_bps: u32,
+) -> Result<i128, SavingsError> {
+    let discount_bps = rewards::redemption::fee_discount_bps(env, user)
+        .max(rewards::tiers::fee_discount_bps(env, user));
+    let discounted_bps =
+        (fee_bps as u64 * (10_000 - discount_bps.min(10_000)) as u64 / 10_000) as u32;
+    calculate_fee(amount, discounted_bps)
+}
+
 #[cfg(test)]
 mod fee_tests {
     use super::calculate_fee;
@@ -158,9 +195,10 @@ impl NesteraContract {
         }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::AdminPublicKey, &admin_public_key);
+        env.storage().instance().set(
+            &DataKey::AdminPublicKey,
+            &SignerKey::Ed25519(admin_public_key.clone()),
+        );
         env.storage().instance().set(&DataKey::Initialized, &true);
         env.storage().persistent().set(&DataKey::Paused, &false);
 
@@ -174,34 +212,131 @@ impl NesteraContract {
             .publish((symbol_short!("init"),), admin_public_key);
     }
 
-    pub fn verify_signature(env: Env, payload: MintPayload, signature: BytesN<64>) -> bool {
-        if !env.storage().instance().has(&DataKey::Initialized) {
-            panic_with_error!(&env, ContractError::NotInitialized);
-        }
-        let current_timestamp = env.ledger().timestamp();
-        let expiry_time = payload.timestamp + payload.expiry_duration;
-        if current_timestamp > expiry_time {
-            panic_with_error!(&env, ContractError::SignatureExpired);
-        }
-        let admin_public_key: BytesN<32> = env
-            .storage()
-            .instance()
-            .get(&DataKey::AdminPublicKey)
-            .expect("Admin PK not found");
-        let payload_bytes: Bytes = payload.to_xdr(&env);
-        env.crypto()
-            .ed25519_verify(&admin_public_key, &payload_bytes, &signature);
+    /// Verifies a payload's signatures; see `set_mint_signers` for when
+    /// several signatures are required
+    pub fn verify_signature(
+        env: Env,
+        payload: MintPayload,
+        signatures: Vec<MintSignature>,
+    ) -> bool {
+        mint::verify(&env, &payload, &signatures);
         true
     }
 
-    pub fn mint(env: Env, payload: MintPayload, signature: BytesN<64>) -> i128 {
-        Self::verify_signature(env.clone(), payload.clone(), signature);
-        let amount = payload.amount;
+    /// Replaces the admin signing key; the old key stays valid for `grace_period` seconds
+    /// (admin only, and only through proposals once governance is active)
+    pub fn rotate_admin_public_key(
+        env: Env,
+        caller: Address,
+        new_public_key: SignerKey,
+        grace_period: u64,
+    ) -> Result<(), SavingsError> {
+        mint::rotate_admin_key(&env, caller, new_public_key, grace_period)
+    }
+
+    pub fn get_admin_public_key(env: Env) -> Option<SignerKey> {
+        mint::get_admin_key(&env)
+    }
+
+    /// Checks a proof by an ed25519 or secp256r1 (passkey) key over `message`.
+    ///
+    /// Fails with `SavingsError::InvalidSignature` if the proof does not match
+    /// the key type or a passkey assertion is not over `message` for the
+    /// relying party set with `set_webauthn_relying_party`; a bad
+    /// cryptographic signature aborts the invocation.
+    pub fn verify_signed_message(
+        env: Env,
+        signer: SignerKey,
+        message: Bytes,
+        proof: SignerProof,
+    ) -> Result<(), SavingsError> {
+        signer::verify(&env, &signer, &message, &proof)
+    }
+
+    /// Sets the RP ID hash and origin passkey assertions must carry (admin
+    /// only, and only through proposals once governance is active)
+    pub fn set_webauthn_relying_party(
+        env: Env,
+        caller: Address,
+        relying_party: WebAuthnRelyingParty,
+    ) -> Result<(), SavingsError> {
+        signer::set_relying_party(&env, caller, relying_party)
+    }
+
+    pub fn get_webauthn_relying_party(env: Env) -> Option<WebAuthnRelyingParty> {
+        signer::get_relying_party(&env)
+    }
+
+    pub fn get_retired_admin_public_key(env: Env) -> Option<RetiredAdminKey> {
+        mint::get_retired_admin_key(&env)
+    }
+
+    /// Requires M-of-N co-signing for mints of at least `large_amount` (admin
+    /// only, and only through proposals once governance is active)
+    pub fn set_mint_signers(
+        env: Env,
+        caller: Address,
+        signer_set: MintSignerSet,
+    ) -> Result<(), SavingsError> {
+        mint::set_signer_set(&env, caller, signer_set)
+    }
+
+    /// Removes the mint signer set (admin only, and only through proposals once
+    /// governance is active)
+    pub fn clear_mint_signers(env: Env, caller: Address) -> Result<(), SavingsError> {
+        mint::clear_signer_set(&env, caller)
+    }
+
+    pub fn get_mint_signers(env: Env) -> Option<MintSignerSet> {
+        mint::get_signer_set(&env)
+    }
+
+    /// Returns the bytes the admin key signs for `payload`.
+    ///
+    /// This is the XDR of a `MintMessage`, which binds the payload to this
+    /// contract's address and the network passphrase hash.
+    pub fn get_mint_message(env: Env, payload: MintPayload) -> Bytes {
+        mint::signing_message(&env, &payload)
+    }
+
+    /// Returns true if the payload with this (user, nonce) has already been minted
+    pub fn is_mint_nonce_used(env: Env, user: Address, nonce: u64) -> bool {
+        mint::is_nonce_used(&env, &user, nonce)
+    }
+
+    /// Pays out a signed claim as configured by `set_mint_config`.
+    ///
+    /// Each (user, nonce) pair can be minted only once, within the per-user
+    /// and global caps.
+    pub fn mint(env: Env, payload: MintPayload, signatures: Vec<MintSignature>) -> i128 {
+        let amount = mint::mint(&env, &payload, &signatures);
         env.events()
             .publish((symbol_short!("mint"), payload.user), amount);
         amount
     }
 
+    /// Sets the mint payout target and caps (admin only, and only through
+    /// proposals once governance is active)
+    pub fn set_mint_config(
+        env: Env,
+        caller: Address,
+        config: MintConfig,
+    ) -> Result<(), SavingsError> {
+        mint::set_config(&env, caller, config)
+    }
+
+    pub fn get_mint_config(env: Env) -> MintConfig {
+        mint::get_config(&env)
+    }
+
+    pub fn get_total_minted(env: Env) -> i128 {
+        mint::get_total_minted(&env)
+    }
+
+    pub fn get_user_minted(env: Env, user: Address) -> i128 {
+        mint::get_user_minted(&env, &user)
+    }
+
     pub fn is_initialized(env: Env) -> bool {
         env.storage().instance().has(&DataKey::Initialized)
     }
@@ -240,6 +375,16 @@ impl NesteraContract {
             .ok_or(SavingsError::Overflow)?;
 
         let plan_id = user_data.savings_count as u64;
+        let now = env.ledger().timestamp();
+        let rate_plan = match &plan_type {
+            PlanType::Flexi => RatePlan::Flexi,
+            PlanType::Lock(locked_until) => {
+                RatePlan::Lock(locked_until.saturating_sub(now) / 86400)
+            }
+            PlanType::Goal(..) => RatePlan::Goal,
+            PlanType::Group(..) => RatePlan::Group,
+        };
+        let interest_rate = rates::plan_interest_rate(&env, &user, rate_plan);
 
         let new_plan = SavingsPlan {
             plan_id,
@@ -248,15 +393,13 @@ impl NesteraContract {
             start_time: env.ledger().timestamp(),
             last_deposit: env.ledger().timestamp(),
             last_withdraw: 0,
-            interest_rate: 500,
+            interest_rate,
             is_completed: false,
             is_withdrawn: false,
         };
 
         // State updates (Effects)
-        env.storage()
-            .persistent()
-            .set(&DataKey::User(user.clone()), &user_data);
+        users::save_user(&env, &user, &user_data);
         env.storage()
             .persistent()
             .set(&DataKey::SavingsPlan(user.clone(), plan_id), &new_plan);
@@ -277,9 +420,18 @@ impl NesteraContract {
         users::get_user(&env, &user)
     }
 
-    pub fn initialize_user(env: Env, user: Address) -> Result<(), SavingsError> {
+    /// Registers a new user, optionally attributing the sign-up to a referrer
+    pub fn initialize_user(
+        env: Env,
+        user: Address,
+        referrer: Option<Address>,
+    ) -> Result<(), SavingsError> {
         ensure_not_paused(&env)?;
-        users::initialize_user(&env, user)
+        users::initialize_user(&env, user.clone())?;
+        if let Some(referrer) = referrer {
+            referral::record_referral(&env, &user, &referrer)?;
+        }
+        Ok(())
     }
 
     pub fn user_exists(env: Env, user: Address) -> bool {
@@ -465,16 +617,8 @@ impl NesteraContract {
         new_admin: Address,
     ) -> Result<(), SavingsError> {
         current_admin.require_auth();
-        let stored_admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
-        if let Some(admin) = stored_admin {
-            if admin != current_admin {
-                return Err(SavingsError::Unauthorized);
-            }
-        }
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-        env.events()
-            .publish((symbol_short!("set_admin"),), new_admin);
-        Ok(())
+        governance::require_admin_before_governance(&env, &current_admin)?;
+        config::set_admin_unchecked(&env, new_admin)
     }
 
     pub fn set_flexi_rate(env: Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
@@ -501,40 +645,30 @@ impl NesteraContract {
     pub fn set_early_break_fee_bps(env: Env, bps: u32) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
-        if bps > 10_000 {
-            return Err(SavingsError::InvalidAmount);
-        }
-        env.storage()
-            .instance()
-            .set(&DataKey::EarlyBreakFeeBps, &bps);
-        env.events().publish((symbol_short!("set_brk"),), bps);
-        Ok(())
+        governance::require_admin_before_governance(&env, &admin)?;
+        config::set_early_break_fee_bps_unchecked(&env, bps)
     }
 
     pub fn set_fee_recipient(env: Env, recipient: Address) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
-        env.storage()
-            .instance()
-            .set(&DataKey::FeeRecipient, &recipient);
-        env.events().publish((symbol_short!("set_fee"),), recipient);
-        Ok(())
+        governance::require_admin_before_governance(&env, &admin)?;
+        config::set_fee_recipient_unchecked(&env, recipient)
     }
 
     pub fn set_protocol_fee_bps(env: Env, bps: u32) -> Result<(), SavingsError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
-        if bps > 10_000 {
-            return Err(SavingsError::InvalidAmount);
-        }
-        env.storage().instance().set(&DataKey::PlatformFee, &bps);
-        env.events().publish((symbol_short!("set_pfee"),), bps);
-        Ok(())
+        governance::require_admin_before_governance(&env, &admin)?;
+        config::set_platform_fee_bps_unchecked(&env, bps)
     }
 
+    /// Pauses the protocol (guardian, or admin until governance is active)
     pub fn pause(env: Env, caller: Address) -> Result<(), SavingsError> {
         caller.require_auth();
-        governance::validate_admin_or_governance(&env, &caller)?;
+        if !governance::is_guardian(&env, &caller) {
+            governance::require_admin_before_governance(&env, &caller)?;
+        }
 
         env.storage().persistent().set(&DataKey::Paused, &true);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
@@ -542,9 +676,11 @@ impl NesteraContract {
         Ok(())
     }
 
+    /// Unpauses the protocol (admin only, and only through an `UnpauseContract`
+    /// proposal once governance is active)
     pub fn unpause(env: Env, caller: Address) -> Result<(), SavingsError> {
         caller.require_auth();
-        governance::validate_admin_or_governance(&env, &caller)?;
+        governance::require_admin_before_governance(&env, &caller)?;
 
         env.storage().persistent().set(&DataKey::Paused, &false);
         ttl::extend_config_ttl(&env, &DataKey::Paused);
@@ -602,7 +738,7 @@ impl NesteraContract {
                         env.storage().persistent().get::<DataKey, User>(&user_key)
                     {
                         user_data.total_balance = user_data.total_balance.saturating_sub(balance);
-                        env.storage().persistent().set(&user_key, &user_data);
+                        users::save_user(&env, &user, &user_data);
                     }
                 }
                 balance
@@ -619,6 +755,7 @@ impl NesteraContract {
                     let amount = lock.amount;
                     lock.is_withdrawn = true;
                     env.storage().persistent().set(&lock_key, &lock);
+                    voting_power::remove_lock(&env, &lock);
 
                     // Update user total balance
                     let user_key = DataKey::User(user.clone());
@@ -626,7 +763,7 @@ impl NesteraContract {
                         env.storage().persistent().get::<DataKey, User>(&user_key)
                     {
                         user_data.total_balance = user_data.total_balance.saturating_sub(amount);
-                        env.storage().persistent().set(&user_key, &user_data);
+                        users::save_user(&env, &user, &user_data);
                     }
                     amount
                 } else {
@@ -652,7 +789,7 @@ impl NesteraContract {
                         env.storage().persistent().get::<DataKey, User>(&user_key)
                     {
                         user_data.total_balance = user_data.total_balance.saturating_sub(amount);
-                        env.storage().persistent().set(&user_key, &user_data);
+                        users::save_user(&env, &user, &user_data);
                     }
                     amount
                 } else {
@@ -691,7 +828,7 @@ impl NesteraContract {
                         {
                             user_data.total_balance =
                                 user_data.total_balance.saturating_sub(contribution);
-                            env.storage().persistent().set(&user_key, &user_data);
+                            users::save_user(&env, &user, &user_data);
                         }
                     }
                     contribution
@@ -783,6 +920,21 @@ impl NesteraContract {
             .unwrap_or(0)
     }
 
+    /// Gets the protocol fees accrued to the treasury and not yet spent
+    pub fn get_treasury_balance(env: Env) -> Result<i128, SavingsError> {
+        treasury::get_treasury_balance(&env)
+    }
+
+    /// Gets how many treasury spends governance has executed
+    pub fn get_treasury_spend_count(env: Env) -> u64 {
+        treasury::get_treasury_spend_count(&env)
+    }
+
+    /// Lists executed treasury spends, oldest first
+    pub fn get_treasury_spends(env: Env, start: u64, limit: u32) -> Vec<TreasurySpend> {
+        treasury::get_treasury_spends(&env, start, limit)
+    }
+
     // ========== Rewards Functions ==========
 
     pub fn init_rewards_config(
@@ -862,19 +1014,24 @@ impl NesteraContract {
 
     // ========== Ranking Functions ==========
 
-    /// Gets the top N users by reward points
+    /// Gets the top N users by reward points, all-time (`None`) or for a season
     /// Read-only - no state mutation
-    pub fn get_top_users(env: Env, limit: u32) -> Vec<(Address, u128)> {
-        rewards::ranking::get_top_users(&env, limit)
+    pub fn get_top_users(env: Env, season: Option<u32>, limit: u32) -> Vec<(Address, u128)> {
+        rewards::ranking::get_top_users(&env, season, limit)
     }
 
     /// Gets the rank of a specific user (1-indexed)
-    /// Returns 0 if user has no points or is not ranked
+    /// Returns 0 if user has no points or no more than `get_leaderboard_cutoff`
     /// Read-only - no state mutation
     pub fn get_user_rank(env: Env, user: Address) -> u32 {
         rewards::ranking::get_user_rank(&env, &user)
     }
 
+    /// Gets the all-time leaderboard cutoff; users with no more points are unranked
+    pub fn get_leaderboard_cutoff(env: Env) -> u128 {
+        rewards::ranking::get_leaderboard_cutoff(&env)
+    }
+
     /// Gets detailed ranking information for a user
     /// Returns (rank, total_points, total_users) or None
     /// Read-only - no state mutation
@@ -882,6 +1039,62 @@ impl NesteraContract {
         rewards::ranking::get_user_ranking_details(&env, &user)
     }
 
+    // ========== Reward Seasons ==========
+
+    /// Starts a rewards season ending at `end_time`, with optional per-rank prizes
+    pub fn start_season(
+        env: Env,
+        admin: Address,
+        end_time: u64,
+        prizes: Vec<u128>,
+    ) -> Result<u32, SavingsError> {
+        rewards::seasons::start_season(&env, admin, end_time, prizes)
+    }
+
+    /// Freezes an ended season's leaderboard and pays its prizes; callable by anyone
+    pub fn finalize_season(env: Env, season_id: u32) -> Result<(), SavingsError> {
+        rewards::seasons::finalize_season(&env, season_id)
+    }
+
+    pub fn get_season(env: Env, season_id: u32) -> Option<Season> {
+        rewards::seasons::get_season(&env, season_id)
+    }
+
+    /// Returns the ID of the latest season, or 0 if none has been started
+    pub fn get_current_season(env: Env) -> u32 {
+        rewards::seasons::get_current_season_id(&env)
+    }
+
+    pub fn get_season_points(env: Env, season_id: u32, user: Address) -> u128 {
+        rewards::seasons::get_season_points(&env, season_id, &user)
+    }
+
+    // ========== Point Expiry ==========
+
+    /// Sets how many days newly earned points stay spendable (0 = never expire)
+    pub fn set_points_expiry_days(env: Env, admin: Address, days: u64) -> Result<(), SavingsError> {
+        rewards::expiry::set_expiry_days(&env, admin, days)
+    }
+
+    pub fn get_points_expiry_days(env: Env) -> u64 {
+        rewards::expiry::get_expiry_days(&env)
+    }
+
+    /// Deducts a user's lapsed points; callable by anyone
+    pub fn expire_points(env: Env, user: Address) -> Result<u128, SavingsError> {
+        rewards::expiry::expire_points(&env, &user)
+    }
+
+    /// Points that will have expired within `within_seconds` from now
+    pub fn get_expiring_points(env: Env, user: Address, within_seconds: u64) -> u128 {
+        rewards::expiry::get_expiring_points(&env, &user, within_seconds)
+    }
+
+    /// The user's expiring point lots, oldest first
+    pub fn get_point_lots(env: Env, user: Address) -> Vec<PointLot> {
+        rewards::expiry::get_point_lots(&env, &user)
+    }
+
     // ========== Points Redemption ==========
 
     /// Redeem points for protocol benefits (fee discounts, boost multiplier, etc.)
@@ -892,6 +1105,103 @@ impl NesteraContract {
         rewards::redemption::redeem_points(&env, user, amount)
     }
 
+    /// Adds a benefit to the points redemption catalog (admin only, and only
+    /// through proposals once governance is active)
+    pub fn add_redemption_item(
+        env: Env,
+        admin: Address,
+        item: RedemptionItem,
+    ) -> Result<u32, SavingsError> {
+        rewards::redemption::add_catalog_item(&env, admin, item)
+    }
+
+    /// Lists or retires a redemption catalog item (admin only, and only through
+    /// proposals once governance is active)
+    pub fn set_redemption_item_active(
+        env: Env,
+        admin: Address,
+        item_id: u32,
+        active: bool,
+    ) -> Result<(), SavingsError> {
+        rewards::redemption::set_catalog_item_active(&env, admin, item_id, active)
+    }
+
+    pub fn get_redemption_item(env: Env, item_id: u32) -> Option<RedemptionItem> {
+        rewards::redemption::get_catalog_item(&env, item_id)
+    }
+
+    pub fn get_redemption_item_count(env: Env) -> u32 {
+        rewards::redemption::get_catalog_count(&env)
+    }
+
+    /// Spends points on a catalog item and grants its benefit to the user
+    pub fn redeem_benefit(env: Env, user: Address, item_id: u32) -> Result<(), SavingsError> {
+        user.require_auth();
+        rewards::redemption::redeem_benefit(&env, user, item_id)
+    }
+
+    pub fn get_redemption_count(env: Env, user: Address, item_id: u32) -> u32 {
+        rewards::redemption::get_redemption_count(&env, &user, item_id)
+    }
+
+    /// Benefits the user has redeemed; entries past `expires_at` no longer apply
+    pub fn get_active_benefits(env: Env, user: Address) -> Vec<ActiveBenefit> {
+        rewards::redemption::get_active_benefits(&env, &user)
+    }
+
+    /// Interest rate a plan the user opens now would fix, including any
+    /// redeemed rate boost
+    pub fn get_effective_rate(env: Env, user: Address, plan: RatePlan) -> i128 {
+        rates::get_effective_rate(&env, &user, plan)
+    }
+
+    /// Replaces the savings tier table (admin only, and only through proposals
+    /// once governance is active)
+    pub fn set_savings_tiers(
+        env: Env,
+        admin: Address,
+        tiers: Vec<SavingsTier>,
+    ) -> Result<(), SavingsError> {
+        rewards::tiers::set_tiers(&env, admin, tiers)
+    }
+
+    pub fn get_savings_tiers(env: Env) -> Vec<SavingsTier> {
+        rewards::tiers::get_tiers(&env)
+    }
+
+    /// Returns the tier the user's lifetime deposits qualify for, if any
+    pub fn get_user_tier(env: Env, user: Address) -> Option<SavingsTier> {
+        rewards::tiers::get_user_tier(&env, &user)
+    }
+
+    // ========== Referrals ==========
+
+    /// Sets referrer rewards: points at a deposit threshold and a fee share (admin only)
+    pub fn set_referral_config(
+        env: Env,
+        caller: Address,
+        config: ReferralConfig,
+    ) -> Result<(), SavingsError> {
+        referral::set_referral_config(&env, caller, config)
+    }
+
+    pub fn get_referral_config(env: Env) -> Option<ReferralConfig> {
+        referral::get_referral_config(&env)
+    }
+
+    pub fn get_referrer(env: Env, user: Address) -> Option<Address> {
+        referral::get_referrer(&env, &user)
+    }
+
+    pub fn get_referrals(env: Env, referrer: Address) -> Vec<Address> {
+        referral::get_referrals(&env, &referrer)
+    }
+
+    /// Referral count and earnings (points and fee share) for a referrer
+    pub fn get_referral_stats(env: Env, referrer: Address) -> ReferralStats {
+        referral::get_referral_stats(&env, &referrer)
+    }
+
     // ========== AutoSave Functions ==========
 
     /// Creates a new AutoSave schedule for recurring Flexi deposits
@@ -904,7 +1214,31 @@ impl NesteraContract {
     ) -> Result<u64, SavingsError> {
         ensure_not_paused(&env)?;
         crate::security::acquire_reentrancy_guard(&env)?;
-        let res = autosave::create_autosave(&env, user, amount, interval_seconds, start_time);
+        let res = autosave::create_autosave(
+            &env,
+            user,
+            AutoSaveTarget::Flexi,
+            amount,
+            interval_seconds,
+            start_time,
+        );
+        crate::security::release_reentrancy_guard(&env);
+        res
+    }
+
+    /// Creates a new AutoSave schedule that deposits into a Goal, Group or new Lock plan
+    pub fn create_autosave_with_target(
+        env: Env,
+        user: Address,
+        target: AutoSaveTarget,
+        amount: i128,
+        interval_seconds: u64,
+        start_time: u64,
+    ) -> Result<u64, SavingsError> {
+        ensure_not_paused(&env)?;
+        crate::security::acquire_reentrancy_guard(&env)?;
+        let res =
+            autosave::create_autosave(&env, user, target, amount, interval_seconds, start_time);
         crate::security::release_reentrancy_guard(&env);
         res
     }
@@ -921,7 +1255,56 @@ impl NesteraContract {
     /// Batch-executes multiple due AutoSave schedules in a single call.
     /// Returns a Vec<bool> indicating success (true) or skip/failure (false) per schedule.
     pub fn execute_due_autosaves(env: Env, schedule_ids: Vec<u64>) -> Vec<bool> {
-        autosave::execute_due_autosaves(&env, schedule_ids)
+        autosave::execute_due_autosaves(&env, schedule_ids, None)
+    }
+
+    /// Batch-executes due AutoSave schedules on behalf of a keeper.
+    /// The keeper must be a registered user; bounties are credited to its Flexi balance.
+    pub fn keeper_execute_autosaves(
+        env: Env,
+        keeper: Address,
+        schedule_ids: Vec<u64>,
+    ) -> Result<Vec<bool>, SavingsError> {
+        ensure_not_paused(&env)?;
+        keeper.require_auth();
+        if !users::user_exists(&env, &keeper) {
+            return Err(SavingsError::UserNotFound);
+        }
+        crate::security::acquire_reentrancy_guard(&env)?;
+        let res = autosave::execute_due_autosaves(&env, schedule_ids, Some(&keeper));
+        crate::security::release_reentrancy_guard(&env);
+        Ok(res)
+    }
+
+    /// Sets the keeper bounty for AutoSave execution (admin only, and only
+    /// through proposals once governance is active)
+    pub fn set_keeper_config(
+        env: Env,
+        caller: Address,
+        config: KeeperConfig,
+    ) -> Result<(), SavingsError> {
+        keeper::set_keeper_config(&env, caller, config)
+    }
+
+    /// Returns the keeper bounty configuration
+    pub fn get_keeper_config(env: Env) -> Option<KeeperConfig> {
+        keeper::get_keeper_config(&env)
+    }
+
+    /// Moves funds from the caller's Flexi balance into the keeper bounty pool
+    pub fn fund_keeper_pool(env: Env, funder: Address, amount: i128) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        keeper::fund_keeper_pool(&env, funder, amount)
+    }
+
+    /// Returns the balance of the keeper bounty pool
+    pub fn get_keeper_pool_balance(env: Env) -> i128 {
+        keeper::get_keeper_pool_balance(&env)
+    }
+
+    /// Returns the execution tally and total bounties earned by a keeper
+    pub fn get_keeper_stats(env: Env, keeper: Address) -> KeeperStats {
+        keeper::get_keeper_stats(&env, &keeper)
     }
 
     /// Cancels an AutoSave schedule
@@ -933,6 +1316,79 @@ impl NesteraContract {
         res
     }
 
+    /// Pauses an AutoSave schedule; it keeps its ID and can be resumed later
+    pub fn pause_autosave(env: Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::pause_autosave(&env, user, schedule_id)
+    }
+
+    /// Resumes a paused AutoSave schedule from its next future slot
+    pub fn resume_autosave(env: Env, user: Address, schedule_id: u64) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::resume_autosave(&env, user, schedule_id)
+    }
+
+    /// Changes the amount and interval of an AutoSave schedule
+    pub fn amend_autosave(
+        env: Env,
+        user: Address,
+        schedule_id: u64,
+        amount: i128,
+        interval_seconds: u64,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::amend_autosave(&env, user, schedule_id, amount, interval_seconds)
+    }
+
+    /// Sets the run limit (0 = unlimited) and end date (0 = none) of an AutoSave schedule
+    pub fn set_autosave_end(
+        env: Env,
+        user: Address,
+        schedule_id: u64,
+        max_runs: u32,
+        end_time: u64,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::set_autosave_end(&env, user, schedule_id, max_runs, end_time)
+    }
+
+    /// Sets whether a late AutoSave skips missed slots or makes up at most K of them
+    pub fn set_autosave_catch_up(
+        env: Env,
+        user: Address,
+        schedule_id: u64,
+        policy: CatchUpPolicy,
+    ) -> Result<(), SavingsError> {
+        ensure_not_paused(&env)?;
+        autosave::set_autosave_catch_up(&env, user, schedule_id, policy)
+    }
+
+    /// Sets how many consecutive batch failures deactivate a schedule (admin only,
+    /// and only through proposals once governance is active)
+    pub fn set_autosave_failure_threshold(
+        env: Env,
+        caller: Address,
+        threshold: u32,
+    ) -> Result<(), SavingsError> {
+        autosave::set_failure_threshold(&env, caller, threshold)
+    }
+
+    /// Returns how many consecutive batch failures deactivate a schedule
+    pub fn get_autosave_failure_threshold(env: Env) -> u32 {
+        autosave::get_failure_threshold(&env)
+    }
+
+    /// Lists due AutoSave schedule IDs for keepers, one page at a time.
+    /// Start from a zeroed cursor, then pass the returned `next_cursor` while `has_more`.
+    pub fn get_due_autosaves(
+        env: Env,
+        now: u64,
+        cursor: AutoSaveCursor,
+        limit: u32,
+    ) -> AutoSaveDuePage {
+        autosave::get_due_autosaves(&env, now, cursor, limit)
+    }
+
     /// Gets an AutoSave schedule by ID
     pub fn get_autosave(env: Env, schedule_id: u64) -> Option<AutoSave> {
         autosave::get_autosave(&env, schedule_id)
@@ -960,7 +1416,8 @@ impl NesteraContract {
         config::get_config(&env)
     }
 
-    /// Updates the treasury address (admin only)
+    /// Updates the treasury address (admin only, and only through proposals
+    /// once governance is active)
     pub fn set_treasury(
         env: Env,
         admin: Address,
@@ -969,7 +1426,8 @@ impl NesteraContract {
         config::set_treasury(&env, admin, new_treasury)
     }
 
-    /// Updates the protocol fee in basis points (admin only)
+    /// Updates the protocol fee in basis points (admin only, and only through
+    /// proposals once governance is active)
     pub fn set_protocol_fee(
         env: Env,
         admin: Address,
@@ -978,18 +1436,26 @@ impl NesteraContract {
         config::set_protocol_fee(&env, admin, new_fee_bps)
     }
 
-    /// Pauses the contract via config module (admin only)
+    /// Pauses the contract via config module (admin only, until governance is
+    /// active)
     pub fn pause_contract(env: Env, admin: Address) -> Result<(), SavingsError> {
         config::pause_contract(&env, admin)
     }
 
-    /// Unpauses the contract via config module (admin only)
+    /// Unpauses the contract via config module (admin only, until governance is
+    /// active)
     pub fn unpause_contract(env: Env, admin: Address) -> Result<(), SavingsError> {
         config::unpause_contract(&env, admin)
     }
 
-    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
-        upgrade::upgrade_contract(&env, admin, new_wasm_hash);
+    /// Upgrades the contract WASM (admin only, and only through proposals once
+    /// governance is active)
+    pub fn upgrade(
+        env: Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), SavingsError> {
+        upgrade::upgrade_contract(&env, admin, new_wasm_hash)
     }
 
     pub fn version(env: Env) -> u32 {
@@ -1028,7 +1494,7 @@ impl NesteraContract {
         env: Env,
         creator: Address,
         description: String,
-    ) -> Result<u64, SavingsError> {
+    ) -> Result<u64, GovernanceError> {
         governance::create_proposal(&env, creator, description)
     }
 
@@ -1038,10 +1504,20 @@ impl NesteraContract {
         creator: Address,
         description: String,
         action: governance::ProposalAction,
-    ) -> Result<u64, SavingsError> {
+    ) -> Result<u64, GovernanceError> {
         governance::create_action_proposal(&env, creator, description, action)
     }
 
+    /// Creates a governance proposal whose actions run atomically, in order
+    pub fn create_batch_proposal(
+        env: Env,
+        creator: Address,
+        description: String,
+        actions: Vec<governance::ProposalAction>,
+    ) -> Result<u64, GovernanceError> {
+        governance::create_batch_proposal(&env, creator, description, actions)
+    }
+
     /// Gets a proposal by ID
     pub fn get_proposal(env: Env, proposal_id: u64) -> Option<governance::Proposal> {
         governance::get_proposal(&env, proposal_id)
@@ -1057,9 +1533,96 @@ impl NesteraContract {
         governance::list_proposals(&env)
     }
 
-    /// Gets the voting power for a user based on their lifetime deposited funds
+    /// Gets the voting power a user votes with: liquid savings plus
+    /// time-weighted Lock Saves, adjusted for delegation
     pub fn get_voting_power(env: Env, user: Address) -> u128 {
-        governance::get_voting_power(&env, &user)
+        voting_power::get_voting_power(&env, &user)
+    }
+
+    /// Gets the voting power a user held at `timestamp`
+    pub fn get_voting_power_at(env: Env, user: Address, timestamp: u64) -> u128 {
+        voting_power::get_voting_power_at(&env, &user, timestamp)
+    }
+
+    /// Gets where a user's voting power comes from
+    pub fn get_voting_power_breakdown(
+        env: Env,
+        user: Address,
+    ) -> voting_power::VotingPowerBreakdown {
+        voting_power::get_voting_power_breakdown(&env, &user)
+    }
+
+    /// Gets a user's voting power at `count` points `step` seconds apart from `from`
+    pub fn get_voting_power_curve(
+        env: Env,
+        user: Address,
+        from: u64,
+        step: u64,
+        count: u32,
+    ) -> Result<Vec<voting_power::VotingPowerPoint>, SavingsError> {
+        voting_power::get_voting_power_curve(&env, &user, from, step, count)
+    }
+
+    /// Gets the weight of liquid balances in voting power, in basis points
+    pub fn get_balance_vote_weight(env: Env) -> u32 {
+        voting_power::get_balance_weight_bps(&env)
+    }
+
+    /// Sets the weight of liquid balances in voting power (admin only, and only
+    /// through proposals once governance is active)
+    pub fn set_balance_vote_weight(
+        env: Env,
+        caller: Address,
+        weight_bps: u32,
+    ) -> Result<(), SavingsError> {
+        voting_power::set_balance_weight_bps(&env, caller, weight_bps)
+    }
+
+    /// Delegates the caller's voting power to another address
+    pub fn delegate_votes(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+    ) -> Result<(), SavingsError> {
+        voting_power::delegate_votes(&env, delegator, delegatee)
+    }
+
+    /// Takes back voting power previously delegated
+    pub fn undelegate(env: Env, delegator: Address) -> Result<(), SavingsError> {
+        voting_power::undelegate(&env, delegator)
+    }
+
+    /// Gets who a user has delegated their votes to, if anyone
+    pub fn get_delegate(env: Env, user: Address) -> Option<Address> {
+        voting_power::get_delegate(&env, &user)
+    }
+
+    /// Lists the users who have delegated their votes to `delegatee`
+    pub fn get_delegators(env: Env, delegatee: Address) -> Vec<Address> {
+        voting_power::get_delegators(&env, &delegatee)
+    }
+
+    /// Gets the voting power `delegatee` holds on behalf of others
+    pub fn get_received_voting_power(env: Env, delegatee: Address) -> u128 {
+        voting_power::get_received_voting_power(&env, &delegatee)
+    }
+
+    /// Gets the total voting power across all users, the base for quorum
+    pub fn get_total_voting_power(env: Env) -> u128 {
+        voting_power::get_total_voting_power(&env)
+    }
+
+    /// Gets the total voting power across all users at `timestamp`
+    pub fn get_total_voting_power_at(env: Env, timestamp: u64) -> u128 {
+        voting_power::get_total_voting_power_at(&env, timestamp)
+    }
+
+    /// Gets why a proposal failed, or `None` if it passed or voting is still open
+    pub fn get_proposal_failure_reason(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<Option<governance::ProposalFailure>, GovernanceError> {
+        governance::get_proposal_failure_reason(&env, proposal_id)
     }
 
     /// Casts a weighted vote on a proposal
@@ -1068,25 +1631,105 @@ impl NesteraContract {
         proposal_id: u64,
         vote_type: u32,
         voter: Address,
-    ) -> Result<(), SavingsError> {
+    ) -> Result<(), GovernanceError> {
         governance::vote(&env, proposal_id, vote_type, voter)
     }
 
+    /// Registers the key `voter` signs off-chain ballots with, or removes it
+    pub fn set_ballot_key(env: Env, voter: Address, public_key: Option<SignerKey>) {
+        ballots::set_ballot_key(&env, voter, public_key)
+    }
+
+    pub fn get_ballot_key(env: Env, voter: Address) -> Option<SignerKey> {
+        ballots::get_ballot_key(&env, &voter)
+    }
+
+    /// Returns the bytes a voter's ballot key must sign for `ballot`
+    pub fn get_ballot_signing_message(env: Env, ballot: Ballot) -> Bytes {
+        ballots::signing_message(&env, &ballot)
+    }
+
+    /// Returns true if the ballot with this nonce has already been counted
+    pub fn is_ballot_nonce_used(env: Env, voter: Address, nonce: u64) -> bool {
+        ballots::is_ballot_nonce_used(&env, &voter, nonce)
+    }
+
+    /// Counts a batch of off-chain signed ballots; callable by any relayer
+    pub fn submit_ballots(env: Env, ballots: Vec<SignedBallot>) -> Result<(), GovernanceError> {
+        ballots::submit_ballots(&env, ballots)
+    }
+
     /// Checks if a user has voted on a proposal
     pub fn has_voted(env: Env, proposal_id: u64, voter: Address) -> bool {
         governance::has_voted(&env, proposal_id, &voter)
     }
 
     /// Queues a proposal for execution after timelock
-    pub fn queue_proposal(env: Env, proposal_id: u64) -> Result<(), SavingsError> {
+    pub fn queue_proposal(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
         governance::queue_proposal(&env, proposal_id)
     }
 
     /// Executes a queued proposal after timelock period
-    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), SavingsError> {
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), GovernanceError> {
         governance::execute_proposal(&env, proposal_id)
     }
 
+    /// Cancels a proposal that has not been queued yet (creator only)
+    pub fn cancel_proposal(
+        env: Env,
+        caller: Address,
+        proposal_id: u64,
+    ) -> Result<(), GovernanceError> {
+        governance::cancel_proposal(&env, proposal_id, caller)
+    }
+
+    /// Gets the lifecycle state of a proposal
+    pub fn get_proposal_state(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<governance::ProposalState, GovernanceError> {
+        governance::get_proposal_state(&env, proposal_id)
+    }
+
+    /// Gets how long a queued proposal stays executable after its timelock
+    pub fn get_execution_grace_period(env: Env) -> u64 {
+        governance::get_execution_grace_period(&env)
+    }
+
+    /// Sets how long a queued proposal stays executable after its timelock
+    /// (admin only, and only through proposals once governance is active)
+    pub fn set_execution_grace_period(
+        env: Env,
+        caller: Address,
+        grace_period: u64,
+    ) -> Result<(), SavingsError> {
+        governance::set_execution_grace_period(&env, caller, grace_period)
+    }
+
+    /// Gets the guardian, if one is set
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        governance::get_guardian(&env)
+    }
+
+    /// Sets or removes the guardian (admin, before governance is active;
+    /// afterwards only through a `SetGuardian` proposal)
+    pub fn set_guardian(
+        env: Env,
+        caller: Address,
+        guardian: Option<Address>,
+    ) -> Result<(), SavingsError> {
+        governance::set_guardian(&env, caller, guardian)
+    }
+
+    /// Vetoes a queued proposal during its timelock (guardian only)
+    pub fn veto_proposal(
+        env: Env,
+        guardian: Address,
+        proposal_id: u64,
+    ) -> Result<(), GovernanceError> {
+        governance::veto_proposal(&env, guardian, proposal_id)
+    }
+
     /// Activates governance (admin only, one-time)
     pub fn activate_governance(env: Env, admin: Address) -> Result<(), SavingsError> {
         governance::activate_governance(&env, admin)
@@ -1099,7 +1742,8 @@ impl NesteraContract {
 
     // ========== Strategy Functions ==========
 
-    /// Registers a new yield strategy (admin/governance only).
+    /// Registers a new yield strategy (admin only, and only through proposals
+    /// once governance is active).
     pub fn register_strategy(
         env: Env,
         caller: Address,
@@ -1109,7 +1753,8 @@ impl NesteraContract {
         strategy::registry::register_strategy(&env, caller, strategy_address, risk_level)
     }
 
-    /// Disables a registered yield strategy (admin/governance only).
+    /// Disables a registered yield strategy (admin only, and only through
+    /// proposals once governance is active).
     pub fn disable_strategy(
         env: Env,
         caller: Address,
@@ -1,9 +1,12 @@
 use crate::ensure_not_paused;
 use crate::errors::SavingsError;
+use crate::rates;
 use crate::rewards::storage;
+use crate::rewards::storage_types::RatePlan;
 use crate::storage_types::{DataKey, LockSave, User};
 use crate::ttl;
 use crate::users;
+use crate::voting_power;
 use soroban_sdk::{symbol_short, Address, Env, Vec};
 
 /// Creates a new Lock Save plan for a user
@@ -43,7 +46,7 @@ pub fn create_lock_save(
         id: lock_id,
         owner: user.clone(),
         amount,
-        interest_rate: 500, // Matching your test expectation of 500 (5%)
+        interest_rate: rates::plan_interest_rate(env, &user, RatePlan::Lock(duration / 86400)),
         start_time,
         maturity_time,
         is_withdrawn: false,
@@ -57,12 +60,15 @@ pub fn create_lock_save(
     // Update user's lock list
     add_lock_to_user(env, &user, lock_id);
 
+    // Escrow voting power before the principal shows up in total_balance
+    voting_power::add_lock(env, &lock_save);
+
     // Update user's profile stats
     let user_key = DataKey::User(user.clone());
     let mut user_data: User = env.storage().persistent().get(&user_key).unwrap();
     user_data.total_balance += amount;
     user_data.savings_count += 1;
-    env.storage().persistent().set(&user_key, &user_data);
+    users::save_user(env, &user, &user_data);
 
     storage::award_deposit_points(env, user.clone(), amount)?;
     storage::award_long_lock_bonus(env, user.clone(), amount, duration)?;
@@ -100,11 +106,13 @@ pub fn withdraw_lock_save(env: &Env, user: Address, lock_id: u64) -> Result<i128
         .persistent()
         .set(&DataKey::LockSave(lock_id), &lock_save);
 
+    voting_power::remove_lock(env, &lock_save);
+
     // Update user's total balance (subtracting the locked portion)
     let user_key = DataKey::User(user.clone());
     if let Some(mut user_data) = env.storage().persistent().get::<DataKey, User>(&user_key) {
         user_data.total_balance -= lock_save.amount;
-        env.storage().persistent().set(&user_key, &user_data);
+        users::save_user(env, &user, &user_data);
     }
 
     // Extend TTL (completed locks get shorter extension)
@@ -281,7 +289,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let amount = 1_000i128;
         let above_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
@@ -299,7 +307,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let amount = 1_000i128;
         client.create_lock_save(&user, &amount, &LONG_LOCK_BONUS_THRESHOLD_SECS);
@@ -315,7 +323,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let amount = 1_000i128;
         let below_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS - 1;
@@ -332,7 +340,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let amount = 1_000i128;
         let above_threshold = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
@@ -348,7 +356,7 @@ mod tests {
         let user = Address::generate(&env);
 
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let amount = 1_000i128;
         let duration = LONG_LOCK_BONUS_THRESHOLD_SECS + 1;
@@ -0,0 +1,329 @@
+//! Admin-signed mint payloads: verification, domain binding, replay protection,
+//! signing-key rotation and M-of-N co-signing of large mints.
+
+use crate::errors::SavingsError;
+use crate::flexi;
+use crate::governance;
+use crate::signer;
+use crate::storage_types::{
+    DataKey, MintConfig, MintPayload, MintSignature, MintSignerSet, MintTarget, RetiredAdminKey,
+    SignerKey,
+};
+use crate::ttl::{EXTEND_TO, LOW_THRESHOLD};
+use crate::ContractError;
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, token, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    Vec,
+};
+
+/// Storage keys for mints; variant names must not clash with `DataKey` or the
+/// other key enums, which share the same storage namespace
+#[contracttype]
+#[derive(Clone)]
+pub enum MintKey {
+    MintConfig,
+    /// Total minted across all users
+    MintedTotal,
+    /// Total minted to one user
+    MintedByUser(Address),
+    /// Set once the payload with this (user, nonce) has been minted
+    MintNonceUsed(Address, u64),
+    /// The admin key replaced by the last rotation
+    RetiredAdminKey,
+    MintSignerSet,
+}
+
+/// The message the admin actually signs.
+///
+/// Binding the payload to this contract and to the network passphrase hash
+/// stops a signature from being replayed on another deployment or network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintMessage {
+    pub contract: Address,
+    pub network_id: BytesN<32>,
+    pub payload: MintPayload,
+}
+
+/// Returns the XDR bytes the admin key must sign for `payload`
+pub fn signing_message(env: &Env, payload: &MintPayload) -> Bytes {
+    MintMessage {
+        contract: env.current_contract_address(),
+        network_id: env.ledger().network_id(),
+        payload: payload.clone(),
+    }
+    .to_xdr(env)
+}
+
+/// Checks the payload's expiry and its signatures over the signing message.
+///
+/// Mints below the signer set's `large_amount` (or any mint when no signer set
+/// is configured) need one signature from the admin key, or from the previous
+/// admin key during a rotation's grace period. Large mints need `threshold`
+/// signatures from distinct keys in the signer set.
+///
+/// Panics with `ContractError::SignatureExpired` once the payload has lapsed,
+/// `UnknownSigner` if a key is not accepted for this amount and
+/// `InsufficientSignatures` if too few distinct keys signed and
+/// `InvalidSignature` if a proof does not fit its key or message; a bad
+/// cryptographic signature aborts the invocation.
+pub fn verify(env: &Env, payload: &MintPayload, signatures: &Vec<MintSignature>) {
+    if !env.storage().instance().has(&DataKey::Initialized) {
+        panic_with_error!(env, ContractError::NotInitialized);
+    }
+    let current_timestamp = env.ledger().timestamp();
+    let expiry_time = payload.timestamp.saturating_add(payload.expiry_duration);
+    if current_timestamp > expiry_time {
+        panic_with_error!(env, ContractError::SignatureExpired);
+    }
+
+    let (accepted, required) = match get_signer_set(env) {
+        Some(set) if payload.amount >= set.large_amount => (set.signers, set.threshold),
+        _ => (active_admin_keys(env), 1),
+    };
+
+    let message = signing_message(env, payload);
+    let mut signed_by: Vec<SignerKey> = Vec::new(env);
+    for entry in signatures.iter() {
+        if !accepted.contains(&entry.public_key) {
+            panic_with_error!(env, ContractError::UnknownSigner);
+        }
+        if signer::verify(env, &entry.public_key, &message, &entry.signature).is_err() {
+            panic_with_error!(env, ContractError::InvalidSignature);
+        }
+        if !signed_by.contains(&entry.public_key) {
+            signed_by.push_back(entry.public_key);
+        }
+    }
+    if signed_by.len() < required {
+        panic_with_error!(env, ContractError::InsufficientSignatures);
+    }
+}
+
+/// The admin key, plus the previous key while its grace period lasts
+fn active_admin_keys(env: &Env) -> Vec<SignerKey> {
+    let admin_public_key = get_admin_key(env).expect("Admin PK not found");
+    let mut keys = Vec::from_array(env, [admin_public_key]);
+    if let Some(retired) = get_retired_admin_key(env) {
+        if env.ledger().timestamp() <= retired.valid_until {
+            keys.push_back(retired.public_key);
+        }
+    }
+    keys
+}
+
+/// Replaces the admin signing key. Admin only until governance is active,
+/// then only through a `RotateAdminKey` proposal.
+///
+/// The replaced key keeps verifying mints for `grace_period` seconds so that
+/// payloads signed before the rotation can still be claimed. A rotation
+/// during a grace period ends the earlier grace period.
+pub fn rotate_admin_key(
+    env: &Env,
+    caller: Address,
+    new_public_key: SignerKey,
+    grace_period: u64,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    rotate_admin_key_unchecked(env, new_public_key, grace_period)
+}
+
+/// Rotates the admin key without the admin check; used by governance
+pub(crate) fn rotate_admin_key_unchecked(
+    env: &Env,
+    new_public_key: SignerKey,
+    grace_period: u64,
+) -> Result<(), SavingsError> {
+    let old_public_key = get_admin_key(env).ok_or(SavingsError::InternalError)?;
+    let retired = RetiredAdminKey {
+        public_key: old_public_key,
+        valid_until: env.ledger().timestamp().saturating_add(grace_period),
+    };
+    env.storage()
+        .instance()
+        .set(&MintKey::RetiredAdminKey, &retired);
+    env.storage()
+        .instance()
+        .set(&DataKey::AdminPublicKey, &new_public_key);
+
+    env.events().publish(
+        (symbol_short!("key_rot"),),
+        (new_public_key, retired.valid_until),
+    );
+    Ok(())
+}
+
+/// Returns the current admin signing key
+pub fn get_admin_key(env: &Env) -> Option<SignerKey> {
+    signer::read_signer_key(env, &DataKey::AdminPublicKey)
+}
+
+/// Returns the key replaced by the last rotation and when it stops being accepted
+pub fn get_retired_admin_key(env: &Env) -> Option<RetiredAdminKey> {
+    env.storage().instance().get(&MintKey::RetiredAdminKey)
+}
+
+/// Sets the keys that co-sign large mints. Admin only until governance is
+/// active, then only through a `SetMintSigners` proposal.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidSignerSet` - If the set is empty, repeats a key or
+///   its threshold is 0 or above the number of signers
+/// * `SavingsError::InvalidAmount` - If `large_amount` is not positive
+pub fn set_signer_set(env: &Env, caller: Address, set: MintSignerSet) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_signer_set_unchecked(env, set)
+}
+
+/// Replaces the signer set without the admin check; used by governance
+pub(crate) fn set_signer_set_unchecked(env: &Env, set: MintSignerSet) -> Result<(), SavingsError> {
+    if set.threshold == 0 || set.threshold > set.signers.len() {
+        return Err(SavingsError::InvalidSignerSet);
+    }
+    for i in 0..set.signers.len() {
+        for j in (i + 1)..set.signers.len() {
+            if set.signers.get(i) == set.signers.get(j) {
+                return Err(SavingsError::InvalidSignerSet);
+            }
+        }
+    }
+    if set.large_amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage().instance().set(&MintKey::MintSignerSet, &set);
+    Ok(())
+}
+
+/// Removes the signer set, so every mint needs only the admin key. Admin only
+/// until governance is active, then only through a `ClearMintSigners` proposal.
+pub fn clear_signer_set(env: &Env, caller: Address) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    clear_signer_set_unchecked(env);
+    Ok(())
+}
+
+/// Removes the signer set without the admin check; used by governance
+pub(crate) fn clear_signer_set_unchecked(env: &Env) {
+    env.storage().instance().remove(&MintKey::MintSignerSet);
+}
+
+pub fn get_signer_set(env: &Env) -> Option<MintSignerSet> {
+    env.storage().instance().get(&MintKey::MintSignerSet)
+}
+
+/// Returns true if the payload with this nonce has already been minted
+pub fn is_nonce_used(env: &Env, user: &Address, nonce: u64) -> bool {
+    env.storage()
+        .persistent()
+        .has(&MintKey::MintNonceUsed(user.clone(), nonce))
+}
+
+/// Sets where mint claims are paid and the caps that apply. Admin only until
+/// governance is active, then only through a `SetMintConfig` proposal.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidAmount` - If a cap is negative
+pub fn set_config(env: &Env, caller: Address, config: MintConfig) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_config_unchecked(env, config)
+}
+
+/// Applies a mint configuration without the admin check; used by governance
+pub(crate) fn set_config_unchecked(env: &Env, config: MintConfig) -> Result<(), SavingsError> {
+    if config.per_user_cap < 0 || config.global_cap < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage().instance().set(&MintKey::MintConfig, &config);
+    Ok(())
+}
+
+/// Returns the mint configuration; defaults to uncapped Flexi credits
+pub fn get_config(env: &Env) -> MintConfig {
+    env.storage()
+        .instance()
+        .get(&MintKey::MintConfig)
+        .unwrap_or(MintConfig {
+            target: MintTarget::Flexi,
+            per_user_cap: 0,
+            global_cap: 0,
+        })
+}
+
+/// Total minted across all users
+pub fn get_total_minted(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&MintKey::MintedTotal)
+        .unwrap_or(0)
+}
+
+/// Total minted to `user`
+pub fn get_user_minted(env: &Env, user: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&MintKey::MintedByUser(user.clone()))
+        .unwrap_or(0)
+}
+
+/// Verifies a signed claim, enforces the caps and pays it out.
+///
+/// Each (user, nonce) pair can be minted only once. Totals and the consumed
+/// nonce are recorded before any token contract is called.
+///
+/// Panics as `verify` does, and with `ContractError::MintPayloadAlreadyUsed`,
+/// `InvalidMintAmount`,
+/// `UserMintCapExceeded` or `GlobalMintCapExceeded`; Flexi payouts panic with
+/// `SavingsError::UserNotFound` for unregistered users.
+pub fn mint(env: &Env, payload: &MintPayload, signatures: &Vec<MintSignature>) -> i128 {
+    if is_nonce_used(env, &payload.user, payload.nonce) {
+        panic_with_error!(env, ContractError::MintPayloadAlreadyUsed);
+    }
+    verify(env, payload, signatures);
+    if payload.amount <= 0 {
+        panic_with_error!(env, ContractError::InvalidMintAmount);
+    }
+
+    let config = get_config(env);
+    let user_total = get_user_minted(env, &payload.user)
+        .checked_add(payload.amount)
+        .unwrap_or_else(|| panic_with_error!(env, SavingsError::Overflow));
+    if config.per_user_cap > 0 && user_total > config.per_user_cap {
+        panic_with_error!(env, ContractError::UserMintCapExceeded);
+    }
+    let global_total = get_total_minted(env)
+        .checked_add(payload.amount)
+        .unwrap_or_else(|| panic_with_error!(env, SavingsError::Overflow));
+    if config.global_cap > 0 && global_total > config.global_cap {
+        panic_with_error!(env, ContractError::GlobalMintCapExceeded);
+    }
+
+    let nonce_key = MintKey::MintNonceUsed(payload.user.clone(), payload.nonce);
+    env.storage().persistent().set(&nonce_key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&nonce_key, LOW_THRESHOLD, EXTEND_TO);
+
+    let user_key = MintKey::MintedByUser(payload.user.clone());
+    env.storage().persistent().set(&user_key, &user_total);
+    env.storage()
+        .persistent()
+        .extend_ttl(&user_key, LOW_THRESHOLD, EXTEND_TO);
+    env.storage()
+        .instance()
+        .set(&MintKey::MintedTotal, &global_total);
+
+    match config.target {
+        MintTarget::Flexi => flexi::credit_flexi_balance(env, &payload.user, payload.amount)
+            .unwrap_or_else(|e| panic_with_error!(env, e)),
+        MintTarget::Token(token) => {
+            token::StellarAssetClient::new(env, &token).mint(&payload.user, &payload.amount)
+        }
+    }
+    payload.amount
+}
@@ -1,4 +1,6 @@
 use crate::governance;
+use crate::rewards::storage_types::RatePlan;
+use crate::rewards::{redemption, tiers};
 use crate::storage_types::DataKey;
 use crate::SavingsError;
 use soroban_sdk::{Address, Env};
@@ -7,7 +9,7 @@ use soroban_sdk::{Address, Env};
 
 pub fn set_flexi_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
-    governance::validate_admin_or_governance(env, &caller)?;
+    governance::require_admin_before_governance(env, &caller)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -18,7 +20,7 @@ pub fn set_flexi_rate(env: &Env, caller: Address, rate: i128) -> Result<(), Savi
 
 pub fn set_goal_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
-    governance::validate_admin_or_governance(env, &caller)?;
+    governance::require_admin_before_governance(env, &caller)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -29,7 +31,7 @@ pub fn set_goal_rate(env: &Env, caller: Address, rate: i128) -> Result<(), Savin
 
 pub fn set_group_rate(env: &Env, caller: Address, rate: i128) -> Result<(), SavingsError> {
     caller.require_auth();
-    governance::validate_admin_or_governance(env, &caller)?;
+    governance::require_admin_before_governance(env, &caller)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -45,7 +47,7 @@ pub fn set_lock_rate(
     rate: i128,
 ) -> Result<(), SavingsError> {
     caller.require_auth();
-    governance::validate_admin_or_governance(env, &caller)?;
+    governance::require_admin_before_governance(env, &caller)?;
 
     if rate < 0 {
         return Err(SavingsError::InvalidInterestRate);
@@ -86,6 +88,33 @@ pub fn get_lock_rate(env: &Env, duration_days: u64) -> Result<i128, SavingsError
         .ok_or(SavingsError::PlanNotFound)
 }
 
+/// Rate a plan earns when none has been configured for its product
+pub const DEFAULT_PLAN_RATE: i128 = 500;
+
+/// Rate `user` earns on `plan`: the configured rate (or `DEFAULT_PLAN_RATE`)
+/// plus their tier bonus and any boost they redeemed points for
+pub fn get_effective_rate(env: &Env, user: &Address, plan: RatePlan) -> i128 {
+    let key = match plan {
+        RatePlan::Flexi => DataKey::FlexiRate,
+        RatePlan::Goal => DataKey::GoalRate,
+        RatePlan::Group => DataKey::GroupRate,
+        RatePlan::Lock(duration_days) => DataKey::LockRate(duration_days),
+    };
+    let base: i128 = env
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or(DEFAULT_PLAN_RATE);
+    let boost = redemption::rate_boost_bps(env, user, &plan) as i128
+        + tiers::rate_bonus_bps(env, user) as i128;
+    base.saturating_add(boost)
+}
+
+/// Rate a plan `user` opens now fixes for its lifetime, from `get_effective_rate`
+pub fn plan_interest_rate(env: &Env, user: &Address, plan: RatePlan) -> u32 {
+    get_effective_rate(env, user, plan).clamp(0, u32::MAX as i128) as u32
+}
+
 // --- Interest Calculation Helpers ---
 
 pub fn calculate_flexi_interest(balance: i128, rate: i128, duration_seconds: u64) -> i128 {
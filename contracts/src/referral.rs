@@ -0,0 +1,224 @@
+use crate::errors::SavingsError;
+use crate::flexi;
+use crate::governance;
+use crate::rewards;
+use crate::storage_types::{ReferralConfig, ReferralStats};
+use crate::ttl::{EXTEND_TO, LOW_THRESHOLD};
+use crate::users;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+/// Storage keys for referrals; variant names must not clash with `DataKey` or
+/// `RewardsDataKey`, which share the same storage namespace
+#[contracttype]
+#[derive(Clone)]
+pub enum ReferralKey {
+    ReferralConfig,
+    /// Maps a referred user to their referrer
+    Referrer(Address),
+    /// Maps a referrer to the users they referred
+    Referrals(Address),
+    ReferralStats(Address),
+    /// Deposits made by a referred user who has not yet qualified
+    ReferralProgress(Address),
+    /// Set once a referred user's deposits have earned their referrer a reward
+    ReferralQualified(Address),
+}
+
+/// Sets the rewards paid to referrers. Admin only until governance is active,
+/// then only through a `SetReferralConfig` proposal.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidAmount` - If the deposit threshold is negative
+/// * `SavingsError::InvalidFeeBps` - If the fee share exceeds 10_000 bps
+pub fn set_referral_config(
+    env: &Env,
+    caller: Address,
+    config: ReferralConfig,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_referral_config_unchecked(env, config)
+}
+
+/// Applies a referral configuration without the admin check; used by governance
+pub(crate) fn set_referral_config_unchecked(
+    env: &Env,
+    config: ReferralConfig,
+) -> Result<(), SavingsError> {
+    if config.deposit_threshold < 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    if config.fee_share_bps > 10_000 {
+        return Err(SavingsError::InvalidFeeBps);
+    }
+
+    env.storage()
+        .instance()
+        .set(&ReferralKey::ReferralConfig, &config);
+    env.events()
+        .publish((symbol_short!("set_ref"),), config.referrer_points);
+    Ok(())
+}
+
+/// Returns the referral configuration, if one has been set
+pub fn get_referral_config(env: &Env) -> Option<ReferralConfig> {
+    env.storage().instance().get(&ReferralKey::ReferralConfig)
+}
+
+/// Records that `referrer` brought `user` to the protocol.
+///
+/// Must be called while `user` is being registered. A referrer has to be
+/// registered already and a user's referrer is fixed at sign-up, so nobody
+/// can end up in their own referral chain once self-referral is rejected.
+///
+/// # Errors
+/// * `SavingsError::InvalidReferrer` - If the referrer is the user or not registered
+pub fn record_referral(env: &Env, user: &Address, referrer: &Address) -> Result<(), SavingsError> {
+    if user == referrer || !users::user_exists(env, referrer) {
+        return Err(SavingsError::InvalidReferrer);
+    }
+
+    let referrer_key = ReferralKey::Referrer(user.clone());
+    env.storage().persistent().set(&referrer_key, referrer);
+    extend_ttl(env, &referrer_key);
+
+    let list_key = ReferralKey::Referrals(referrer.clone());
+    let mut referrals = get_referrals(env, referrer);
+    referrals.push_back(user.clone());
+    env.storage().persistent().set(&list_key, &referrals);
+    extend_ttl(env, &list_key);
+
+    let mut stats = get_referral_stats(env, referrer);
+    stats.referrals = stats
+        .referrals
+        .checked_add(1)
+        .ok_or(SavingsError::Overflow)?;
+    save_stats(env, referrer, &stats);
+
+    env.events()
+        .publish((symbol_short!("referral"), referrer.clone()), user.clone());
+    Ok(())
+}
+
+/// Returns who referred `user`, if anyone
+pub fn get_referrer(env: &Env, user: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Referrer(user.clone()))
+}
+
+/// Returns the users `referrer` has referred, in sign-up order
+pub fn get_referrals(env: &Env, referrer: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Referrals(referrer.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns a referrer's tally and earnings
+pub fn get_referral_stats(env: &Env, referrer: &Address) -> ReferralStats {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::ReferralStats(referrer.clone()))
+        .unwrap_or_default()
+}
+
+/// Counts a deposit by `user` towards their referrer's reward.
+///
+/// Once the user's deposits reach the configured threshold the referrer is
+/// credited `referrer_points`, exactly once per referred user.
+pub fn record_deposit(env: &Env, user: &Address, amount: i128) -> Result<(), SavingsError> {
+    let referrer = match get_referrer(env, user) {
+        Some(referrer) => referrer,
+        None => return Ok(()),
+    };
+    let config = match get_referral_config(env) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+
+    let qualified_key = ReferralKey::ReferralQualified(user.clone());
+    if env.storage().persistent().has(&qualified_key) {
+        return Ok(());
+    }
+
+    let progress_key = ReferralKey::ReferralProgress(user.clone());
+    let progress: i128 = env.storage().persistent().get(&progress_key).unwrap_or(0);
+    let progress = progress.checked_add(amount).ok_or(SavingsError::Overflow)?;
+    if progress < config.deposit_threshold {
+        env.storage().persistent().set(&progress_key, &progress);
+        extend_ttl(env, &progress_key);
+        return Ok(());
+    }
+
+    env.storage().persistent().remove(&progress_key);
+    env.storage().persistent().set(&qualified_key, &true);
+    extend_ttl(env, &qualified_key);
+
+    if config.referrer_points > 0 {
+        rewards::storage::add_points(env, referrer.clone(), config.referrer_points)?;
+    }
+
+    let mut stats = get_referral_stats(env, &referrer);
+    stats.qualified = stats
+        .qualified
+        .checked_add(1)
+        .ok_or(SavingsError::Overflow)?;
+    stats.points_earned = stats
+        .points_earned
+        .checked_add(config.referrer_points)
+        .ok_or(SavingsError::Overflow)?;
+    save_stats(env, &referrer, &stats);
+
+    env.events().publish(
+        (symbol_short!("ref_qual"), referrer, user.clone()),
+        config.referrer_points,
+    );
+    Ok(())
+}
+
+/// Pays the referrer's share of a protocol fee charged to `user`.
+///
+/// Returns the part of `fee_amount` left for the treasury.
+pub fn share_fee(env: &Env, user: &Address, fee_amount: i128) -> Result<i128, SavingsError> {
+    if fee_amount <= 0 {
+        return Ok(fee_amount);
+    }
+    let referrer = match get_referrer(env, user) {
+        Some(referrer) => referrer,
+        None => return Ok(fee_amount),
+    };
+    let share_bps = get_referral_config(env)
+        .map(|config| config.fee_share_bps)
+        .unwrap_or(0);
+    let share = crate::calculate_fee(fee_amount, share_bps)?;
+    if share == 0 {
+        return Ok(fee_amount);
+    }
+
+    flexi::credit_flexi_balance(env, &referrer, share)?;
+
+    let mut stats = get_referral_stats(env, &referrer);
+    stats.fees_earned = stats
+        .fees_earned
+        .checked_add(share)
+        .ok_or(SavingsError::Overflow)?;
+    save_stats(env, &referrer, &stats);
+
+    env.events()
+        .publish((symbol_short!("ref_fee"), referrer, user.clone()), share);
+    Ok(fee_amount - share)
+}
+
+fn save_stats(env: &Env, referrer: &Address, stats: &ReferralStats) {
+    let key = ReferralKey::ReferralStats(referrer.clone());
+    env.storage().persistent().set(&key, stats);
+    extend_ttl(env, &key);
+}
+
+fn extend_ttl(env: &Env, key: &ReferralKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, LOW_THRESHOLD, EXTEND_TO);
+}
@@ -15,7 +15,8 @@ pub fn initialize_rewards_config(env: &Env, config: RewardsConfig) -> Result<(),
     Ok(())
 }
 
-/// Updates existing rewards configuration. Only accessible by Admin.
+/// Updates existing rewards configuration. Only accessible by Admin, and only
+/// through proposals once governance is active.
 pub fn update_rewards_config(
     env: &Env,
     admin: Address,
@@ -24,20 +25,18 @@ pub fn update_rewards_config(
     // 1. Ensure the transaction was signed by the address provided
     admin.require_auth();
 
-    // 2. Security: Verify this address is actually the authorized Admin
-    // For now, we'll check against the admin address stored in your contract state.
-    // Replace 'get_admin(env)' with whatever function you use to fetch your admin address.
-    let stored_admin: Address = env
-        .storage()
-        .instance()
-        .get(&soroban_sdk::symbol_short!("admin"))
-        .ok_or(SavingsError::Unauthorized)?;
-
-    if admin != stored_admin {
-        return Err(SavingsError::Unauthorized);
-    }
+    // 2. Security: only the stored admin, and only until governance takes over
+    crate::governance::require_admin_before_governance(env, &admin)?;
 
     // 3. Validate and Save
+    update_rewards_config_unchecked(env, config)
+}
+
+/// Validates and saves a rewards configuration without the admin check; used by governance
+pub(crate) fn update_rewards_config_unchecked(
+    env: &Env,
+    config: RewardsConfig,
+) -> Result<(), SavingsError> {
     validate_config(&config)?;
     env.storage()
         .instance()
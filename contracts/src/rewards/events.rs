@@ -30,6 +30,43 @@ pub struct StreakUpdated {
     pub streak: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BenefitRedeemed {
+    pub user: Address,
+    pub item_id: u32,
+    pub cost: u128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierChanged {
+    pub user: Address,
+    pub old_level: u32,
+    pub new_level: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonStarted {
+    pub season_id: u32,
+    pub end_time: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeasonFinalized {
+    pub season_id: u32,
+    pub ranked_users: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointsExpired {
+    pub user: Address,
+    pub amount: u128,
+}
+
 /// Emits a PointsAwarded event.
 pub fn emit_points_awarded(env: &Env, user: Address, amount: u128) {
     let event = PointsAwarded {
@@ -78,3 +115,63 @@ pub fn emit_streak_updated(env: &Env, user: Address, streak: u32) {
         event,
     );
 }
+
+/// Emits a BenefitRedeemed event.
+pub fn emit_benefit_redeemed(env: &Env, user: Address, item_id: u32, cost: u128) {
+    let event = BenefitRedeemed {
+        user: user.clone(),
+        item_id,
+        cost,
+    };
+    env.events().publish(
+        (symbol_short!("rewards"), symbol_short!("benefit"), user),
+        event,
+    );
+}
+
+/// Emits a TierChanged event.
+pub fn emit_tier_changed(env: &Env, user: Address, old_level: u32, new_level: u32) {
+    let event = TierChanged {
+        user: user.clone(),
+        old_level,
+        new_level,
+    };
+    env.events().publish(
+        (symbol_short!("rewards"), symbol_short!("tier"), user),
+        event,
+    );
+}
+
+/// Emits a SeasonStarted event.
+pub fn emit_season_started(env: &Env, season_id: u32, end_time: u64) {
+    let event = SeasonStarted {
+        season_id,
+        end_time,
+    };
+    env.events()
+        .publish((symbol_short!("rewards"), symbol_short!("season")), event);
+}
+
+/// Emits a SeasonFinalized event.
+pub fn emit_season_finalized(env: &Env, season_id: u32, ranked_users: u32) {
+    let event = SeasonFinalized {
+        season_id,
+        ranked_users,
+    };
+    env.events().publish(
+        (symbol_short!("rewards"), symbol_short!("finalized")),
+        event,
+    );
+}
+
+/// Emits a PointsExpired event.
+pub fn emit_points_expired(env: &Env, user: Address, amount: u128) {
+    let event = PointsExpired {
+        user: user.clone(),
+        amount,
+    };
+    env.events().publish(
+        (symbol_short!("rewards"), symbol_short!("expired"), user),
+        event,
+    );
+}
@@ -0,0 +1,174 @@
+//! Point expiry: points are tracked in lots by earn day and lapse after a configured period.
+
+use super::storage_types::{PointLot, RewardsDataKey};
+use crate::errors::SavingsError;
+use crate::governance;
+use crate::rewards::events::emit_points_expired;
+use crate::rewards::ranking;
+use crate::rewards::storage::{get_user_rewards, save_user_rewards};
+use soroban_sdk::{Address, Env, Vec};
+
+const SECONDS_PER_DAY: u64 = 86400;
+
+/// Sets how many days earned points stay spendable. Admin only until governance
+/// is active, then only through a `SetPointsExpiryDays` proposal.
+///
+/// Each lot keeps the expiry day in force when it was earned, so a change,
+/// including passing 0 to disable expiry, only applies to points earned
+/// afterwards. Points earned while expiry is disabled are not tracked in lots;
+/// they are treated as the oldest and are spent first, but never expire.
+pub fn set_expiry_days(env: &Env, admin: Address, days: u64) -> Result<(), SavingsError> {
+    admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    set_expiry_days_unchecked(env, days)
+}
+
+/// Sets the expiry period without the admin check; used by governance
+pub(crate) fn set_expiry_days_unchecked(env: &Env, days: u64) -> Result<(), SavingsError> {
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::PointsExpiryDays, &days);
+    Ok(())
+}
+
+/// Days earned points stay spendable (0 = never expire)
+pub fn get_expiry_days(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::PointsExpiryDays)
+        .unwrap_or(0)
+}
+
+/// Returns the user's unexpired-or-unprocessed point lots, oldest first
+pub fn get_point_lots(env: &Env, user: &Address) -> Vec<PointLot> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::PointLots(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records `amount` newly earned points in today's lot
+pub fn record_earned(env: &Env, user: &Address, amount: u128) -> Result<(), SavingsError> {
+    if amount == 0 || get_expiry_days(env) == 0 {
+        return Ok(());
+    }
+
+    let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let expires_day = today.saturating_add(get_expiry_days(env));
+    let mut lots = get_point_lots(env, user);
+    match lots.last() {
+        // Merge same-day earnings so a user holds at most one lot per day and period
+        Some(mut lot) if lot.earned_day == today && lot.expires_day == expires_day => {
+            lot.amount = lot
+                .amount
+                .checked_add(amount)
+                .ok_or(SavingsError::Overflow)?;
+            lots.set(lots.len() - 1, lot);
+        }
+        _ => lots.push_back(PointLot {
+            earned_day: today,
+            expires_day,
+            amount,
+        }),
+    }
+    save_point_lots(env, user, &lots);
+    Ok(())
+}
+
+/// Removes lapsed lots and deducts them from the user's points.
+///
+/// Lots lapse on the expiry day fixed when they were earned, whatever the
+/// current expiry setting.
+///
+/// # Returns
+/// * The number of points that expired
+pub fn expire_points(env: &Env, user: &Address) -> Result<u128, SavingsError> {
+    let lots = get_point_lots(env, user);
+    if lots.is_empty() {
+        return Ok(0);
+    }
+
+    // Lots are in earn order, but a shortened period can make a newer lot lapse first
+    let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let mut expired: u128 = 0;
+    let mut remaining = Vec::new(env);
+    for lot in lots.iter() {
+        if lot.expires_day <= today {
+            expired = expired
+                .checked_add(lot.amount)
+                .ok_or(SavingsError::Overflow)?;
+        } else {
+            remaining.push_back(lot);
+        }
+    }
+    if expired == 0 {
+        return Ok(0);
+    }
+    save_point_lots(env, user, &remaining);
+
+    let mut rewards = get_user_rewards(env, user.clone());
+    rewards.total_points = rewards.total_points.saturating_sub(expired);
+    save_user_rewards(env, user.clone(), &rewards);
+    ranking::on_points_changed(env, user, rewards.total_points);
+
+    emit_points_expired(env, user.clone(), expired);
+    Ok(expired)
+}
+
+/// Consumes `amount` spent points from the user's lots, oldest first.
+///
+/// `total_points` is the balance before spending; the part of it not covered
+/// by lots predates expiry tracking and is spent before any lot.
+pub fn spend_points(
+    env: &Env,
+    user: &Address,
+    total_points: u128,
+    amount: u128,
+) -> Result<(), SavingsError> {
+    let mut lots = get_point_lots(env, user);
+    if lots.is_empty() {
+        return Ok(());
+    }
+
+    let mut tracked: u128 = 0;
+    for lot in lots.iter() {
+        tracked = tracked.saturating_add(lot.amount);
+    }
+    let mut remaining = amount.saturating_sub(total_points.saturating_sub(tracked));
+
+    while remaining > 0 {
+        let mut lot = match lots.first() {
+            Some(lot) => lot,
+            None => break,
+        };
+        if lot.amount <= remaining {
+            remaining -= lot.amount;
+            lots.pop_front();
+        } else {
+            lot.amount -= remaining;
+            remaining = 0;
+            lots.set(0, lot);
+        }
+    }
+    save_point_lots(env, user, &lots);
+    Ok(())
+}
+
+/// Points that will have expired by `now + within_seconds`, including any
+/// already lapsed but not yet deducted
+pub fn get_expiring_points(env: &Env, user: &Address, within_seconds: u64) -> u128 {
+    let horizon = env.ledger().timestamp().saturating_add(within_seconds) / SECONDS_PER_DAY;
+    let mut expiring: u128 = 0;
+    for lot in get_point_lots(env, user).iter() {
+        if lot.expires_day <= horizon {
+            expiring = expiring.saturating_add(lot.amount);
+        }
+    }
+    expiring
+}
+
+fn save_point_lots(env: &Env, user: &Address, lots: &Vec<PointLot>) {
+    let key = RewardsDataKey::PointLots(user.clone());
+    env.storage().persistent().set(&key, lots);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+}
@@ -1,11 +1,17 @@
 pub mod config;
 pub mod events;
+pub mod expiry;
 pub mod ranking;
 pub mod redemption;
+pub mod seasons;
 pub mod storage;
 pub mod storage_types;
+pub mod tiers;
 
 // Re-exporting these makes them accessible as crate::rewards::UserRewards
 pub use config::*;
 pub use events::*;
-pub use storage_types::{RewardsDataKey, UserRewards}; // Optional: re-exports config functions
+pub use storage_types::{
+    ActiveBenefit, PointLot, RatePlan, RedemptionBenefit, RedemptionItem, RewardsDataKey,
+    SavingsTier, Season, UserRewards,
+}; // Optional: re-exports config functions
@@ -1,10 +1,21 @@
+use super::seasons;
 use super::storage::get_user_rewards;
 use super::storage_types::RewardsDataKey;
 use soroban_sdk::{Address, Env, Vec};
 
-/// Maximum number of users to consider for ranking calculations
-/// Limits iteration to prevent excessive gas usage
-const MAX_RANKING_USERS: u32 = 1000;
+/// Number of entries kept on each leaderboard
+///
+/// Boards are updated incrementally as points change, so reads and writes
+/// cost O(LEADERBOARD_SIZE) regardless of how many users hold points.
+pub const LEADERBOARD_SIZE: u32 = 100;
+
+/// Number of users kept ranked just below the all-time board
+///
+/// All-time points fall on redemption and expiry. Users who drop off the
+/// board are replaced from the reserve, so the board only shrinks once more
+/// than this many users have fallen to the cutoff. Season points only grow,
+/// so season boards have no reserve.
+pub const LEADERBOARD_RESERVE_SIZE: u32 = 100;
 
 /// Represents a user's ranking entry
 #[derive(Clone, Debug)]
@@ -14,79 +25,178 @@ pub struct RankingEntry {
     pub rank: u32,
 }
 
-/// Retrieves the list of all tracked users for ranking purposes
-fn get_all_ranked_users(env: &Env) -> Vec<Address> {
-    let key = RewardsDataKey::AllUsers;
+/// Reads a leaderboard, sorted by points descending
+pub(crate) fn get_board(env: &Env, key: &RewardsDataKey) -> Vec<(Address, u128)> {
     env.storage()
         .persistent()
-        .get(&key)
+        .get(key)
         .unwrap_or_else(|| Vec::new(env))
 }
 
-/// Adds a user to the ranked users list if not already present
-/// Called internally when rewards are first awarded to a user
-pub fn track_user_for_ranking(env: &Env, user: Address) {
-    let key = RewardsDataKey::AllUsers;
-    let mut users = get_all_ranked_users(env);
-
-    // Check if user already tracked
-    for i in 0..users.len() {
-        if users.get(i).unwrap() == user {
-            return; // Already tracked
+/// Key of the cutoff stored alongside the board at `key`
+fn cutoff_key(key: &RewardsDataKey) -> RewardsDataKey {
+    match key {
+        RewardsDataKey::SeasonLeaderboard(season_id) => {
+            RewardsDataKey::SeasonLeaderboardCutoff(*season_id)
         }
+        _ => RewardsDataKey::LeaderboardCutoff,
     }
-
-    // Add new user
-    users.push_back(user);
-    env.storage().persistent().set(&key, &users);
 }
 
-/// Gets the top N users by total reward points
+/// Returns the cutoff of the board stored at `key`.
 ///
-/// # Arguments
-/// * `env` - The contract environment
-/// * `limit` - Maximum number of users to return (capped at MAX_RANKING_USERS)
+/// No user off the board and its reserve has more points than the cutoff,
+/// and every user on them has more, so their positions are exact ranks.
+pub(crate) fn get_cutoff(env: &Env, key: &RewardsDataKey) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&cutoff_key(key))
+        .unwrap_or(0)
+}
+
+/// Key of the reserve kept below the board stored at `key`, if it has one
+fn reserve_key(key: &RewardsDataKey) -> Option<RewardsDataKey> {
+    match key {
+        RewardsDataKey::Leaderboard => Some(RewardsDataKey::LeaderboardReserve),
+        _ => None,
+    }
+}
+
+/// Reads the board stored at `key` followed by its reserve, if any
+fn get_ranked_entries(env: &Env, key: &RewardsDataKey) -> Vec<(Address, u128)> {
+    let mut entries = get_board(env, key);
+    if let Some(reserve_key) = reserve_key(key) {
+        entries.append(&get_board(env, &reserve_key));
+    }
+    entries
+}
+
+/// Moves `user` to the position matching `points` on the board stored at `key`.
 ///
-/// # Returns
-/// Vec of (Address, points) tuples sorted by points descending
-pub fn get_top_users(env: &Env, limit: u32) -> Vec<(Address, u128)> {
-    let users = get_all_ranked_users(env);
-    let effective_limit = limit.min(MAX_RANKING_USERS).min(users.len());
-
-    // Build list of (user, points) tuples
-    let mut user_points = Vec::new(env);
-    for i in 0..users.len().min(MAX_RANKING_USERS) {
-        let user = users.get(i).unwrap();
-        let rewards = get_user_rewards(env, user.clone());
-        if rewards.total_points > 0 {
-            user_points.push_back((user, rewards.total_points));
+/// Ties keep the user who reached the score first ahead. A user whose points
+/// fall to the cutoff or below leaves the board, and the first reserve entry
+/// takes their slot. When the board and its reserve overflow, the last entry,
+/// and any entries tied with it, are evicted and the cutoff rises to their
+/// score.
+pub(crate) fn update_board(env: &Env, key: &RewardsDataKey, user: &Address, points: u128) {
+    let reserve_key = reserve_key(key);
+    let capacity = match reserve_key {
+        Some(_) => LEADERBOARD_SIZE + LEADERBOARD_RESERVE_SIZE,
+        None => LEADERBOARD_SIZE,
+    };
+    let mut entries = get_ranked_entries(env, key);
+    let mut cutoff = get_cutoff(env, key);
+
+    let mut existing = None;
+    for i in 0..entries.len() {
+        if entries.get(i).unwrap().0 == *user {
+            existing = Some(i);
+            break;
         }
     }
+    if let Some(i) = existing {
+        entries.remove(i);
+    } else if points <= cutoff {
+        return; // Not ranked and does not qualify
+    }
 
-    // Sort by points (descending) using bubble sort for simplicity
-    // Note: This is O(n²) but acceptable for limited user sets
-    let len = user_points.len();
-    for i in 0..len {
-        for j in 0..(len - i - 1) {
-            let current = user_points.get(j).unwrap();
-            let next = user_points.get(j + 1).unwrap();
-            if current.1 < next.1 {
-                // Swap
-                user_points.set(j, next);
-                user_points.set(j + 1, current);
+    if points > cutoff {
+        let mut position = entries.len();
+        for i in 0..entries.len() {
+            if entries.get(i).unwrap().1 < points {
+                position = i;
+                break;
             }
         }
+        entries.insert(position, (user.clone(), points));
+
+        if entries.len() > capacity {
+            cutoff = entries.pop_back().unwrap().1;
+            while entries.last().is_some_and(|(_, last)| last <= cutoff) {
+                entries.pop_back();
+            }
+            env.storage().persistent().set(&cutoff_key(key), &cutoff);
+            env.storage()
+                .persistent()
+                .extend_ttl(&cutoff_key(key), 17280, 17280);
+        }
     }
 
-    // Take top N
-    let mut result = Vec::new(env);
-    for i in 0..effective_limit.min(user_points.len()) {
-        result.push_back(user_points.get(i).unwrap());
+    let board_len = entries.len().min(LEADERBOARD_SIZE);
+    env.storage()
+        .persistent()
+        .set(key, &entries.slice(0..board_len));
+    env.storage().persistent().extend_ttl(key, 17280, 17280);
+    if let Some(reserve_key) = reserve_key {
+        env.storage()
+            .persistent()
+            .set(&reserve_key, &entries.slice(board_len..entries.len()));
+        env.storage()
+            .persistent()
+            .extend_ttl(&reserve_key, 17280, 17280);
     }
+}
 
+/// Registers a user as holding points, in O(1)
+/// Called internally whenever a user's points change
+pub fn track_user_for_ranking(env: &Env, user: Address) {
+    let key = RewardsDataKey::RankedUser(user);
+    if env.storage().persistent().has(&key) {
+        return; // Already tracked
+    }
+
+    env.storage().persistent().set(&key, &true);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+    let count = get_ranked_user_count(env);
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::RankedUserCount, &(count + 1));
+}
+
+/// Records a change in a user's spendable points on the all-time leaderboard
+pub fn on_points_changed(env: &Env, user: &Address, total_points: u128) {
+    if total_points > 0 {
+        track_user_for_ranking(env, user.clone());
+    }
+    update_board(env, &RewardsDataKey::Leaderboard, user, total_points);
+}
+
+/// Number of users who have ever been awarded points
+pub fn get_ranked_user_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::RankedUserCount)
+        .unwrap_or(0)
+}
+
+/// Gets the top N users by reward points, all-time or for one season
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `season` - `None` for all-time `total_points`, or a season ID
+/// * `limit` - Maximum number of users to return (capped at LEADERBOARD_SIZE)
+///
+/// # Returns
+/// Vec of (Address, points) tuples sorted by points descending
+pub fn get_top_users(env: &Env, season: Option<u32>, limit: u32) -> Vec<(Address, u128)> {
+    let board = match season {
+        Some(season_id) => seasons::get_season_board(env, season_id),
+        None => get_board(env, &RewardsDataKey::Leaderboard),
+    };
+
+    let mut result = Vec::new(env);
+    for i in 0..limit.min(board.len()) {
+        result.push_back(board.get(i).unwrap());
+    }
     result
 }
 
+/// Gets the all-time leaderboard cutoff; users with no more points than this
+/// are neither on the board nor in its reserve, and are unranked
+pub fn get_leaderboard_cutoff(env: &Env) -> u128 {
+    get_cutoff(env, &RewardsDataKey::Leaderboard)
+}
+
 /// Gets the rank of a specific user
 ///
 /// # Arguments
@@ -94,37 +204,13 @@ pub fn get_top_users(env: &Env, limit: u32) -> Vec<(Address, u128)> {
 /// * `user` - The user address to rank
 ///
 /// # Returns
-/// Rank (1-indexed) or 0 if user has no points or is not ranked
+/// Rank (1-indexed), or 0 (unranked) if the user has no points or no more
+/// than `get_leaderboard_cutoff`. Users in the reserve below the board are
+/// ranked too.
 pub fn get_user_rank(env: &Env, user: &Address) -> u32 {
-    let users = get_all_ranked_users(env);
-
-    // Build list of all users with points
-    let mut user_points = Vec::new(env);
-    for i in 0..users.len().min(MAX_RANKING_USERS) {
-        let current_user = users.get(i).unwrap();
-        let rewards = get_user_rewards(env, current_user.clone());
-        if rewards.total_points > 0 {
-            user_points.push_back((current_user, rewards.total_points));
-        }
-    }
-
-    // Sort by points descending
-    let len = user_points.len();
-    for i in 0..len {
-        for j in 0..(len - i - 1) {
-            let current = user_points.get(j).unwrap();
-            let next = user_points.get(j + 1).unwrap();
-            if current.1 < next.1 {
-                user_points.set(j, next);
-                user_points.set(j + 1, current);
-            }
-        }
-    }
-
-    // Find user's position
-    for i in 0..user_points.len() {
-        let entry = user_points.get(i).unwrap();
-        if entry.0 == *user {
+    let entries = get_ranked_entries(env, &RewardsDataKey::Leaderboard);
+    for i in 0..entries.len() {
+        if entries.get(i).unwrap().0 == *user {
             return i + 1; // 1-indexed rank
         }
     }
@@ -147,8 +233,5 @@ pub fn get_user_ranking_details(env: &Env, user: &Address) -> Option<(u32, u128,
         return None;
     }
 
-    let users = get_all_ranked_users(env);
-    let total_users = users.len().min(MAX_RANKING_USERS);
-
-    Some((rank, rewards.total_points, total_users))
+    Some((rank, rewards.total_points, get_ranked_user_count(env)))
 }
@@ -1,9 +1,14 @@
 //! Points redemption functionality for protocol benefits.
 
 use crate::errors::SavingsError;
-use crate::rewards::events::emit_points_redeemed;
+use crate::governance;
+use crate::rewards::events::{emit_benefit_redeemed, emit_points_redeemed};
 use crate::rewards::storage::{get_user_rewards, save_user_rewards};
-use soroban_sdk::{Address, Env};
+use crate::rewards::storage_types::{
+    ActiveBenefit, RatePlan, RedemptionBenefit, RedemptionItem, RewardsDataKey,
+};
+use crate::rewards::{expiry, ranking};
+use soroban_sdk::{Address, Env, Vec};
 
 /// Redeem points for protocol benefits (fee discounts, boost multiplier, etc.)
 ///
@@ -17,6 +22,7 @@ use soroban_sdk::{Address, Env};
 /// * `Err(SavingsError)` if insufficient points or arithmetic error
 ///
 /// # Safety
+/// * Expires lapsed points first, then spends the oldest points
 /// * Validates user has sufficient points
 /// * Uses checked arithmetic to prevent underflow
 /// * Emits PointsRedeemed event on success
@@ -26,6 +32,9 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
         return Err(SavingsError::InvalidAmount);
     }
 
+    // Drop lapsed points before checking the balance
+    expiry::expire_points(env, &user)?;
+
     // Get user rewards
     let mut rewards = get_user_rewards(env, user.clone());
 
@@ -34,6 +43,9 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
         return Err(SavingsError::InsufficientBalance);
     }
 
+    // Spend the oldest points first
+    expiry::spend_points(env, &user, rewards.total_points, amount)?;
+
     // Deduct points safely
     rewards.total_points = rewards
         .total_points
@@ -42,9 +54,227 @@ pub fn redeem_points(env: &Env, user: Address, amount: u128) -> Result<(), Savin
 
     // Save updated state
     save_user_rewards(env, user.clone(), &rewards);
+    ranking::on_points_changed(env, &user, rewards.total_points);
 
     // Emit redemption event
     emit_points_redeemed(env, user, amount);
 
     Ok(())
 }
+
+// ========== Redemption Catalog ==========
+
+/// Adds a benefit to the redemption catalog. Admin only until governance is
+/// active, then only through an `AddCatalogItem` proposal.
+///
+/// # Returns
+/// * The ID of the new catalog item
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidAmount` - If the cost or duration is zero
+/// * `SavingsError::InvalidFeeBps` - If a discount or boost exceeds 10_000 bps
+pub fn add_catalog_item(
+    env: &Env,
+    admin: Address,
+    item: RedemptionItem,
+) -> Result<u32, SavingsError> {
+    admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    add_catalog_item_unchecked(env, item)
+}
+
+/// Adds a catalog item without the admin check; used by governance
+pub(crate) fn add_catalog_item_unchecked(
+    env: &Env,
+    item: RedemptionItem,
+) -> Result<u32, SavingsError> {
+    validate_item(&item)?;
+
+    let id: u32 = env
+        .storage()
+        .instance()
+        .get(&RewardsDataKey::CatalogCount)
+        .unwrap_or(0)
+        + 1;
+    let key = RewardsDataKey::CatalogItem(id);
+    env.storage().persistent().set(&key, &item);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::CatalogCount, &id);
+    Ok(id)
+}
+
+/// Lists or retires an existing catalog item. Admin only until governance is
+/// active, then only through a `SetCatalogItemActive` proposal.
+pub fn set_catalog_item_active(
+    env: &Env,
+    admin: Address,
+    item_id: u32,
+    active: bool,
+) -> Result<(), SavingsError> {
+    admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    set_catalog_item_active_unchecked(env, item_id, active)
+}
+
+/// Lists or retires a catalog item without the admin check; used by governance
+pub(crate) fn set_catalog_item_active_unchecked(
+    env: &Env,
+    item_id: u32,
+    active: bool,
+) -> Result<(), SavingsError> {
+    let mut item = get_catalog_item(env, item_id).ok_or(SavingsError::RedemptionItemNotFound)?;
+    item.active = active;
+    let key = RewardsDataKey::CatalogItem(item_id);
+    env.storage().persistent().set(&key, &item);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+    Ok(())
+}
+
+/// Fetches a catalog item by ID
+pub fn get_catalog_item(env: &Env, item_id: u32) -> Option<RedemptionItem> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::CatalogItem(item_id))
+}
+
+/// Returns the number of items ever added to the catalog (IDs run 1..=count)
+pub fn get_catalog_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::CatalogCount)
+        .unwrap_or(0)
+}
+
+/// Spends points on a catalog item and grants its benefit to the user.
+///
+/// # Errors
+/// * `SavingsError::RedemptionItemNotFound` - If the item does not exist
+/// * `SavingsError::RedemptionUnavailable` - If the item is retired or its offer expired
+/// * `SavingsError::RedemptionLimitReached` - If the user has hit the per-user limit
+/// * `SavingsError::InsufficientBalance` - If the user lacks the points
+pub fn redeem_benefit(env: &Env, user: Address, item_id: u32) -> Result<(), SavingsError> {
+    let item = get_catalog_item(env, item_id).ok_or(SavingsError::RedemptionItemNotFound)?;
+    let now = env.ledger().timestamp();
+
+    if !item.active || (item.available_until != 0 && now > item.available_until) {
+        return Err(SavingsError::RedemptionUnavailable);
+    }
+
+    let count = get_redemption_count(env, &user, item_id);
+    if item.max_per_user != 0 && count >= item.max_per_user {
+        return Err(SavingsError::RedemptionLimitReached);
+    }
+
+    redeem_points(env, user.clone(), item.cost)?;
+
+    let count_key = RewardsDataKey::Redemptions(user.clone(), item_id);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&count_key, 17280, 17280);
+
+    // Drop lapsed benefits while we are rewriting the list anyway
+    let mut benefits = Vec::new(env);
+    for benefit in get_active_benefits(env, &user).iter() {
+        if benefit.expires_at > now {
+            benefits.push_back(benefit);
+        }
+    }
+    let expires_at = now
+        .checked_add(item.duration_seconds)
+        .ok_or(SavingsError::Overflow)?;
+    benefits.push_back(ActiveBenefit {
+        item_id,
+        benefit: item.benefit,
+        expires_at,
+    });
+    save_active_benefits(env, &user, &benefits);
+
+    emit_benefit_redeemed(env, user, item_id, item.cost);
+    Ok(())
+}
+
+/// Returns how many times `user` has redeemed `item_id`
+pub fn get_redemption_count(env: &Env, user: &Address, item_id: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::Redemptions(user.clone(), item_id))
+        .unwrap_or(0)
+}
+
+/// Returns the user's redeemed benefits, including any that have lapsed
+pub fn get_active_benefits(env: &Env, user: &Address) -> Vec<ActiveBenefit> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::ActiveBenefits(user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Largest unexpired protocol-fee discount (in bps) the user holds
+pub fn fee_discount_bps(env: &Env, user: &Address) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut best = 0;
+    for benefit in get_active_benefits(env, user).iter() {
+        if let RedemptionBenefit::FeeDiscount(bps) = benefit.benefit {
+            if benefit.expires_at > now && bps > best {
+                best = bps;
+            }
+        }
+    }
+    best
+}
+
+/// Largest unexpired rate boost (in bps) the user holds for `plan`
+pub fn rate_boost_bps(env: &Env, user: &Address, plan: &RatePlan) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut best = 0;
+    for benefit in get_active_benefits(env, user).iter() {
+        if let RedemptionBenefit::RateBoost(boosted, bps) = benefit.benefit {
+            if benefit.expires_at > now && boosted == *plan && bps > best {
+                best = bps;
+            }
+        }
+    }
+    best
+}
+
+/// Consumes one unexpired early-break fee waiver, returning whether one was held
+pub fn consume_early_break_waiver(env: &Env, user: &Address) -> bool {
+    let now = env.ledger().timestamp();
+    let benefits = get_active_benefits(env, user);
+    for (i, benefit) in benefits.iter().enumerate() {
+        if benefit.benefit == RedemptionBenefit::EarlyBreakWaiver && benefit.expires_at > now {
+            let mut remaining = benefits.clone();
+            remaining.remove(i as u32);
+            save_active_benefits(env, user, &remaining);
+            return true;
+        }
+    }
+    false
+}
+
+fn save_active_benefits(env: &Env, user: &Address, benefits: &Vec<ActiveBenefit>) {
+    let key = RewardsDataKey::ActiveBenefits(user.clone());
+    env.storage().persistent().set(&key, benefits);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+}
+
+fn validate_item(item: &RedemptionItem) -> Result<(), SavingsError> {
+    if item.cost == 0 || item.duration_seconds == 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    match &item.benefit {
+        RedemptionBenefit::FeeDiscount(bps) | RedemptionBenefit::RateBoost(_, bps) => {
+            if *bps == 0 || *bps > 10_000 {
+                return Err(SavingsError::InvalidFeeBps);
+            }
+        }
+        RedemptionBenefit::EarlyBreakWaiver => {}
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//! Reward seasons: time-boxed points counters with frozen final leaderboards.
+
+use super::ranking::{get_board, update_board};
+use super::storage_types::{RewardsDataKey, Season};
+use crate::errors::SavingsError;
+use crate::governance;
+use crate::rewards::events::{emit_season_finalized, emit_season_started};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Starts a new season running from now until `end_time`. Admin only until
+/// governance is active, then only through a `StartSeason` proposal.
+///
+/// # Arguments
+/// * `prizes` - Bonus points paid at finalization to rank 1, 2, ...
+///
+/// # Returns
+/// * The new season ID (IDs start at 1)
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::SeasonActive` - If the previous season has not been finalized
+/// * `SavingsError::InvalidTimestamp` - If `end_time` is not in the future
+pub fn start_season(
+    env: &Env,
+    admin: Address,
+    end_time: u64,
+    prizes: Vec<u128>,
+) -> Result<u32, SavingsError> {
+    admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    start_season_unchecked(env, end_time, prizes)
+}
+
+/// Starts a season without the admin check; used by governance
+pub(crate) fn start_season_unchecked(
+    env: &Env,
+    end_time: u64,
+    prizes: Vec<u128>,
+) -> Result<u32, SavingsError> {
+    let now = env.ledger().timestamp();
+    if end_time <= now {
+        return Err(SavingsError::InvalidTimestamp);
+    }
+
+    let current_id = get_current_season_id(env);
+    if let Some(current) = get_season(env, current_id) {
+        if !current.finalized {
+            return Err(SavingsError::SeasonActive);
+        }
+    }
+
+    let id = current_id + 1;
+    let season = Season {
+        id,
+        start_time: now,
+        end_time,
+        prizes,
+        finalized: false,
+    };
+    save_season(env, &season);
+    env.storage()
+        .instance()
+        .set(&RewardsDataKey::CurrentSeason, &id);
+
+    emit_season_started(env, id, end_time);
+    Ok(id)
+}
+
+/// Freezes a season's leaderboard and pays its prizes.
+///
+/// Prizes beyond the leaderboard size (`ranking::LEADERBOARD_SIZE`) are not paid.
+///
+/// Anyone may finalize a season once its end time has passed.
+///
+/// # Errors
+/// * `SavingsError::SeasonNotFound` - If the season does not exist
+/// * `SavingsError::SeasonNotEnded` - If the season is still running
+/// * `SavingsError::SeasonFinalized` - If the season was already finalized
+pub fn finalize_season(env: &Env, season_id: u32) -> Result<(), SavingsError> {
+    let mut season = get_season(env, season_id).ok_or(SavingsError::SeasonNotFound)?;
+    if season.finalized {
+        return Err(SavingsError::SeasonFinalized);
+    }
+    if env.ledger().timestamp() < season.end_time {
+        return Err(SavingsError::SeasonNotEnded);
+    }
+
+    let leaderboard = get_season_board(env, season_id);
+
+    // Mark finalized first so prize points do not count towards the season itself
+    season.finalized = true;
+    save_season(env, &season);
+
+    for i in 0..season.prizes.len().min(leaderboard.len()) {
+        let prize = season.prizes.get(i).unwrap();
+        if prize > 0 {
+            let (winner, _) = leaderboard.get(i).unwrap();
+            crate::rewards::storage::add_points(env, winner, prize)?;
+        }
+    }
+
+    emit_season_finalized(env, season_id, leaderboard.len());
+    Ok(())
+}
+
+/// Credits points earned now to the running season, if any
+pub fn record_season_points(env: &Env, user: &Address, points: u128) -> Result<(), SavingsError> {
+    if points == 0 {
+        return Ok(());
+    }
+    let season_id = get_current_season_id(env);
+    let season = match get_season(env, season_id) {
+        Some(season) => season,
+        None => return Ok(()),
+    };
+    if season.finalized || env.ledger().timestamp() >= season.end_time {
+        return Ok(());
+    }
+
+    let points_key = RewardsDataKey::SeasonPoints(season_id, user.clone());
+    let updated = get_season_points(env, season_id, user)
+        .checked_add(points)
+        .ok_or(SavingsError::Overflow)?;
+    env.storage().persistent().set(&points_key, &updated);
+    env.storage()
+        .persistent()
+        .extend_ttl(&points_key, 17280, 17280);
+
+    update_board(
+        env,
+        &RewardsDataKey::SeasonLeaderboard(season_id),
+        user,
+        updated,
+    );
+    Ok(())
+}
+
+/// Standings of a season; they stop changing once the season is finalized
+pub fn get_season_board(env: &Env, season_id: u32) -> Vec<(Address, u128)> {
+    get_board(env, &RewardsDataKey::SeasonLeaderboard(season_id))
+}
+
+/// Returns the ID of the latest season, or 0 if none has been started
+pub fn get_current_season_id(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::CurrentSeason)
+        .unwrap_or(0)
+}
+
+/// Fetches a season by ID
+pub fn get_season(env: &Env, season_id: u32) -> Option<Season> {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::Season(season_id))
+}
+
+/// Points a user earned during a season
+pub fn get_season_points(env: &Env, season_id: u32, user: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&RewardsDataKey::SeasonPoints(season_id, user.clone()))
+        .unwrap_or(0)
+}
+
+fn save_season(env: &Env, season: &Season) {
+    let key = RewardsDataKey::Season(season.id);
+    env.storage().persistent().set(&key, season);
+    env.storage().persistent().extend_ttl(&key, 17280, 17280);
+}
@@ -2,6 +2,7 @@ use super::storage_types::{RewardsDataKey, UserRewards};
 use crate::errors::SavingsError;
 use crate::rewards::config::get_rewards_config;
 use crate::rewards::events::{emit_bonus_awarded, emit_points_awarded, emit_streak_updated};
+use crate::rewards::{expiry, ranking, seasons, tiers};
 use soroban_sdk::{Address, Env, Symbol};
 
 /// Duration threshold for long-lock bonus eligibility (in seconds).
@@ -65,6 +66,7 @@ pub fn initialize_user_rewards(env: &Env, user: Address) -> Result<(), SavingsEr
 
 /// Increases user points with overflow protection
 pub fn add_points(env: &Env, user: Address, points: u128) -> Result<(), SavingsError> {
+    expiry::expire_points(env, &user)?;
     let mut rewards = get_user_rewards(env, user.clone());
 
     // Safety check for overflow
@@ -73,8 +75,10 @@ pub fn add_points(env: &Env, user: Address, points: u128) -> Result<(), SavingsE
         .checked_add(points)
         .ok_or(SavingsError::Overflow)?;
 
-    save_user_rewards(env, user, &rewards);
-    Ok(())
+    save_user_rewards(env, user.clone(), &rewards);
+    ranking::on_points_changed(env, &user, rewards.total_points);
+    expiry::record_earned(env, &user, points)?;
+    seasons::record_season_points(env, &user, points)
 }
 
 /// Resets the streak back to zero
@@ -123,6 +127,9 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         return Ok(());
     }
 
+    // Referral progress counts every deposit, whether or not rewards are enabled
+    crate::referral::record_deposit(env, &user, amount)?;
+
     // 1. Fetch Config & Check if Enabled
     let config = match get_rewards_config(env) {
         Ok(config) if config.enabled => config,
@@ -134,6 +141,9 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         return Ok(()); // No rewards for micro-deposits
     }
 
+    // Lapsed points must go before new ones are counted
+    expiry::expire_points(env, &user)?;
+
     let mut user_rewards = get_user_rewards(env, user.clone());
     let now = env.ledger().timestamp();
     let current_day = now / 86400;
@@ -178,9 +188,13 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         0
     };
 
+    // 5. Tier multiplier on everything earned by this deposit
     let total_points_awarded = base_points
         .checked_add(streak_bonus_points)
-        .ok_or(SavingsError::Overflow)?;
+        .ok_or(SavingsError::Overflow)?
+        .checked_mul(tiers::points_multiplier_bps(env, &user) as u128)
+        .ok_or(SavingsError::Overflow)?
+        / 10_000u128;
 
     // ANTI-FARMING: Cap to remaining daily allowance
     let remaining_daily = config
@@ -192,7 +206,8 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         return Ok(()); // Nothing to award after capping
     }
 
-    // 5. Update State
+    // 6. Update State
+    let previous_lifetime = user_rewards.lifetime_deposited;
     user_rewards.total_points = user_rewards
         .total_points
         .checked_add(capped_points)
@@ -208,11 +223,19 @@ pub fn award_deposit_points(env: &Env, user: Address, amount: i128) -> Result<()
         .checked_add(amount)
         .ok_or(SavingsError::Overflow)?;
 
-    // 6. Save and Emit Event
+    // 7. Save and Emit Event
     save_user_rewards(env, user.clone(), &user_rewards);
-
-    // Track user for ranking leaderboard
-    crate::rewards::ranking::track_user_for_ranking(env, user.clone());
+    expiry::record_earned(env, &user, capped_points)?;
+    seasons::record_season_points(env, &user, capped_points)?;
+    tiers::check_tier_change(
+        env,
+        &user,
+        previous_lifetime,
+        user_rewards.lifetime_deposited,
+    );
+
+    // Update ranking leaderboard
+    ranking::on_points_changed(env, &user, user_rewards.total_points);
 
     emit_points_awarded(env, user.clone(), capped_points);
 
@@ -328,7 +351,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
 
@@ -342,7 +365,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
         env.ledger()
@@ -358,7 +381,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
 
@@ -372,7 +395,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
         create_plan_deposit(&client, &user, 100);
@@ -387,7 +410,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
         let rewards_after_first = client.get_user_rewards(&user);
@@ -406,7 +429,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         create_plan_deposit(&client, &user, 100);
         env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
@@ -425,7 +448,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         assert_eq!(client.update_streak(&user), 1);
         env.ledger()
@@ -438,7 +461,7 @@ mod tests {
         let (env, client, _) = setup_env_with_rewards(default_rewards_config());
         let user = Address::generate(&env);
         env.mock_all_auths();
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         assert_eq!(client.update_streak(&user), 1);
         env.ledger().with_mut(|li| li.timestamp += 24 * 60 * 60);
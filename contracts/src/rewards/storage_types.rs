@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -29,9 +29,96 @@ pub struct UserRewards {
     pub last_reward_day: u64,      // Last day rewards were earned (ledger day)
 }
 
+/// Admin-configured savings tier, reached once `lifetime_deposited` meets the threshold
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsTier {
+    pub name: Symbol,                 // e.g., "bronze", "gold"
+    pub min_lifetime_deposited: i128, // Threshold to enter this tier
+    pub points_multiplier_bps: u32,   // Applied to deposit points (10_000 = 1x)
+    pub fee_discount_bps: u32,        // BPS off the protocol fee
+    pub rate_bonus_bps: u32,          // BPS added to the rate of plans opened in this tier
+}
+
+/// Admin-defined rewards epoch with its own points counter and leaderboard
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub id: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub prizes: Vec<u128>, // Bonus points for rank 1, 2, ... when the season is finalized
+    pub finalized: bool,
+}
+
+/// Points credited to a user on one ledger day, tracked so they can expire
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PointLot {
+    pub earned_day: u64,  // Ledger day (timestamp / 86400) the points were earned
+    pub expires_day: u64, // Ledger day the lot lapses, fixed when it was earned
+    pub amount: u128,     // Points from this lot not yet spent or expired
+}
+
+/// Savings product whose interest rate a `RateBoost` benefit applies to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RatePlan {
+    Flexi,
+    Goal,
+    Group,
+    Lock(u64), // Lock duration in days
+}
+
+/// What a user receives for redeeming a catalog item
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RedemptionBenefit {
+    FeeDiscount(u32),         // BPS off the protocol fee while active
+    RateBoost(RatePlan, u32), // BPS added to the plan's rate while active
+    EarlyBreakWaiver,         // Waives the fee on the next goal break
+}
+
+/// Admin-configured entry in the redemption catalog
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RedemptionItem {
+    pub benefit: RedemptionBenefit,
+    pub cost: u128,            // Points spent per redemption
+    pub duration_seconds: u64, // How long the benefit lasts once redeemed
+    pub available_until: u64,  // Offer expiry timestamp (0 = no expiry)
+    pub max_per_user: u32,     // Redemptions allowed per user (0 = unlimited)
+    pub active: bool,
+}
+
+/// A redeemed benefit held by a user
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveBenefit {
+    pub item_id: u32,
+    pub benefit: RedemptionBenefit,
+    pub expires_at: u64,
+}
+
 #[contracttype]
 pub enum RewardsDataKey {
     Config,
     UserLedger(Address),
-    AllUsers, // Tracks all users with rewards for ranking
+    RankedUser(Address), // Set once a user has been awarded points
+    RankedUserCount,
+    Leaderboard,        // All-time top users by points, see ranking::LEADERBOARD_SIZE
+    LeaderboardReserve, // Users ranked just below the all-time board, refilling it
+    LeaderboardCutoff,  // Highest score of any user left off the all-time board and reserve
+    CatalogCount,
+    CatalogItem(u32),
+    Redemptions(Address, u32), // Times a user redeemed a catalog item
+    ActiveBenefits(Address),
+    Tiers,         // Vec<SavingsTier>, ordered by threshold
+    CurrentSeason, // ID of the latest season (0 = none yet)
+    Season(u32),
+    SeasonPoints(u32, Address),
+    SeasonLeaderboard(u32), // Live standings, frozen once the season is finalized
+    SeasonLeaderboardCutoff(u32),
+    PointsExpiryDays,   // Days until earned points expire (0 = never)
+    PointLots(Address), // Vec<PointLot>, oldest first
 }
@@ -0,0 +1,100 @@
+//! Savings tiers derived from a user's lifetime deposit volume.
+
+use crate::errors::SavingsError;
+use crate::governance;
+use crate::rewards::events::emit_tier_changed;
+use crate::rewards::storage::get_user_rewards;
+use crate::rewards::storage_types::{RewardsDataKey, SavingsTier};
+use soroban_sdk::{Address, Env, Vec};
+
+/// Replaces the tier table. Admin only until governance is active, then only
+/// through a `SetSavingsTiers` proposal.
+///
+/// Tiers must be listed with strictly increasing `min_lifetime_deposited`;
+/// an empty list disables tiers.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If the caller is not the admin or governance is active
+/// * `SavingsError::InvalidAmount` - If thresholds are not positive and increasing
+/// * `SavingsError::InvalidFeeBps` - If a multiplier is below 1x or a discount exceeds 100%
+pub fn set_tiers(env: &Env, admin: Address, tiers: Vec<SavingsTier>) -> Result<(), SavingsError> {
+    admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    set_tiers_unchecked(env, tiers)
+}
+
+/// Replaces the tiers without the admin check; used by governance
+pub(crate) fn set_tiers_unchecked(env: &Env, tiers: Vec<SavingsTier>) -> Result<(), SavingsError> {
+    let mut previous: i128 = 0;
+    for tier in tiers.iter() {
+        if tier.min_lifetime_deposited <= previous {
+            return Err(SavingsError::InvalidAmount);
+        }
+        if tier.points_multiplier_bps < 10_000 || tier.fee_discount_bps > 10_000 {
+            return Err(SavingsError::InvalidFeeBps);
+        }
+        previous = tier.min_lifetime_deposited;
+    }
+
+    env.storage().instance().set(&RewardsDataKey::Tiers, &tiers);
+    Ok(())
+}
+
+/// Returns the configured tiers, lowest first
+pub fn get_tiers(env: &Env) -> Vec<SavingsTier> {
+    env.storage()
+        .instance()
+        .get(&RewardsDataKey::Tiers)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Tier level for a lifetime deposit volume: 0 below the first tier, then 1..=n
+pub fn tier_level_for(env: &Env, lifetime_deposited: i128) -> u32 {
+    let mut level = 0;
+    for tier in get_tiers(env).iter() {
+        if lifetime_deposited < tier.min_lifetime_deposited {
+            break;
+        }
+        level += 1;
+    }
+    level
+}
+
+/// Returns the tier the user currently qualifies for, if any
+pub fn get_user_tier(env: &Env, user: &Address) -> Option<SavingsTier> {
+    let lifetime = get_user_rewards(env, user.clone()).lifetime_deposited;
+    match tier_level_for(env, lifetime) {
+        0 => None,
+        level => get_tiers(env).get(level - 1),
+    }
+}
+
+/// Emits a tier-change event if moving between the two volumes crosses a threshold
+pub fn check_tier_change(env: &Env, user: &Address, previous_lifetime: i128, lifetime: i128) {
+    let old_level = tier_level_for(env, previous_lifetime);
+    let new_level = tier_level_for(env, lifetime);
+    if old_level != new_level {
+        emit_tier_changed(env, user.clone(), old_level, new_level);
+    }
+}
+
+/// Points multiplier (in bps) for the user's tier; 10_000 when untiered
+pub fn points_multiplier_bps(env: &Env, user: &Address) -> u32 {
+    get_user_tier(env, user)
+        .map(|tier| tier.points_multiplier_bps)
+        .unwrap_or(10_000)
+}
+
+/// Protocol-fee discount (in bps) granted by the user's tier
+pub fn fee_discount_bps(env: &Env, user: &Address) -> u32 {
+    get_user_tier(env, user)
+        .map(|tier| tier.fee_discount_bps)
+        .unwrap_or(0)
+}
+
+/// Rate bonus (in bps) granted by the user's tier
+pub fn rate_bonus_bps(env: &Env, user: &Address) -> u32 {
+    get_user_tier(env, user)
+        .map(|tier| tier.rate_bonus_bps)
+        .unwrap_or(0)
+}
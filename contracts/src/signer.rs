@@ -0,0 +1,223 @@
+//! Signature verification for off-chain authorizations, independent of key type.
+
+use crate::errors::SavingsError;
+use crate::governance;
+use crate::storage_types::{
+    DataKey, SignerKey, SignerProof, WebAuthnAssertion, WebAuthnRelyingParty,
+};
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, TryFromVal, Val};
+
+/// Longest `client_data_json` a WebAuthn assertion may carry
+pub const MAX_CLIENT_DATA_LEN: u32 = 1024;
+
+/// Longest origin a relying party may be configured with
+pub const MAX_ORIGIN_LEN: u32 = 256;
+
+/// Order of the P-256 group, big-endian
+const P256_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+/// Largest low-S value, `P256_ORDER / 2`, big-endian
+const P256_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0x80, 0x00, 0x00, 0x00, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xde, 0x73, 0x7d, 0x56, 0xd3, 0x8b, 0xcf, 0x42, 0x79, 0xdc, 0xe5, 0x61, 0x7e, 0x31, 0x92, 0xa8,
+];
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+const WEBAUTHN_GET_TYPE: &[u8] = b"\"type\":\"webauthn.get\"";
+const CHALLENGE_PREFIX: &[u8] = b"\"challenge\":\"";
+const ORIGIN_PREFIX: &[u8] = b"\"origin\":\"";
+
+/// Verifies `proof` by `signer` over `message`.
+///
+/// Ed25519 keys sign the message itself. Secp256r1 (passkey) keys prove it
+/// with a WebAuthn assertion for the configured relying party whose challenge
+/// is the message's SHA-256 digest; high-S signatures are normalized before
+/// verification.
+///
+/// # Errors
+/// * `SavingsError::InvalidSignature` - If the proof does not fit the key type,
+///   no relying party is configured, or the assertion is not a `webauthn.get`
+///   over `message` from the relying party's RP ID and origin with the user
+///   present
+///
+/// A bad cryptographic signature aborts the invocation.
+pub fn verify(
+    env: &Env,
+    signer: &SignerKey,
+    message: &Bytes,
+    proof: &SignerProof,
+) -> Result<(), SavingsError> {
+    match (signer, proof) {
+        (SignerKey::Ed25519(public_key), SignerProof::Ed25519(signature)) => {
+            env.crypto().ed25519_verify(public_key, message, signature);
+            Ok(())
+        }
+        (SignerKey::Secp256r1(public_key), SignerProof::WebAuthn(assertion)) => {
+            verify_webauthn(env, public_key, message, assertion)
+        }
+        _ => Err(SavingsError::InvalidSignature),
+    }
+}
+
+fn verify_webauthn(
+    env: &Env,
+    public_key: &BytesN<65>,
+    message: &Bytes,
+    assertion: &WebAuthnAssertion,
+) -> Result<(), SavingsError> {
+    let relying_party = get_relying_party(env).ok_or(SavingsError::InvalidSignature)?;
+
+    let challenge = env.crypto().sha256(message).to_array();
+    if !client_data_matches(&assertion.client_data_json, &challenge, &relying_party) {
+        return Err(SavingsError::InvalidSignature);
+    }
+
+    // 32-byte RP ID hash, then flags (bit 0 = user present), then a 4-byte counter
+    let authenticator_data = &assertion.authenticator_data;
+    if authenticator_data.len() < 37
+        || authenticator_data.slice(0..32) != Bytes::from(relying_party.rp_id_hash)
+        || authenticator_data.get_unchecked(32) & 0x01 == 0
+    {
+        return Err(SavingsError::InvalidSignature);
+    }
+
+    let mut signed = authenticator_data.clone();
+    signed.extend_from_array(&env.crypto().sha256(&assertion.client_data_json).to_array());
+    let digest = env.crypto().sha256(&signed);
+    let signature = BytesN::from_array(env, &normalize_s(assertion.signature.to_array()));
+    env.crypto()
+        .secp256r1_verify(public_key, &digest, &signature);
+    Ok(())
+}
+
+/// Whether `client_data_json` is a `webauthn.get` assertion for `challenge`
+/// made at the relying party's origin
+fn client_data_matches(
+    client_data_json: &Bytes,
+    challenge: &[u8; 32],
+    relying_party: &WebAuthnRelyingParty,
+) -> bool {
+    let len = client_data_json.len();
+    if len > MAX_CLIENT_DATA_LEN {
+        return false;
+    }
+    let mut buf = [0u8; MAX_CLIENT_DATA_LEN as usize];
+    let json = &mut buf[..len as usize];
+    client_data_json.copy_into_slice(json);
+
+    let mut expected_challenge = [0u8; 57];
+    expected_challenge[..13].copy_from_slice(CHALLENGE_PREFIX);
+    expected_challenge[13..56].copy_from_slice(&base64url(challenge));
+    expected_challenge[56] = b'"';
+
+    // `"origin":"` + origin + `"`
+    let origin_len = relying_party.origin.len() as usize;
+    let mut origin_buf = [0u8; ORIGIN_PREFIX.len() + MAX_ORIGIN_LEN as usize + 1];
+    let expected_origin = &mut origin_buf[..ORIGIN_PREFIX.len() + origin_len + 1];
+    expected_origin[..ORIGIN_PREFIX.len()].copy_from_slice(ORIGIN_PREFIX);
+    relying_party.origin.copy_into_slice(
+        &mut expected_origin[ORIGIN_PREFIX.len()..ORIGIN_PREFIX.len() + origin_len],
+    );
+    expected_origin[ORIGIN_PREFIX.len() + origin_len] = b'"';
+
+    contains(json, WEBAUTHN_GET_TYPE)
+        && contains(json, &expected_challenge)
+        && contains(json, expected_origin)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Unpadded base64url encoding of a 32-byte digest
+fn base64url(bytes: &[u8; 32]) -> [u8; 43] {
+    let mut out = [0u8; 43];
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    for &byte in bytes {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= 6 {
+            bits -= 6;
+            out[i] = BASE64URL[((acc >> bits) & 0x3f) as usize];
+            i += 1;
+        }
+        acc &= (1 << bits) - 1;
+    }
+    out[i] = BASE64URL[((acc << (6 - bits)) & 0x3f) as usize];
+    out
+}
+
+/// Replaces a high S with `n - S`, which the host requires and which is an
+/// equally valid signature
+fn normalize_s(mut signature: [u8; 64]) -> [u8; 64] {
+    if signature[32..] <= P256_HALF_ORDER[..] {
+        return signature;
+    }
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let mut diff = P256_ORDER[i] as i16 - signature[32 + i] as i16 - borrow;
+        borrow = 0;
+        if diff < 0 {
+            diff += 256;
+            borrow = 1;
+        }
+        signature[32 + i] = diff as u8;
+    }
+    signature
+}
+
+/// Reads a signer key stored at `key`.
+///
+/// Keys written before signer types existed were bare ed25519 public keys and
+/// are read as `SignerKey::Ed25519`.
+pub fn read_signer_key<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Option<SignerKey> {
+    let raw: Val = env.storage().instance().get(key)?;
+    SignerKey::try_from_val(env, &raw).ok().or_else(|| {
+        BytesN::<32>::try_from_val(env, &raw)
+            .ok()
+            .map(SignerKey::Ed25519)
+    })
+}
+
+/// Sets the relying party passkey assertions are checked against (admin only,
+/// and only through proposals once governance is active).
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If caller is not the admin, or governance is active
+/// * `SavingsError::InvalidAmount` - If the origin is empty or longer than `MAX_ORIGIN_LEN`
+pub fn set_relying_party(
+    env: &Env,
+    caller: Address,
+    relying_party: WebAuthnRelyingParty,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_relying_party_unchecked(env, relying_party)
+}
+
+/// Sets the relying party without the admin check; used by governance
+pub(crate) fn set_relying_party_unchecked(
+    env: &Env,
+    relying_party: WebAuthnRelyingParty,
+) -> Result<(), SavingsError> {
+    if relying_party.origin.is_empty() || relying_party.origin.len() > MAX_ORIGIN_LEN {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::WebAuthnRelyingParty, &relying_party);
+    Ok(())
+}
+
+/// Returns the configured relying party; passkey proofs fail until one is set
+pub fn get_relying_party(env: &Env) -> Option<WebAuthnRelyingParty> {
+    env.storage().instance().get(&DataKey::WebAuthnRelyingParty)
+}
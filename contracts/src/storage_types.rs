@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String, Symbol};
+use soroban_sdk::{contracterror, contracttype, Address, Bytes, BytesN, String, Symbol, Vec};
 
 /// Represents the different types of savings plans available in Nestera
 #[contracttype]
@@ -114,7 +114,31 @@ pub struct GoalSave {
     pub is_withdrawn: bool,
 }
 
-/// Represents an automated recurring deposit schedule for Flexi Save
+/// Destination plan for the deposits made by an AutoSave schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AutoSaveTarget {
+    /// Deposit into the user's Flexi balance
+    Flexi,
+    /// Deposit into an existing Goal Save owned by the user
+    Goal(u64),
+    /// Contribute to a Group Save the user is a member of
+    Group(u64),
+    /// Open a fresh Lock Save with the given duration (seconds) on each run
+    NewLock(u64),
+}
+
+/// How an AutoSave schedule handles slots that passed without being executed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CatchUpPolicy {
+    /// Run once, then skip ahead to the next future slot
+    SkipMissed,
+    /// Also make up at most this many missed runs in the same execution
+    ExecuteMissed(u32),
+}
+
+/// Represents an automated recurring deposit schedule
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AutoSave {
@@ -124,6 +148,130 @@ pub struct AutoSave {
     pub interval_seconds: u64,
     pub next_execution_time: u64,
     pub is_active: bool,
+    /// Plan that receives the deposit on each execution
+    pub target: AutoSaveTarget,
+    /// Keeper that last executed this schedule, if any
+    pub last_keeper: Option<Address>,
+    /// Temporarily suspended by the owner; resumable without losing the schedule
+    pub is_paused: bool,
+    /// Number of successful executions so far
+    pub runs_completed: u32,
+    /// Schedule ends after this many runs (0 = unlimited)
+    pub max_runs: u32,
+    /// No run is scheduled after this timestamp (0 = no end date)
+    pub end_time: u64,
+    /// Behaviour when the schedule is executed late
+    pub catch_up: CatchUpPolicy,
+    /// Failed batch executions since the last successful run
+    pub consecutive_failures: u32,
+}
+
+/// Layout of `AutoSave` schedules stored before targets, keepers and run
+/// limits existed; read back with `autosave::read_autosave`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAutoSave {
+    pub id: u64,
+    pub user: Address,
+    pub amount: i128,
+    pub interval_seconds: u64,
+    pub next_execution_time: u64,
+    pub is_active: bool,
+}
+
+impl From<LegacyAutoSave> for AutoSave {
+    /// Legacy schedules deposited into Flexi with no end and no catch-up
+    fn from(legacy: LegacyAutoSave) -> Self {
+        AutoSave {
+            id: legacy.id,
+            user: legacy.user,
+            amount: legacy.amount,
+            interval_seconds: legacy.interval_seconds,
+            next_execution_time: legacy.next_execution_time,
+            is_active: legacy.is_active,
+            target: AutoSaveTarget::Flexi,
+            last_keeper: None,
+            is_paused: false,
+            runs_completed: 0,
+            max_runs: 0,
+            end_time: 0,
+            catch_up: CatchUpPolicy::SkipMissed,
+            consecutive_failures: 0,
+        }
+    }
+}
+
+/// Where the keeper bounty for an executed AutoSave schedule is paid from
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeeperFeeSource {
+    /// Deducted from the scheduled deposit before it reaches the target
+    UserDeposit,
+    /// Paid from the protocol-funded keeper pool
+    ProtocolPool,
+}
+
+/// Admin-configured incentive for keepers that execute due AutoSave schedules
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperConfig {
+    /// Flat bounty paid per successful execution
+    pub fee: i128,
+    pub source: KeeperFeeSource,
+    /// Schedules running more often than this earn no bounty
+    pub min_interval_seconds: u64,
+    /// Schedules depositing less than this per run earn no bounty, so
+    /// splitting savings across many tiny schedules does not farm bounties
+    pub min_amount: i128,
+}
+
+/// Per-keeper execution tally
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct KeeperStats {
+    pub executions: u64,
+    pub total_earned: i128,
+}
+
+/// Position in the AutoSave due index; start from the default (0, 0) cursor
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AutoSaveCursor {
+    pub bucket: u64,
+    pub offset: u32,
+}
+
+/// One page of due AutoSave schedule IDs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoSaveDuePage {
+    pub schedule_ids: Vec<u64>,
+    /// Pass back to continue listing while `has_more` is true
+    pub next_cursor: AutoSaveCursor,
+    pub has_more: bool,
+}
+
+/// Rewards paid to referrers, configured by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralConfig {
+    /// Total a referred user must deposit before their referrer is rewarded
+    pub deposit_threshold: i128,
+    /// Points credited to the referrer once the threshold is passed
+    pub referrer_points: u128,
+    /// Share of the referred user's protocol fees paid to the referrer (bps)
+    pub fee_share_bps: u32,
+}
+
+/// Referral tally for a referrer
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferralStats {
+    pub referrals: u32,
+    /// Referred users who have passed the deposit threshold
+    pub qualified: u32,
+    pub points_earned: u128,
+    pub fees_earned: i128,
 }
 
 /// Storage keys for the contract's persistent data
@@ -192,6 +340,20 @@ pub enum DataKey {
     UserAutoSaves(Address),
     /// Stores the next auto-incrementing AutoSave schedule ID
     NextAutoSaveId,
+    /// Keeper bounty configuration for AutoSave execution
+    KeeperConfig,
+    /// Protocol-funded balance used to pay keeper bounties
+    KeeperPool,
+    /// Maps keeper address to its KeeperStats
+    KeeperStats(Address),
+    /// Consecutive batch failures after which an AutoSave schedule is deactivated
+    AutoSaveMaxFailures,
+    /// Maps a due-time bucket to the (schedule ID, next execution time) pairs it holds
+    AutoSaveDueBucket(u64),
+    /// Sorted IDs of the due-time buckets that hold any schedules
+    AutoSaveDueBuckets,
+    /// Relying party WebAuthn assertions are checked against
+    WebAuthnRelyingParty,
     // Interest Rates
     FlexiRate,
     GoalRate,
@@ -215,6 +377,98 @@ pub struct MintPayload {
     pub timestamp: u64,
     /// Expiry duration in seconds (signature valid for timestamp + expiry_duration)
     pub expiry_duration: u64,
+    /// Admin-chosen value unique per user; each (user, nonce) can be minted once
+    pub nonce: u64,
+}
+
+/// A public key able to sign off-chain authorizations
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SignerKey {
+    /// 32-byte ed25519 public key
+    Ed25519(BytesN<32>),
+    /// 65-byte SEC-1 uncompressed secp256r1 public key, as used by passkeys
+    Secp256r1(BytesN<65>),
+}
+
+/// A WebAuthn assertion made by a passkey over a signing message
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WebAuthnAssertion {
+    /// Authenticator data as returned by the authenticator
+    pub authenticator_data: Bytes,
+    /// Client data JSON; its challenge is the base64url SHA-256 of the message
+    pub client_data_json: Bytes,
+    /// ECDSA P-256 signature (r || s) over `authenticator_data || sha256(client_data_json)`
+    pub signature: BytesN<64>,
+}
+
+/// The relying party passkey assertions must be made for
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WebAuthnRelyingParty {
+    /// SHA-256 of the RP ID, as it leads the authenticator data
+    pub rp_id_hash: BytesN<32>,
+    /// Origin the client data must report, e.g. `https://nestera.app`
+    pub origin: String,
+}
+
+/// Proof that a `SignerKey` signed a message, in the form its key type produces
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum SignerProof {
+    /// Ed25519 signature over the message itself
+    Ed25519(BytesN<64>),
+    /// Passkey assertion, for `SignerKey::Secp256r1`
+    WebAuthn(WebAuthnAssertion),
+}
+
+/// One signature over a mint's signing message, with the key that made it
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintSignature {
+    pub public_key: SignerKey,
+    pub signature: SignerProof,
+}
+
+/// Keys that must co-sign large mints
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintSignerSet {
+    pub signers: Vec<SignerKey>,
+    /// Distinct signers required (M of N)
+    pub threshold: u32,
+    /// Mints of at least this amount need `threshold` signers instead of the admin key
+    pub large_amount: i128,
+}
+
+/// An admin signing key replaced by rotation, still accepted until `valid_until`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RetiredAdminKey {
+    pub public_key: SignerKey,
+    pub valid_until: u64,
+}
+
+/// Where a signed mint claim is paid out
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum MintTarget {
+    /// Credits the user's Flexi balance
+    Flexi,
+    /// Mints a reward token; this contract must be the token's admin
+    Token(Address),
+}
+
+/// Mint payout and caps; a cap of 0 means unlimited
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintConfig {
+    pub target: MintTarget,
+    /// Maximum a single user can ever mint
+    pub per_user_cap: i128,
+    /// Maximum minted across all users
+    pub global_cap: i128,
 }
 
 /// Performance metrics for a yield strategy (frontend-ready, read-only view)
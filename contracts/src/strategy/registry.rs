@@ -25,12 +25,13 @@ pub enum StrategyKey {
     AllStrategies,
 }
 
-// ========== Admin / Governance Guard ==========
+// ========== Admin Guard ==========
 
-/// Ensures the caller is the admin or governance is active.
-fn require_admin_or_governance(env: &Env, caller: &Address) -> Result<(), SavingsError> {
+/// Ensures the caller is the admin and governance is not yet active; after
+/// activation strategies are managed through proposals.
+fn require_admin(env: &Env, caller: &Address) -> Result<(), SavingsError> {
     caller.require_auth();
-    governance::validate_admin_or_governance(env, caller)?;
+    governance::require_admin_before_governance(env, caller)?;
     Ok(())
 }
 
@@ -38,16 +39,17 @@ fn require_admin_or_governance(env: &Env, caller: &Address) -> Result<(), Saving
 
 /// Registers a new yield strategy.
 ///
-/// Only callable by admin or governance. The strategy is enabled by default.
+/// Only callable by the admin, and only through proposals once governance is
+/// active. The strategy is enabled by default.
 ///
 /// # Arguments
 /// * `env` - The contract environment
-/// * `caller` - Admin or governance caller
+/// * `caller` - Admin caller
 /// * `strategy_address` - Address of the strategy contract
 /// * `risk_level` - Risk classification (0-255)
 ///
 /// # Errors
-/// * `Unauthorized` - If caller is not admin / governance is not active
+/// * `Unauthorized` - If caller is not admin, or governance is active
 /// * `StrategyAlreadyRegistered` - If the strategy address is already registered
 pub fn register_strategy(
     env: &Env,
@@ -55,8 +57,16 @@ pub fn register_strategy(
     strategy_address: Address,
     risk_level: u32,
 ) -> Result<(), SavingsError> {
-    require_admin_or_governance(env, &caller)?;
+    require_admin(env, &caller)?;
+    register_strategy_unchecked(env, strategy_address, risk_level)
+}
 
+/// Registers a strategy without the admin check; used by governance
+pub(crate) fn register_strategy_unchecked(
+    env: &Env,
+    strategy_address: Address,
+    risk_level: u32,
+) -> Result<(), SavingsError> {
     let info_key = StrategyKey::Info(strategy_address.clone());
 
     // Prevent duplicate registration
@@ -106,19 +116,26 @@ pub fn register_strategy(
 ///
 /// # Arguments
 /// * `env` - The contract environment
-/// * `caller` - Admin or governance caller
+/// * `caller` - Admin caller
 /// * `strategy_address` - Address of the strategy to disable
 ///
 /// # Errors
-/// * `Unauthorized` - If caller is not admin / governance is not active
+/// * `Unauthorized` - If caller is not admin, or governance is active
 /// * `StrategyNotFound` - If the strategy is not registered
 pub fn disable_strategy(
     env: &Env,
     caller: Address,
     strategy_address: Address,
 ) -> Result<(), SavingsError> {
-    require_admin_or_governance(env, &caller)?;
+    require_admin(env, &caller)?;
+    disable_strategy_unchecked(env, strategy_address)
+}
 
+/// Disables a strategy without the admin check; used by governance
+pub(crate) fn disable_strategy_unchecked(
+    env: &Env,
+    strategy_address: Address,
+) -> Result<(), SavingsError> {
     let info_key = StrategyKey::Info(strategy_address.clone());
     let mut info: StrategyInfo = env
         .storage()
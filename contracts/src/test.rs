@@ -26,9 +26,9 @@ fn test_successful_break_group_save() {
     env.mock_all_auths();
 
     // Initialize users
-    client.initialize_user(&creator);
-    client.initialize_user(&member1);
-    client.initialize_user(&member2);
+    client.initialize_user(&creator, &None);
+    client.initialize_user(&member1, &None);
+    client.initialize_user(&member2, &None);
 
     // Create a group
     let group_id = client.create_group_save(
@@ -70,9 +70,9 @@ fn test_break_group_updates_member_count() {
 
     env.mock_all_auths();
 
-    client.initialize_user(&creator);
-    client.initialize_user(&member1);
-    client.initialize_user(&member2);
+    client.initialize_user(&creator, &None);
+    client.initialize_user(&member1, &None);
+    client.initialize_user(&member2, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -103,7 +103,7 @@ fn test_break_group_updates_user_groups_list() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
 
     // Create first group
     let group1_id = client.create_group_save(
@@ -148,8 +148,8 @@ fn test_break_group_refunds_contribution() {
     let member = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&creator);
-    client.initialize_user(&member);
+    client.initialize_user(&creator, &None);
+    client.initialize_user(&member, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -182,8 +182,8 @@ fn test_break_group_with_zero_contribution() {
     let member = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&creator);
-    client.initialize_user(&member);
+    client.initialize_user(&creator, &None);
+    client.initialize_user(&member, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -213,8 +213,8 @@ fn test_error_user_not_member() {
     let non_member = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&creator);
-    client.initialize_user(&non_member);
+    client.initialize_user(&creator, &None);
+    client.initialize_user(&non_member, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -240,7 +240,7 @@ fn test_error_group_completed() {
     let creator = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&creator);
+    client.initialize_user(&creator, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -269,7 +269,7 @@ fn test_error_group_not_found() {
     let user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&user);
+    client.initialize_user(&user, &None);
 
     // Try to leave non-existent group
     let result = client.try_break_group_save(&user, &999);
@@ -283,7 +283,7 @@ fn test_error_user_not_found() {
     let non_existent_user = Address::generate(&env);
 
     env.mock_all_auths();
-    client.initialize_user(&creator);
+    client.initialize_user(&creator, &None);
 
     let group_id = client.create_group_save(
         &creator,
@@ -2,8 +2,8 @@
 mod transition_tests {
     use crate::governance::ProposalAction;
     use crate::rewards::storage_types::RewardsConfig;
-    use crate::{NesteraContract, NesteraContractClient, PlanType};
-    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+    use crate::{NesteraContract, NesteraContractClient, PlanType, SavingsError};
+    use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
 
     fn setup_contract() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -74,13 +74,20 @@ mod transition_tests {
     }
 
     #[test]
-    fn test_admin_can_still_act_after_governance_active() {
+    fn test_rate_setters_closed_after_governance_active() {
         let (env, client, admin) = setup_contract();
         env.mock_all_auths();
 
         client.activate_governance(&admin);
-        let result = client.try_set_flexi_rate(&admin, &600);
-        assert!(result.is_ok());
+        let unauthorized = Err(Ok(SavingsError::Unauthorized));
+        assert_eq!(client.try_set_flexi_rate(&admin, &600), unauthorized);
+        assert_eq!(client.try_set_goal_rate(&admin, &600), unauthorized);
+        assert_eq!(client.try_set_group_rate(&admin, &600), unauthorized);
+        assert_eq!(client.try_set_lock_rate(&admin, &30, &600), unauthorized);
+        assert_eq!(
+            client.try_set_flexi_rate(&Address::generate(&env), &600),
+            unauthorized
+        );
     }
 
     #[test]
@@ -93,7 +100,7 @@ mod transition_tests {
         let creator = Address::generate(&env);
         let description = String::from_str(&env, "Set flexi rate to 500");
 
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
         let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
 
         let action = ProposalAction::SetFlexiRate(500);
@@ -104,7 +111,10 @@ mod transition_tests {
             .unwrap();
 
         let proposal = client.get_action_proposal(&proposal_id).unwrap();
-        assert_eq!(proposal.action, ProposalAction::SetFlexiRate(500));
+        assert_eq!(
+            proposal.actions,
+            vec![&env, ProposalAction::SetFlexiRate(500)]
+        );
     }
 
     #[test]
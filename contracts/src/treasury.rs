@@ -0,0 +1,130 @@
+//! Governance-approved spending of protocol fees held by the treasury, and
+//! the history of those spends.
+
+use crate::config;
+use crate::errors::SavingsError;
+use crate::flexi;
+use crate::storage_types::DataKey;
+use crate::ttl::{EXTEND_TO, LOW_THRESHOLD};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+/// Most spends `get_treasury_spends` returns per call.
+pub const MAX_SPENDS_PER_PAGE: u32 = 50;
+
+/// Storage keys for treasury spends; variant names must not clash with
+/// `DataKey` or the other key enums, which share the same storage namespace
+#[contracttype]
+#[derive(Clone)]
+pub enum TreasuryKey {
+    TreasurySpendCount,
+    TreasurySpend(u64),
+}
+
+/// One executed `TreasuryTransfer`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasurySpend {
+    pub id: u64,
+    /// Proposal that approved the spend
+    pub proposal_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub executed_at: u64,
+}
+
+/// Returns the protocol fees accrued to the treasury and not yet spent
+pub fn get_treasury_balance(env: &Env) -> Result<i128, SavingsError> {
+    let treasury = config::get_config(env)?.treasury;
+    Ok(env
+        .storage()
+        .persistent()
+        .get(&DataKey::TotalBalance(treasury))
+        .unwrap_or(0))
+}
+
+/// Moves `amount` of accrued fees from the treasury to `recipient`'s Flexi
+/// balance and records the spend.
+///
+/// Only reachable through an executed `TreasuryTransfer` proposal, so it
+/// always runs after the timelock.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If `amount` is not positive
+/// * `SavingsError::InsufficientBalance` - If it exceeds the accrued fee balance
+/// * `SavingsError::UserNotFound` - If `recipient` has no savings account
+pub(crate) fn spend_unchecked(
+    env: &Env,
+    proposal_id: u64,
+    recipient: &Address,
+    amount: i128,
+) -> Result<(), SavingsError> {
+    if amount <= 0 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    let treasury = config::get_config(env)?.treasury;
+    let balance_key = DataKey::TotalBalance(treasury);
+    let balance: i128 = env.storage().persistent().get(&balance_key).unwrap_or(0);
+    if amount > balance {
+        return Err(SavingsError::InsufficientBalance);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&balance_key, &(balance - amount));
+    flexi::credit_flexi_balance(env, recipient, amount)?;
+
+    let id = get_treasury_spend_count(env);
+    let spend = TreasurySpend {
+        id,
+        proposal_id,
+        recipient: recipient.clone(),
+        amount,
+        executed_at: env.ledger().timestamp(),
+    };
+    let spend_key = TreasuryKey::TreasurySpend(id);
+    env.storage().persistent().set(&spend_key, &spend);
+    env.storage()
+        .persistent()
+        .extend_ttl(&spend_key, LOW_THRESHOLD, EXTEND_TO);
+    env.storage()
+        .persistent()
+        .set(&TreasuryKey::TreasurySpendCount, &(id + 1));
+
+    env.events().publish(
+        (
+            symbol_short!("treasury"),
+            symbol_short!("spend"),
+            recipient.clone(),
+        ),
+        spend,
+    );
+    Ok(())
+}
+
+/// Returns how many treasury spends have been executed
+pub fn get_treasury_spend_count(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&TreasuryKey::TreasurySpendCount)
+        .unwrap_or(0)
+}
+
+pub fn get_treasury_spend(env: &Env, id: u64) -> Option<TreasurySpend> {
+    env.storage()
+        .persistent()
+        .get(&TreasuryKey::TreasurySpend(id))
+}
+
+/// Returns up to `limit` spends, oldest first, starting at spend `start`.
+/// `limit` is capped at `MAX_SPENDS_PER_PAGE`.
+pub fn get_treasury_spends(env: &Env, start: u64, limit: u32) -> Vec<TreasurySpend> {
+    let mut spends = Vec::new(env);
+    let end = get_treasury_spend_count(env)
+        .min(start.saturating_add(limit.min(MAX_SPENDS_PER_PAGE) as u64));
+    for id in start..end {
+        if let Some(spend) = get_treasury_spend(env, id) {
+            spends.push_back(spend);
+        }
+    }
+    spends
+}
@@ -156,6 +156,16 @@ pub fn extend_autosave_ttl(env: &Env, schedule_id: u64) {
     }
 }
 
+/// Extends TTL for AutoSave due-index entries
+pub fn extend_autosave_index_ttl(env: &Env, index_key: &DataKey) {
+    // Only extend TTL if the key exists
+    if env.storage().persistent().has(index_key) {
+        env.storage()
+            .persistent()
+            .extend_ttl(index_key, LOW_THRESHOLD, EXTEND_TO);
+    }
+}
+
 /// Extends TTL for configuration entries (rates, fees, etc.)
 pub fn extend_config_ttl(env: &Env, config_key: &DataKey) {
     // Only extend TTL if the key exists
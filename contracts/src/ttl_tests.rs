@@ -18,7 +18,7 @@ mod tests {
         env.mock_all_auths();
 
         // Create user - should extend TTL
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         // Verify user exists
         assert!(client.user_exists(&user));
@@ -36,7 +36,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         // Deposit to flexi - should extend TTL
         assert!(client.try_deposit_flexi(&user, &1000).is_ok());
@@ -60,7 +60,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         // Create lock save - should extend TTL
         let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60));
@@ -83,7 +83,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "vacation");
 
@@ -111,7 +111,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         let goal_name = Symbol::new(&env, "car");
 
@@ -139,7 +139,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         // Create autosave schedule - should extend TTL
         let schedule_id =
@@ -195,7 +195,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         // Create a lock save
         let lock_id = client.create_lock_save(&user, &5000, &(30 * 24 * 60 * 60));
@@ -219,7 +219,7 @@ mod tests {
         env.mock_all_auths();
 
         // Initialize user
-        client.initialize_user(&creator);
+        client.initialize_user(&creator, &None);
 
         // Create group save - should extend TTL
         let group_id = client.create_group_save(
@@ -240,7 +240,7 @@ mod tests {
 
         // Join operations should extend TTL for all members
         let member = Address::generate(&env);
-        client.initialize_user(&member);
+        client.initialize_user(&member, &None);
         client.join_group_save(&member, &group_id);
     }
 }
@@ -1,4 +1,6 @@
 // use crate::storage_types::DataKey;
+use crate::errors::SavingsError;
+use crate::governance;
 use soroban_sdk::{contracttype, Address, BytesN, Env}; // Assuming you have storage keys defined here, add panic with error when necessary
 
 #[contracttype]
@@ -21,10 +23,25 @@ pub fn set_version(env: &Env, version: u32) {
         .set(&UpgradeDataKey::ContractVersion, &version);
 }
 
-pub fn upgrade_contract(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) {
+/// Upgrades the WASM on behalf of the stored admin.
+///
+/// # Errors
+/// * `SavingsError::Unauthorized` - If `admin` is not the stored admin, or
+///   governance is active (upgrades then go through proposals)
+pub fn upgrade_contract(
+    env: &Env,
+    admin: Address,
+    new_wasm_hash: BytesN<32>,
+) -> Result<(), SavingsError> {
     // 1. Verify Authorization
     admin.require_auth();
+    governance::require_admin_before_governance(env, &admin)?;
+    upgrade_contract_unchecked(env, new_wasm_hash);
+    Ok(())
+}
 
+/// Upgrades the WASM without the admin check; used by governance
+pub(crate) fn upgrade_contract_unchecked(env: &Env, new_wasm_hash: BytesN<32>) {
     // 2. Perform Version Validation (Migration Safety)
     let current_version = get_version(env);
     let new_version = CONTRACT_VERSION; // This would typically come from the new WASM logic
@@ -4,6 +4,7 @@ use crate::ensure_not_paused;
 use crate::errors::SavingsError;
 use crate::storage_types::{DataKey, User};
 use crate::ttl;
+use crate::voting_power;
 
 /// Check if a user exists in storage
 ///
@@ -44,6 +45,22 @@ pub fn get_user(env: &Env, user: &Address) -> Result<User, SavingsError> {
     Ok(user_data)
 }
 
+/// Save a user to storage
+///
+/// Voting power follows the liquid part of `total_balance`, so every write
+/// also checkpoints it.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `user` - The address of the user to save
+/// * `user_data` - The updated user record
+pub fn save_user(env: &Env, user: &Address, user_data: &User) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::User(user.clone()), user_data);
+    voting_power::checkpoint_balance(env, user, user_data.total_balance);
+}
+
 /// Initialize a new user in the savings contract
 ///
 /// This function creates a new user record with zero balances.
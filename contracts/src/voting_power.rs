@@ -0,0 +1,713 @@
+//! Governance voting power: checkpointed savings balances, vote-escrowed Lock
+//! Saves and delegation.
+//!
+//! A user's own power has two parts:
+//! - their liquid balance (`total_balance` minus Lock Save principal), weighted
+//!   by the configured balance weight
+//! - every active Lock Save, weighted by its remaining lock time: full weight
+//!   while at least `MAX_LOCK_DURATION` remains, then decaying linearly to zero
+//!   at maturity
+//!
+//! Both parts are checkpointed for whoever holds the user's votes (the user or
+//! their delegate) and for the protocol total, so power can be read as of any
+//! past timestamp. Lock power is stored as a piecewise-linear curve scaled by
+//! `MAX_LOCK_DURATION`: a `(bias, slope)` checkpoint plus the slope changes at
+//! which locks start decaying or mature.
+
+use crate::errors::SavingsError;
+use crate::governance;
+use crate::governance_events::emit_delegate_changed;
+use crate::lock;
+use crate::storage_types::LockSave;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Remaining lock time that earns a Lock Save its full amount as voting power
+pub const MAX_LOCK_DURATION: u64 = 4 * 365 * 24 * 60 * 60;
+
+/// Most points `get_voting_power_curve` returns in one call
+pub const MAX_CURVE_POINTS: u32 = 100;
+
+const DEFAULT_BALANCE_WEIGHT_BPS: u32 = 10_000;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingPowerKey {
+    /// Liquid balance checkpoints of the votes an address holds
+    BalanceCheckpoints(Address),
+    TotalBalanceCheckpoints,
+    OwnBalance(Address),
+    LockedPrincipal(Address),
+    /// Lock curve of the votes an address holds
+    LockCurve(Address),
+    LockCurveChanges(Address),
+    OwnLockCurve(Address),
+    OwnLockCurveChanges(Address),
+    TotalLockCurve,
+    TotalLockCurveChanges,
+    /// Marks a Lock Save whose power has been added to the curves
+    EscrowedLock(u64),
+    Delegate(Address),
+    Delegators(Address),
+    BalanceWeight,
+}
+
+/// Liquid balance held from `timestamp` until the next checkpoint.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingPowerCheckpoint {
+    pub timestamp: u64,
+    pub power: u128,
+}
+
+/// Lock power at `timestamp` (`bias`) and its rate of decay (`slope`), both
+/// scaled by `MAX_LOCK_DURATION`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockCurveCheckpoint {
+    pub timestamp: u64,
+    pub bias: i128,
+    pub slope: i128,
+}
+
+/// Change in a lock curve's slope at `timestamp`, when a lock starts decaying
+/// or matures.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlopeChange {
+    pub timestamp: u64,
+    pub delta: i128,
+}
+
+/// Current voting power of one Lock Save.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockVotingPower {
+    pub lock_id: u64,
+    pub amount: i128,
+    pub maturity_time: u64,
+    pub power: u128,
+}
+
+/// Where a user's voting power comes from.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingPowerBreakdown {
+    /// Liquid savings balance, before weighting
+    pub balance: u128,
+    /// Power from the liquid balance
+    pub balance_power: u128,
+    /// Power from active Lock Saves
+    pub lock_power: u128,
+    pub locks: Vec<LockVotingPower>,
+    /// Power delegated to the user by others
+    pub delegated_power: u128,
+    /// Who the user's own power is delegated to, if anyone
+    pub delegate: Option<Address>,
+    /// Power the user votes with: their own unless delegated, plus delegated power
+    pub total: u128,
+}
+
+/// Voting power at a point in time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingPowerPoint {
+    pub timestamp: u64,
+    pub power: u128,
+}
+
+// ========== Reading Power ==========
+
+/// Returns the voting power `user` votes with now
+pub fn get_voting_power(env: &Env, user: &Address) -> u128 {
+    get_voting_power_at(env, user, env.ledger().timestamp())
+}
+
+/// Returns the voting power `user` voted with at `timestamp`
+pub fn get_voting_power_at(env: &Env, user: &Address, timestamp: u64) -> u128 {
+    combine(
+        env,
+        checkpoint_at(
+            env,
+            &VotingPowerKey::BalanceCheckpoints(user.clone()),
+            timestamp,
+        ),
+        curve_value(
+            env,
+            &VotingPowerKey::LockCurve(user.clone()),
+            &VotingPowerKey::LockCurveChanges(user.clone()),
+            timestamp,
+        ),
+    )
+}
+
+/// Returns the total voting power held across all users
+pub fn get_total_voting_power(env: &Env) -> u128 {
+    get_total_voting_power_at(env, env.ledger().timestamp())
+}
+
+/// Returns the total voting power held across all users at `timestamp`
+pub fn get_total_voting_power_at(env: &Env, timestamp: u64) -> u128 {
+    combine(
+        env,
+        checkpoint_at(env, &VotingPowerKey::TotalBalanceCheckpoints, timestamp),
+        curve_value(
+            env,
+            &VotingPowerKey::TotalLockCurve,
+            &VotingPowerKey::TotalLockCurveChanges,
+            timestamp,
+        ),
+    )
+}
+
+/// Returns where `user`'s voting power comes from
+pub fn get_voting_power_breakdown(env: &Env, user: &Address) -> VotingPowerBreakdown {
+    let now = env.ledger().timestamp();
+    let balance = own_balance(env, user);
+
+    let mut locks = Vec::new(env);
+    for lock_id in lock::get_user_lock_saves(env, user).iter() {
+        if !env
+            .storage()
+            .persistent()
+            .has(&VotingPowerKey::EscrowedLock(lock_id))
+        {
+            continue;
+        }
+        if let Some(lock_save) = lock::get_lock_save(env, lock_id) {
+            locks.push_back(LockVotingPower {
+                lock_id,
+                amount: lock_save.amount,
+                maturity_time: lock_save.maturity_time,
+                power: lock_power_at(&lock_save, now),
+            });
+        }
+    }
+
+    VotingPowerBreakdown {
+        balance,
+        balance_power: combine(env, balance, 0),
+        lock_power: own_lock_power(env, user, now),
+        locks,
+        delegated_power: get_received_voting_power(env, user),
+        delegate: get_delegate(env, user),
+        total: get_voting_power(env, user),
+    }
+}
+
+/// Returns `user`'s voting power at `count` points spaced `step` seconds apart
+/// from `from`.
+///
+/// Points in the future project the decay of current locks, assuming no
+/// further deposits, withdrawals or delegation changes.
+///
+/// # Errors
+/// * `SavingsError::InvalidAmount` - If `step` is zero or `count` exceeds
+///   `MAX_CURVE_POINTS`
+pub fn get_voting_power_curve(
+    env: &Env,
+    user: &Address,
+    from: u64,
+    step: u64,
+    count: u32,
+) -> Result<Vec<VotingPowerPoint>, SavingsError> {
+    if step == 0 || count > MAX_CURVE_POINTS {
+        return Err(SavingsError::InvalidAmount);
+    }
+
+    let mut points = Vec::new(env);
+    let mut timestamp = from;
+    for _ in 0..count {
+        points.push_back(VotingPowerPoint {
+            timestamp,
+            power: get_voting_power_at(env, user, timestamp),
+        });
+        timestamp = match timestamp.checked_add(step) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(points)
+}
+
+/// Returns the weight of liquid balances in voting power, in basis points
+pub fn get_balance_weight_bps(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&VotingPowerKey::BalanceWeight)
+        .unwrap_or(DEFAULT_BALANCE_WEIGHT_BPS)
+}
+
+/// Sets the weight of liquid balances in voting power. Admin only until
+/// governance is active, then only through a `SetBalanceVoteWeight` proposal.
+pub fn set_balance_weight_bps(
+    env: &Env,
+    caller: Address,
+    weight_bps: u32,
+) -> Result<(), SavingsError> {
+    caller.require_auth();
+    governance::require_admin_before_governance(env, &caller)?;
+    set_balance_weight_bps_unchecked(env, weight_bps)
+}
+
+/// Sets the weight of liquid balances without an authorization check.
+///
+/// Zero makes voting power purely vote-escrowed.
+pub(crate) fn set_balance_weight_bps_unchecked(
+    env: &Env,
+    weight_bps: u32,
+) -> Result<(), SavingsError> {
+    if weight_bps > 10_000 {
+        return Err(SavingsError::InvalidAmount);
+    }
+    env.storage()
+        .persistent()
+        .set(&VotingPowerKey::BalanceWeight, &weight_bps);
+    Ok(())
+}
+
+// ========== Recording Power ==========
+
+/// Records a user's liquid balance after their `total_balance` changed.
+pub fn checkpoint_balance(env: &Env, user: &Address, total_balance: i128) {
+    let previous = own_balance(env, user);
+    let locked: i128 = env
+        .storage()
+        .persistent()
+        .get(&VotingPowerKey::LockedPrincipal(user.clone()))
+        .unwrap_or(0);
+    let balance = total_balance.saturating_sub(locked).max(0) as u128;
+    if balance == previous {
+        return;
+    }
+    env.storage()
+        .persistent()
+        .set(&VotingPowerKey::OwnBalance(user.clone()), &balance);
+
+    let holder = VotingPowerKey::BalanceCheckpoints(delegate_of(env, user));
+    let votes = latest_checkpoint(env, &holder)
+        .saturating_sub(previous)
+        .saturating_add(balance);
+    let total = latest_checkpoint(env, &VotingPowerKey::TotalBalanceCheckpoints)
+        .saturating_sub(previous)
+        .saturating_add(balance);
+    write_checkpoint(env, &holder, votes);
+    write_checkpoint(env, &VotingPowerKey::TotalBalanceCheckpoints, total);
+}
+
+/// Escrows a new Lock Save's power. Call before saving the owner's updated
+/// `total_balance`, so the principal is not also counted as liquid balance.
+pub fn add_lock(env: &Env, lock_save: &LockSave) {
+    let escrow_key = VotingPowerKey::EscrowedLock(lock_save.id);
+    if env.storage().persistent().has(&escrow_key) {
+        return;
+    }
+    env.storage().persistent().set(&escrow_key, &true);
+    adjust_locked_principal(env, &lock_save.owner, lock_save.amount);
+    apply_lock_curve(env, lock_save, 1);
+}
+
+/// Releases a Lock Save's power when it is withdrawn. Call before saving the
+/// owner's updated `total_balance`.
+///
+/// Locks created before vote escrow existed were never added and are ignored.
+pub fn remove_lock(env: &Env, lock_save: &LockSave) {
+    let escrow_key = VotingPowerKey::EscrowedLock(lock_save.id);
+    if !env.storage().persistent().has(&escrow_key) {
+        return;
+    }
+    env.storage().persistent().remove(&escrow_key);
+    adjust_locked_principal(env, &lock_save.owner, -lock_save.amount);
+    apply_lock_curve(env, lock_save, -1);
+}
+
+fn adjust_locked_principal(env: &Env, user: &Address, delta: i128) {
+    let key = VotingPowerKey::LockedPrincipal(user.clone());
+    let locked: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&key, &locked.saturating_add(delta).max(0));
+}
+
+/// Adds (`sign` = 1) or removes (`sign` = -1) the rest of a lock's curve, from
+/// now to maturity, for its owner, the holder of their votes and the total.
+fn apply_lock_curve(env: &Env, lock_save: &LockSave, sign: i128) {
+    let now = env.ledger().timestamp();
+    if now >= lock_save.maturity_time {
+        return;
+    }
+
+    let amount = lock_save.amount.max(0).saturating_mul(sign);
+    let remaining = lock_save.maturity_time - now;
+    let mut changes = Vec::new(env);
+    let (bias, slope) = if remaining <= MAX_LOCK_DURATION {
+        (amount.saturating_mul(remaining as i128), amount)
+    } else {
+        changes.push_back(SlopeChange {
+            timestamp: lock_save.maturity_time - MAX_LOCK_DURATION,
+            delta: amount,
+        });
+        (amount.saturating_mul(MAX_LOCK_DURATION as i128), 0)
+    };
+    changes.push_back(SlopeChange {
+        timestamp: lock_save.maturity_time,
+        delta: -amount,
+    });
+
+    let owner = &lock_save.owner;
+    adjust_curve(
+        env,
+        &VotingPowerKey::OwnLockCurve(owner.clone()),
+        &VotingPowerKey::OwnLockCurveChanges(owner.clone()),
+        bias,
+        slope,
+        &changes,
+    );
+    let holder = delegate_of(env, owner);
+    adjust_curve(
+        env,
+        &VotingPowerKey::LockCurve(holder.clone()),
+        &VotingPowerKey::LockCurveChanges(holder),
+        bias,
+        slope,
+        &changes,
+    );
+    adjust_curve(
+        env,
+        &VotingPowerKey::TotalLockCurve,
+        &VotingPowerKey::TotalLockCurveChanges,
+        bias,
+        slope,
+        &changes,
+    );
+}
+
+/// Current power of a single lock, unscaled
+fn lock_power_at(lock_save: &LockSave, timestamp: u64) -> u128 {
+    if lock_save.is_withdrawn || timestamp >= lock_save.maturity_time {
+        return 0;
+    }
+    let remaining = (lock_save.maturity_time - timestamp).min(MAX_LOCK_DURATION);
+    (lock_save.amount.max(0) as u128).saturating_mul(remaining as u128) / MAX_LOCK_DURATION as u128
+}
+
+// ========== Delegation ==========
+
+/// Returns who `user` has delegated their votes to, if anyone
+pub fn get_delegate(env: &Env, user: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&VotingPowerKey::Delegate(user.clone()))
+}
+
+/// Returns the users who have delegated their votes to `delegatee`
+pub fn get_delegators(env: &Env, delegatee: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&VotingPowerKey::Delegators(delegatee.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the voting power `delegatee` currently holds on behalf of others
+pub fn get_received_voting_power(env: &Env, delegatee: &Address) -> u128 {
+    let now = env.ledger().timestamp();
+    get_delegators(env, delegatee)
+        .iter()
+        .fold(0u128, |sum, delegator| {
+            sum.saturating_add(combine(
+                env,
+                own_balance(env, &delegator),
+                own_lock_curve(env, &delegator, now),
+            ))
+        })
+}
+
+/// Delegates `delegator`'s voting power to `delegatee`.
+///
+/// Both the liquid balance and the remaining lock curve move with a
+/// checkpoint, so proposals that opened earlier still count them where they
+/// were. Delegating to yourself is the same as `undelegate`. Delegations do
+/// not chain: power delegated to a user who has delegated stays with that
+/// user.
+pub fn delegate_votes(
+    env: &Env,
+    delegator: Address,
+    delegatee: Address,
+) -> Result<(), SavingsError> {
+    delegator.require_auth();
+
+    let current = delegate_of(env, &delegator);
+    if current == delegatee {
+        return Ok(());
+    }
+
+    let balance = own_balance(env, &delegator);
+    if balance > 0 {
+        let from = VotingPowerKey::BalanceCheckpoints(current.clone());
+        let to = VotingPowerKey::BalanceCheckpoints(delegatee.clone());
+        let from_votes = latest_checkpoint(env, &from).saturating_sub(balance);
+        let to_votes = latest_checkpoint(env, &to)
+            .checked_add(balance)
+            .ok_or(SavingsError::Overflow)?;
+        write_checkpoint(env, &from, from_votes);
+        write_checkpoint(env, &to, to_votes);
+    }
+    move_lock_curve(env, &delegator, &current, &delegatee);
+
+    if current != delegator {
+        let mut delegators = get_delegators(env, &current);
+        if let Some(index) = delegators.first_index_of(&delegator) {
+            delegators.remove(index);
+        }
+        env.storage()
+            .persistent()
+            .set(&VotingPowerKey::Delegators(current.clone()), &delegators);
+    }
+
+    let delegate_key = VotingPowerKey::Delegate(delegator.clone());
+    if delegatee == delegator {
+        env.storage().persistent().remove(&delegate_key);
+    } else {
+        env.storage().persistent().set(&delegate_key, &delegatee);
+        let mut delegators = get_delegators(env, &delegatee);
+        delegators.push_back(delegator.clone());
+        env.storage()
+            .persistent()
+            .set(&VotingPowerKey::Delegators(delegatee.clone()), &delegators);
+    }
+
+    emit_delegate_changed(env, delegator, current, delegatee);
+    Ok(())
+}
+
+/// Returns `delegator`'s voting power to them
+pub fn undelegate(env: &Env, delegator: Address) -> Result<(), SavingsError> {
+    delegate_votes(env, delegator.clone(), delegator)
+}
+
+/// Moves what remains of `user`'s own lock curve from one holder to another
+fn move_lock_curve(env: &Env, user: &Address, from: &Address, to: &Address) {
+    let now = env.ledger().timestamp();
+    let (bias, slope) = curve_state(
+        env,
+        &VotingPowerKey::OwnLockCurve(user.clone()),
+        &VotingPowerKey::OwnLockCurveChanges(user.clone()),
+        now,
+    );
+
+    let mut pending = Vec::new(env);
+    let mut reversed = Vec::new(env);
+    let changes: Vec<SlopeChange> = env
+        .storage()
+        .persistent()
+        .get(&VotingPowerKey::OwnLockCurveChanges(user.clone()))
+        .unwrap_or(Vec::new(env));
+    for change in changes.iter() {
+        if change.timestamp > now {
+            reversed.push_back(SlopeChange {
+                timestamp: change.timestamp,
+                delta: -change.delta,
+            });
+            pending.push_back(change);
+        }
+    }
+    if bias == 0 && slope == 0 && pending.is_empty() {
+        return;
+    }
+
+    adjust_curve(
+        env,
+        &VotingPowerKey::LockCurve(from.clone()),
+        &VotingPowerKey::LockCurveChanges(from.clone()),
+        -bias,
+        -slope,
+        &reversed,
+    );
+    adjust_curve(
+        env,
+        &VotingPowerKey::LockCurve(to.clone()),
+        &VotingPowerKey::LockCurveChanges(to.clone()),
+        bias,
+        slope,
+        &pending,
+    );
+}
+
+fn delegate_of(env: &Env, user: &Address) -> Address {
+    get_delegate(env, user).unwrap_or(user.clone())
+}
+
+/// A user's own liquid balance, before delegation
+fn own_balance(env: &Env, user: &Address) -> u128 {
+    env.storage()
+        .persistent()
+        .get(&VotingPowerKey::OwnBalance(user.clone()))
+        .unwrap_or(0)
+}
+
+/// A user's own lock power, before delegation
+fn own_lock_power(env: &Env, user: &Address, timestamp: u64) -> u128 {
+    combine(env, 0, own_lock_curve(env, user, timestamp))
+}
+
+fn own_lock_curve(env: &Env, user: &Address, timestamp: u64) -> i128 {
+    curve_value(
+        env,
+        &VotingPowerKey::OwnLockCurve(user.clone()),
+        &VotingPowerKey::OwnLockCurveChanges(user.clone()),
+        timestamp,
+    )
+}
+
+/// Weighted liquid balance plus unscaled lock power
+fn combine(env: &Env, balance: u128, scaled_lock_power: i128) -> u128 {
+    let balance_power = balance.saturating_mul(get_balance_weight_bps(env) as u128) / 10_000u128;
+    let lock_power = scaled_lock_power.max(0) as u128 / MAX_LOCK_DURATION as u128;
+    balance_power.saturating_add(lock_power)
+}
+
+// ========== Checkpoint Storage ==========
+
+fn read_checkpoints(env: &Env, key: &VotingPowerKey) -> Vec<VotingPowerCheckpoint> {
+    env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+}
+
+fn latest_checkpoint(env: &Env, key: &VotingPowerKey) -> u128 {
+    read_checkpoints(env, key)
+        .last()
+        .map(|c| c.power)
+        .unwrap_or(0)
+}
+
+/// Returns the power in the last checkpoint taken at or before `timestamp`.
+fn checkpoint_at(env: &Env, key: &VotingPowerKey, timestamp: u64) -> u128 {
+    let checkpoints = read_checkpoints(env, key);
+    let (mut low, mut high) = (0u32, checkpoints.len());
+    while low < high {
+        let mid = (low + high) / 2;
+        if checkpoints.get_unchecked(mid).timestamp <= timestamp {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == 0 {
+        0
+    } else {
+        checkpoints.get_unchecked(low - 1).power
+    }
+}
+
+/// Appends a checkpoint, replacing the last one if it has the same timestamp.
+fn write_checkpoint(env: &Env, key: &VotingPowerKey, power: u128) {
+    let timestamp = env.ledger().timestamp();
+    let mut checkpoints = read_checkpoints(env, key);
+    if let Some(last) = checkpoints.last() {
+        if last.timestamp == timestamp {
+            checkpoints.pop_back();
+        }
+    }
+    checkpoints.push_back(VotingPowerCheckpoint { timestamp, power });
+    env.storage().persistent().set(key, &checkpoints);
+}
+
+/// Returns a lock curve's scaled value at `timestamp`
+fn curve_value(
+    env: &Env,
+    checkpoints_key: &VotingPowerKey,
+    changes_key: &VotingPowerKey,
+    timestamp: u64,
+) -> i128 {
+    curve_state(env, checkpoints_key, changes_key, timestamp)
+        .0
+        .max(0)
+}
+
+/// Returns a lock curve's scaled value and slope at `timestamp`.
+///
+/// Starts from the last checkpoint at or before `timestamp` and applies the
+/// slope changes between the two. Changes are always added after the
+/// checkpoint they belong to, so later changes never fall in that window.
+fn curve_state(
+    env: &Env,
+    checkpoints_key: &VotingPowerKey,
+    changes_key: &VotingPowerKey,
+    timestamp: u64,
+) -> (i128, i128) {
+    let checkpoints: Vec<LockCurveCheckpoint> = env
+        .storage()
+        .persistent()
+        .get(checkpoints_key)
+        .unwrap_or(Vec::new(env));
+
+    let mut base = None;
+    for checkpoint in checkpoints.iter().rev() {
+        if checkpoint.timestamp <= timestamp {
+            base = Some(checkpoint);
+            break;
+        }
+    }
+    let Some(base) = base else {
+        return (0, 0);
+    };
+
+    let elapsed = (timestamp - base.timestamp) as i128;
+    let mut bias = base.bias.saturating_sub(base.slope.saturating_mul(elapsed));
+    let mut slope = base.slope;
+
+    let changes: Vec<SlopeChange> = env
+        .storage()
+        .persistent()
+        .get(changes_key)
+        .unwrap_or(Vec::new(env));
+    for change in changes.iter() {
+        if change.timestamp > base.timestamp && change.timestamp <= timestamp {
+            let since = (timestamp - change.timestamp) as i128;
+            bias = bias.saturating_sub(change.delta.saturating_mul(since));
+            slope = slope.saturating_add(change.delta);
+        }
+    }
+    (bias, slope)
+}
+
+/// Shifts a lock curve by `bias` and `slope` from now on and records future
+/// slope changes.
+fn adjust_curve(
+    env: &Env,
+    checkpoints_key: &VotingPowerKey,
+    changes_key: &VotingPowerKey,
+    bias: i128,
+    slope: i128,
+    new_changes: &Vec<SlopeChange>,
+) {
+    let now = env.ledger().timestamp();
+    let (current_bias, current_slope) = curve_state(env, checkpoints_key, changes_key, now);
+
+    let mut checkpoints: Vec<LockCurveCheckpoint> = env
+        .storage()
+        .persistent()
+        .get(checkpoints_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(last) = checkpoints.last() {
+        if last.timestamp == now {
+            checkpoints.pop_back();
+        }
+    }
+    checkpoints.push_back(LockCurveCheckpoint {
+        timestamp: now,
+        bias: current_bias.saturating_add(bias),
+        slope: current_slope.saturating_add(slope),
+    });
+    env.storage()
+        .persistent()
+        .set(checkpoints_key, &checkpoints);
+
+    if !new_changes.is_empty() {
+        let mut changes: Vec<SlopeChange> = env
+            .storage()
+            .persistent()
+            .get(changes_key)
+            .unwrap_or(Vec::new(env));
+        changes.append(new_changes);
+        env.storage().persistent().set(changes_key, &changes);
+    }
+}
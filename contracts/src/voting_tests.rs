@@ -41,6 +41,8 @@ mod voting_tests {
         client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
 
         let creator = Address::generate(&env);
+        client.initialize_user(&creator, &None);
+        let _ = client.create_savings_plan(&creator, &PlanType::Flexi, &1000);
         let description = String::from_str(&env, "Test proposal");
         let proposal_id = client
             .try_create_proposal(&creator, &description)
@@ -56,7 +58,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         let result = client.try_vote(&proposal_id, &1, &voter);
@@ -74,7 +76,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &2000);
 
         let result = client.try_vote(&proposal_id, &2, &voter);
@@ -92,7 +94,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1500);
 
         let result = client.try_vote(&proposal_id, &3, &voter);
@@ -113,9 +115,9 @@ mod voting_tests {
         let voter2 = Address::generate(&env);
         let voter3 = Address::generate(&env);
 
-        client.initialize_user(&voter1);
-        client.initialize_user(&voter2);
-        client.initialize_user(&voter3);
+        client.initialize_user(&voter1, &None);
+        client.initialize_user(&voter2, &None);
+        client.initialize_user(&voter3, &None);
 
         let _ = client.create_savings_plan(&voter1, &PlanType::Flexi, &1000);
         let _ = client.create_savings_plan(&voter2, &PlanType::Flexi, &2000);
@@ -137,7 +139,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         client.vote(&proposal_id, &1, &voter);
@@ -156,7 +158,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         assert!(!client.has_voted(&proposal_id, &voter));
@@ -172,7 +174,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
 
         let result = client.try_vote(&proposal_id, &1, &voter);
         assert!(result.is_err());
@@ -184,7 +186,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         let result = client.try_vote(&proposal_id, &0, &voter);
@@ -200,7 +202,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         env.ledger().with_mut(|li| {
@@ -217,7 +219,7 @@ mod voting_tests {
         let voter = Address::generate(&env);
         env.mock_all_auths();
 
-        client.initialize_user(&voter);
+        client.initialize_user(&voter, &None);
         let _ = client.create_savings_plan(&voter, &PlanType::Flexi, &1000);
 
         let result = client.try_vote(&999, &1, &voter);
@@ -232,8 +234,8 @@ mod voting_tests {
         let voter1 = Address::generate(&env);
         let voter2 = Address::generate(&env);
 
-        client.initialize_user(&voter1);
-        client.initialize_user(&voter2);
+        client.initialize_user(&voter1, &None);
+        client.initialize_user(&voter2, &None);
 
         let _ = client.create_savings_plan(&voter1, &PlanType::Flexi, &5000);
         let _ = client.create_savings_plan(&voter2, &PlanType::Flexi, &3000);
@@ -244,4 +246,258 @@ mod voting_tests {
         let proposal = client.get_proposal(&proposal_id).unwrap();
         assert_eq!(proposal.for_votes, 8000);
     }
+
+    fn saver(env: &Env, client: &NesteraContractClient, amount: i128) -> Address {
+        let user = Address::generate(env);
+        client.initialize_user(&user, &None);
+        client.deposit_flexi(&user, &amount);
+        user
+    }
+
+    #[test]
+    fn test_delegate_moves_voting_power() {
+        let (env, client, _admin) = setup_contract();
+        let alice = saver(&env, &client, 3000);
+        let bob = saver(&env, &client, 2000);
+
+        client.delegate_votes(&alice, &bob);
+
+        assert_eq!(client.get_voting_power(&alice), 0);
+        assert_eq!(client.get_voting_power(&bob), 5000);
+        assert_eq!(client.get_total_voting_power(), 5000);
+        assert_eq!(client.get_delegate(&alice), Some(bob.clone()));
+        assert_eq!(client.get_delegators(&bob).len(), 1);
+        assert_eq!(client.get_received_voting_power(&bob), 3000);
+    }
+
+    #[test]
+    fn test_undelegate_returns_voting_power() {
+        let (env, client, _admin) = setup_contract();
+        let alice = saver(&env, &client, 3000);
+        let bob = saver(&env, &client, 2000);
+
+        client.delegate_votes(&alice, &bob);
+        client.undelegate(&alice);
+
+        assert_eq!(client.get_voting_power(&alice), 3000);
+        assert_eq!(client.get_voting_power(&bob), 2000);
+        assert_eq!(client.get_delegate(&alice), None);
+        assert_eq!(client.get_delegators(&bob).len(), 0);
+        assert_eq!(client.get_received_voting_power(&bob), 0);
+    }
+
+    #[test]
+    fn test_redelegate_moves_power_between_delegates() {
+        let (env, client, _admin) = setup_contract();
+        let alice = saver(&env, &client, 3000);
+        let bob = saver(&env, &client, 2000);
+        let carol = saver(&env, &client, 1000);
+
+        client.delegate_votes(&alice, &bob);
+        client.delegate_votes(&alice, &carol);
+
+        assert_eq!(client.get_voting_power(&bob), 2000);
+        assert_eq!(client.get_voting_power(&carol), 4000);
+        assert_eq!(client.get_delegators(&bob).len(), 0);
+        assert_eq!(client.get_delegators(&carol).len(), 1);
+    }
+
+    #[test]
+    fn test_delegated_balance_changes_follow_delegate() {
+        let (env, client, _admin) = setup_contract();
+        let alice = saver(&env, &client, 3000);
+        let bob = saver(&env, &client, 2000);
+
+        client.delegate_votes(&alice, &bob);
+        client.deposit_flexi(&alice, &1000);
+        client.withdraw_flexi(&alice, &500);
+
+        assert_eq!(client.get_voting_power(&alice), 0);
+        assert_eq!(client.get_voting_power(&bob), 5500);
+        assert_eq!(client.get_total_voting_power(), 5500);
+    }
+
+    #[test]
+    fn test_delegation_after_proposal_start_is_not_double_counted() {
+        let (env, client, admin) = setup_contract();
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        let alice = saver(&env, &client, 3000);
+        let bob = saver(&env, &client, 2000);
+
+        let proposal_id = client.create_proposal(&bob, &String::from_str(&env, "Snapshot"));
+        env.ledger().with_mut(|li| li.timestamp += 10);
+        client.delegate_votes(&alice, &bob);
+
+        client.vote(&proposal_id, &1, &alice);
+        client.vote(&proposal_id, &1, &bob);
+        assert_eq!(client.get_proposal_votes(&proposal_id), (5000, 0, 0));
+    }
+
+    #[test]
+    fn test_delegated_weight_respects_max_voting_power() {
+        let (env, client, admin) = setup_contract();
+        client.init_voting_config(&admin, &5000, &604800, &86400, &100, &10_000);
+        let alice = saver(&env, &client, 8000);
+        let bob = saver(&env, &client, 7000);
+        client.delegate_votes(&alice, &bob);
+
+        env.ledger().with_mut(|li| li.timestamp += 10);
+        let proposal_id = client.create_proposal(&bob, &String::from_str(&env, "Capped"));
+        client.vote(&proposal_id, &1, &bob);
+
+        assert_eq!(client.get_voting_power(&bob), 15_000);
+        assert_eq!(client.get_proposal_votes(&proposal_id), (10_000, 0, 0));
+        assert!(client.try_vote(&proposal_id, &1, &alice).is_err());
+    }
+
+    const MAX_LOCK: u64 = crate::voting_power::MAX_LOCK_DURATION;
+
+    fn locker(env: &Env, client: &NesteraContractClient, amount: i128, duration: u64) -> Address {
+        let user = Address::generate(env);
+        client.initialize_user(&user, &None);
+        client.create_lock_save(&user, &amount, &duration);
+        user
+    }
+
+    #[test]
+    fn test_lock_power_decays_linearly_to_maturity() {
+        let (env, client, _admin) = setup_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = locker(&env, &client, 4000, MAX_LOCK);
+
+        assert_eq!(client.get_voting_power(&user), 4000);
+        assert_eq!(
+            client.get_voting_power_at(&user, &(1_000 + MAX_LOCK / 4)),
+            3000
+        );
+        assert_eq!(
+            client.get_voting_power_at(&user, &(1_000 + MAX_LOCK / 2)),
+            2000
+        );
+        assert_eq!(client.get_voting_power_at(&user, &(1_000 + MAX_LOCK)), 0);
+        assert_eq!(client.get_voting_power_at(&user, &999), 0);
+        assert_eq!(
+            client.get_total_voting_power_at(&(1_000 + MAX_LOCK / 2)),
+            2000
+        );
+    }
+
+    #[test]
+    fn test_lock_longer_than_max_keeps_full_weight() {
+        let (env, client, _admin) = setup_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = locker(&env, &client, 4000, 2 * MAX_LOCK);
+
+        assert_eq!(client.get_voting_power_at(&user, &(1_000 + MAX_LOCK)), 4000);
+        assert_eq!(
+            client.get_voting_power_at(&user, &(1_000 + MAX_LOCK + MAX_LOCK / 2)),
+            2000
+        );
+        assert_eq!(
+            client.get_voting_power_at(&user, &(1_000 + 2 * MAX_LOCK)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_voting_power_breakdown() {
+        let (env, client, _admin) = setup_contract();
+        let user = saver(&env, &client, 1000);
+        client.create_lock_save(&user, &4000, &(MAX_LOCK / 2));
+
+        let breakdown = client.get_voting_power_breakdown(&user);
+        assert_eq!(breakdown.balance, 1000);
+        assert_eq!(breakdown.balance_power, 1000);
+        assert_eq!(breakdown.lock_power, 2000);
+        assert_eq!(breakdown.locks.len(), 1);
+        assert_eq!(breakdown.locks.get(0).unwrap().power, 2000);
+        assert_eq!(breakdown.delegated_power, 0);
+        assert_eq!(breakdown.delegate, None);
+        assert_eq!(breakdown.total, 3000);
+    }
+
+    #[test]
+    fn test_balance_weight_zero_counts_only_locks() {
+        let (env, client, admin) = setup_contract();
+        let user = saver(&env, &client, 1000);
+        client.create_lock_save(&user, &4000, &MAX_LOCK);
+
+        client.set_balance_vote_weight(&admin, &0);
+        assert_eq!(client.get_balance_vote_weight(), 0);
+        assert_eq!(client.get_voting_power(&user), 4000);
+        assert_eq!(client.get_total_voting_power(), 4000);
+
+        let result = client.try_set_balance_vote_weight(&admin, &10_001);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_voting_power_curve_view() {
+        let (env, client, _admin) = setup_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = locker(&env, &client, 4000, MAX_LOCK);
+
+        let curve = client.get_voting_power_curve(&user, &1_000, &(MAX_LOCK / 4), &5);
+        assert_eq!(curve.len(), 5);
+        for (point, expected) in curve.iter().zip([4000u128, 3000, 2000, 1000, 0]) {
+            assert_eq!(point.power, expected);
+        }
+
+        assert!(client
+            .try_get_voting_power_curve(&user, &1_000, &0, &5)
+            .is_err());
+    }
+
+    #[test]
+    fn test_delegated_lock_power_decays_for_delegate() {
+        let (env, client, _admin) = setup_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let alice = locker(&env, &client, 4000, MAX_LOCK);
+        let bob = saver(&env, &client, 1000);
+
+        env.ledger()
+            .with_mut(|li| li.timestamp = 1_000 + MAX_LOCK / 4);
+        client.delegate_votes(&alice, &bob);
+
+        assert_eq!(client.get_voting_power(&alice), 0);
+        assert_eq!(client.get_voting_power(&bob), 4000);
+        assert_eq!(client.get_received_voting_power(&bob), 3000);
+        assert_eq!(
+            client.get_voting_power_at(&bob, &(1_000 + MAX_LOCK / 2)),
+            3000
+        );
+        // Before the delegation, the lock power stayed with Alice
+        assert_eq!(client.get_voting_power_at(&alice, &1_000), 4000);
+        assert_eq!(client.get_voting_power_at(&bob, &1_000), 1000);
+    }
+
+    #[test]
+    fn test_withdrawn_lock_principal_is_not_liquid_balance() {
+        let (env, client, _admin) = setup_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = saver(&env, &client, 1000);
+        let lock_id = client.create_lock_save(&user, &4000, &100);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_200);
+        client.withdraw_lock_save(&user, &lock_id);
+
+        let breakdown = client.get_voting_power_breakdown(&user);
+        assert_eq!(breakdown.balance, 1000);
+        assert_eq!(breakdown.lock_power, 0);
+        assert_eq!(client.get_total_voting_power(), 1000);
+    }
+
+    #[test]
+    fn test_vote_weight_uses_decayed_lock_power() {
+        let (env, client, admin) = setup_contract();
+        client.init_voting_config(&admin, &0, &604800, &86400, &100, &10_000);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        let user = locker(&env, &client, 4000, MAX_LOCK);
+
+        env.ledger()
+            .with_mut(|li| li.timestamp = 1_000 + MAX_LOCK / 2);
+        let proposal_id = client.create_proposal(&user, &String::from_str(&env, "ve"));
+        client.vote(&proposal_id, &1, &user);
+        assert_eq!(client.get_proposal_votes(&proposal_id), (2000, 0, 0));
+    }
 }
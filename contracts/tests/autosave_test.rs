@@ -1,7 +1,14 @@
 #[cfg(test)]
 mod autosave_tests {
-    use soroban_sdk::{testutils::Address as _, Address, Env};
-    use Nestera::{NesteraContract, NesteraContractClient};
+    use soroban_sdk::{
+        symbol_short,
+        testutils::{Address as _, Ledger},
+        Address, BytesN, Env, String,
+    };
+    use Nestera::{
+        AutoSaveCursor, AutoSaveTarget, CatchUpPolicy, DataKey, KeeperConfig, KeeperFeeSource,
+        NesteraContract, NesteraContractClient, SavingsError,
+    };
 
     fn setup_test_contract() -> (Env, NesteraContractClient<'static>, Address) {
         let env = Env::default();
@@ -13,7 +20,7 @@ mod autosave_tests {
         let user = Address::generate(&env);
 
         // Initialize user
-        client.initialize_user(&user);
+        client.initialize_user(&user, &None);
 
         (env, client, user)
     }
@@ -132,7 +139,7 @@ mod autosave_tests {
         let schedule_id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
 
         let other_user = Address::generate(&env);
-        client.initialize_user(&other_user);
+        client.initialize_user(&other_user, &None);
 
         // Try to cancel someone else's schedule
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -331,7 +338,7 @@ mod autosave_tests {
         let (env, client, user1) = setup_test_contract();
 
         let user2 = Address::generate(&env);
-        client.initialize_user(&user2);
+        client.initialize_user(&user2, &None);
 
         let start_time = env.ledger().timestamp();
 
@@ -350,4 +357,658 @@ mod autosave_tests {
         assert_eq!(client.get_flexi_balance(&user1), 500);
         assert_eq!(client.get_flexi_balance(&user2), 800);
     }
+
+    // ========== Target Tests ==========
+
+    #[test]
+    fn test_autosave_into_goal() {
+        let (env, client, user) = setup_test_contract();
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &0);
+        let start_time = env.ledger().timestamp();
+        let id = client.create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::Goal(goal_id),
+            &1000,
+            &86400,
+            &start_time,
+        );
+
+        client.execute_autosave(&id);
+
+        assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 1000);
+        assert_eq!(client.get_flexi_balance(&user), 0);
+        assert!(client.get_autosave(&id).unwrap().is_active);
+    }
+
+    #[test]
+    fn test_autosave_into_group() {
+        let (env, client, user) = setup_test_contract();
+
+        let group_id = client.create_group_save(
+            &user,
+            &String::from_str(&env, "Ajo"),
+            &String::from_str(&env, "Monthly ajo"),
+            &String::from_str(&env, "family"),
+            &10_000,
+            &0,
+            &500,
+            &true,
+            &0,
+            &1_000_000,
+        );
+        let start_time = env.ledger().timestamp();
+        let id = client.create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::Group(group_id),
+            &500,
+            &86400,
+            &start_time,
+        );
+
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id]);
+        assert!(results.get(0).unwrap());
+        let plan = client.get_savings_plan(&user, &group_id).unwrap();
+        assert_eq!(plan.balance, 500);
+    }
+
+    #[test]
+    fn test_autosave_opens_new_lock_each_run() {
+        let (env, client, user) = setup_test_contract();
+
+        let start_time = env.ledger().timestamp();
+        let id = client.create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::NewLock(30 * 86400),
+            &2000,
+            &86400,
+            &start_time,
+        );
+
+        client.execute_autosave(&id);
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        client.execute_autosave(&id);
+
+        assert_eq!(client.get_user_lock_saves(&user).len(), 2);
+    }
+
+    #[test]
+    fn test_autosave_deactivates_when_goal_completed() {
+        let (env, client, user) = setup_test_contract();
+
+        let goal_id = client.create_goal_save(&user, &symbol_short!("phone"), &1500, &0);
+        let start_time = env.ledger().timestamp();
+        let id = client.create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::Goal(goal_id),
+            &1000,
+            &86400,
+            &start_time,
+        );
+
+        // Second run completes the goal
+        client.execute_autosave(&id);
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        client.execute_autosave(&id);
+        assert!(client.get_goal_save_detail(&goal_id).is_completed);
+        assert!(client.get_autosave(&id).unwrap().is_active);
+
+        // Third run finds the goal completed and stops the schedule
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id]);
+        assert!(!results.get(0).unwrap());
+        assert!(!client.get_autosave(&id).unwrap().is_active);
+        assert_eq!(client.get_goal_save_detail(&goal_id).current_amount, 2000);
+    }
+
+    #[test]
+    fn test_autosave_target_validation() {
+        let (env, client, user) = setup_test_contract();
+        let start_time = env.ledger().timestamp();
+
+        // Missing goal
+        let result = client.try_create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::Goal(42),
+            &1000,
+            &86400,
+            &start_time,
+        );
+        assert_eq!(result, Err(Ok(SavingsError::PlanNotFound)));
+
+        // Someone else's goal
+        let other = Address::generate(&env);
+        client.initialize_user(&other, &None);
+        let goal_id = client.create_goal_save(&other, &symbol_short!("trip"), &10_000, &0);
+        let result = client.try_create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::Goal(goal_id),
+            &1000,
+            &86400,
+            &start_time,
+        );
+        assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+
+        // Zero lock duration
+        let result = client.try_create_autosave_with_target(
+            &user,
+            &AutoSaveTarget::NewLock(0),
+            &1000,
+            &86400,
+            &start_time,
+        );
+        assert_eq!(result, Err(Ok(SavingsError::InvalidTimestamp)));
+    }
+
+    // ========== Keeper Bounty Tests ==========
+
+    fn setup_keeper(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        fee: i128,
+        source: KeeperFeeSource,
+    ) -> Address {
+        setup_keeper_config(
+            env,
+            client,
+            &KeeperConfig {
+                fee,
+                source,
+                min_interval_seconds: 3600,
+                min_amount: 0,
+            },
+        )
+    }
+
+    fn setup_keeper_config(
+        env: &Env,
+        client: &NesteraContractClient<'static>,
+        config: &KeeperConfig,
+    ) -> Address {
+        let admin = Address::generate(env);
+        client.initialize(&admin, &BytesN::from_array(env, &[0u8; 32]));
+        client.set_keeper_config(&admin, config);
+
+        let keeper = Address::generate(env);
+        client.initialize_user(&keeper, &None);
+        keeper
+    }
+
+    fn fund_pool(env: &Env, client: &NesteraContractClient<'static>, amount: i128) {
+        let sponsor = Address::generate(env);
+        client.initialize_user(&sponsor, &None);
+        client.deposit_flexi(&sponsor, &amount);
+        client.fund_keeper_pool(&sponsor, &amount);
+    }
+
+    #[test]
+    fn test_keeper_bounty_from_user_deposit() {
+        let (env, client, user) = setup_test_contract();
+        let keeper = setup_keeper(&env, &client, 10, KeeperFeeSource::UserDeposit);
+
+        let id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        let results = client.keeper_execute_autosaves(&keeper, &soroban_sdk::vec![&env, id]);
+        assert!(results.get(0).unwrap());
+
+        assert_eq!(client.get_flexi_balance(&user), 990);
+        assert_eq!(client.get_flexi_balance(&keeper), 10);
+
+        let stats = client.get_keeper_stats(&keeper);
+        assert_eq!(stats.executions, 1);
+        assert_eq!(stats.total_earned, 10);
+        assert_eq!(client.get_autosave(&id).unwrap().last_keeper, Some(keeper));
+    }
+
+    #[test]
+    fn test_keeper_bounty_from_protocol_pool() {
+        let (env, client, user) = setup_test_contract();
+        let keeper = setup_keeper(&env, &client, 25, KeeperFeeSource::ProtocolPool);
+
+        let sponsor = Address::generate(&env);
+        client.initialize_user(&sponsor, &None);
+        client.deposit_flexi(&sponsor, &100);
+        client.fund_keeper_pool(&sponsor, &30);
+        assert_eq!(client.get_keeper_pool_balance(), 30);
+
+        let start_time = env.ledger().timestamp();
+        let id1 = client.create_autosave(&user, &1000, &86400, &start_time);
+        let id2 = client.create_autosave(&user, &1000, &86400, &start_time);
+        let results = client.keeper_execute_autosaves(&keeper, &soroban_sdk::vec![&env, id1, id2]);
+        assert!(results.get(0).unwrap());
+        assert!(results.get(1).unwrap());
+
+        // User deposits are untouched; the pool only covers one bounty
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+        assert_eq!(client.get_flexi_balance(&keeper), 25);
+        assert_eq!(client.get_keeper_pool_balance(), 5);
+
+        let stats = client.get_keeper_stats(&keeper);
+        assert_eq!(stats.executions, 2);
+        assert_eq!(stats.total_earned, 25);
+    }
+
+    #[test]
+    fn test_keeper_not_paid_for_schedules_not_due() {
+        let (env, client, user) = setup_test_contract();
+        let keeper = setup_keeper(&env, &client, 10, KeeperFeeSource::UserDeposit);
+
+        let future_time = env.ledger().timestamp() + 100_000;
+        let id = client.create_autosave(&user, &1000, &86400, &future_time);
+        let results = client.keeper_execute_autosaves(&keeper, &soroban_sdk::vec![&env, id]);
+        assert!(!results.get(0).unwrap());
+
+        assert_eq!(client.get_flexi_balance(&keeper), 0);
+        assert_eq!(client.get_keeper_stats(&keeper).executions, 0);
+    }
+
+    #[test]
+    fn test_keeper_not_paid_for_own_or_high_frequency_schedules() {
+        let (env, client, user) = setup_test_contract();
+        let keeper = setup_keeper(&env, &client, 10, KeeperFeeSource::UserDeposit);
+        let start_time = env.ledger().timestamp();
+
+        // Keeper executing its own schedule earns nothing
+        let own = client.create_autosave(&keeper, &1000, &86400, &start_time);
+        // Interval below min_interval_seconds earns nothing
+        let fast = client.create_autosave(&user, &1000, &60, &start_time);
+
+        let results = client.keeper_execute_autosaves(&keeper, &soroban_sdk::vec![&env, own, fast]);
+        assert!(results.get(0).unwrap());
+        assert!(results.get(1).unwrap());
+
+        assert_eq!(client.get_flexi_balance(&keeper), 1000);
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+        let stats = client.get_keeper_stats(&keeper);
+        assert_eq!(stats.executions, 2);
+        assert_eq!(stats.total_earned, 0);
+    }
+
+    #[test]
+    fn test_keeper_not_paid_for_small_schedules() {
+        let (env, client, user) = setup_test_contract();
+        let keeper = setup_keeper_config(
+            &env,
+            &client,
+            &KeeperConfig {
+                fee: 10,
+                source: KeeperFeeSource::ProtocolPool,
+                min_interval_seconds: 0,
+                min_amount: 500,
+            },
+        );
+        fund_pool(&env, &client, 100);
+        let start_time = env.ledger().timestamp();
+
+        // Many tiny schedules cannot drain the pool
+        let tiny = client.create_autosave(&user, &499, &86400, &start_time);
+        let large = client.create_autosave(&user, &500, &86400, &start_time);
+        let results =
+            client.keeper_execute_autosaves(&keeper, &soroban_sdk::vec![&env, tiny, large]);
+        assert_eq!(results, soroban_sdk::vec![&env, true, true]);
+
+        assert_eq!(client.get_flexi_balance(&keeper), 10);
+        assert_eq!(client.get_keeper_pool_balance(), 90);
+    }
+
+    // ========== Lifecycle Tests ==========
+
+    #[test]
+    fn test_autosave_ends_after_max_runs() {
+        let (env, client, user) = setup_test_contract();
+
+        let id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        client.set_autosave_end(&user, &id, &2, &0);
+
+        client.execute_autosave(&id);
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        client.execute_autosave(&id);
+
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.runs_completed, 2);
+        assert!(!schedule.is_active);
+
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        assert!(client.try_execute_autosave(&id).is_err());
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+    }
+
+    #[test]
+    fn test_autosave_ends_after_end_time() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+        client.set_autosave_end(&user, &id, &0, &(start + 86400));
+
+        client.execute_autosave(&id);
+        assert!(client.get_autosave(&id).unwrap().is_active);
+
+        env.ledger().with_mut(|li| li.timestamp += 86400);
+        client.execute_autosave(&id);
+        assert!(!client.get_autosave(&id).unwrap().is_active);
+    }
+
+    #[test]
+    fn test_pause_and_resume_skips_missed_intervals() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+        client.pause_autosave(&user, &id);
+
+        assert!(client.try_execute_autosave(&id).is_err());
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id]);
+        assert!(!results.get(0).unwrap());
+
+        // Resume 3.5 days later: the schedule keeps its ID and fires once at the next slot
+        env.ledger()
+            .with_mut(|li| li.timestamp = start + 3 * 86400 + 43200);
+        client.resume_autosave(&user, &id);
+
+        let schedule = client.get_autosave(&id).unwrap();
+        assert!(!schedule.is_paused);
+        assert_eq!(schedule.next_execution_time, start + 4 * 86400);
+        assert!(client.try_execute_autosave(&id).is_err());
+
+        env.ledger().with_mut(|li| li.timestamp = start + 4 * 86400);
+        client.execute_autosave(&id);
+        assert!(client.try_execute_autosave(&id).is_err());
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_amend_autosave() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+        client.amend_autosave(&user, &id, &2500, &3600);
+
+        client.execute_autosave(&id);
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.amount, 2500);
+        assert_eq!(schedule.next_execution_time, start + 3600);
+        assert_eq!(client.get_flexi_balance(&user), 2500);
+
+        assert_eq!(
+            client.try_amend_autosave(&user, &id, &0, &3600),
+            Err(Ok(SavingsError::InvalidAmount))
+        );
+
+        let other = Address::generate(&env);
+        client.initialize_user(&other, &None);
+        assert_eq!(
+            client.try_amend_autosave(&other, &id, &100, &3600),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+
+    // ========== Catch-up and Failure Tests ==========
+
+    #[test]
+    fn test_late_schedule_skips_missed_slots_by_default() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+
+        // Executed five and a half intervals late: runs once, then waits for the next slot
+        env.ledger()
+            .with_mut(|li| li.timestamp = start + 5 * 86400 + 43200);
+        client.execute_autosave(&id);
+        assert!(client.try_execute_autosave(&id).is_err());
+
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.next_execution_time, start + 6 * 86400);
+        assert_eq!(schedule.runs_completed, 1);
+        assert_eq!(client.get_flexi_balance(&user), 1000);
+    }
+
+    #[test]
+    fn test_late_schedule_makes_up_at_most_k_runs() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+        client.set_autosave_catch_up(&user, &id, &CatchUpPolicy::ExecuteMissed(2));
+
+        env.ledger().with_mut(|li| li.timestamp = start + 5 * 86400);
+        let results = client.execute_due_autosaves(&soroban_sdk::vec![&env, id]);
+        assert!(results.get(0).unwrap());
+
+        // Current slot plus two missed runs
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.runs_completed, 3);
+        assert_eq!(schedule.next_execution_time, start + 6 * 86400);
+        assert_eq!(client.get_flexi_balance(&user), 3000);
+    }
+
+    #[test]
+    fn test_catch_up_respects_max_runs() {
+        let (env, client, user) = setup_test_contract();
+
+        let start = env.ledger().timestamp();
+        let id = client.create_autosave(&user, &1000, &86400, &start);
+        client.set_autosave_catch_up(&user, &id, &CatchUpPolicy::ExecuteMissed(10));
+        client.set_autosave_end(&user, &id, &2, &0);
+
+        env.ledger().with_mut(|li| li.timestamp = start + 5 * 86400);
+        client.execute_autosave(&id);
+
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.runs_completed, 2);
+        assert!(!schedule.is_active);
+        assert_eq!(client.get_flexi_balance(&user), 2000);
+    }
+
+    #[test]
+    fn test_consecutive_failures_deactivate_schedule() {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        assert_eq!(client.get_autosave_failure_threshold(), 3);
+
+        let id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        let ids = soroban_sdk::vec![&env, id];
+
+        // Deposits fail while the protocol is paused
+        client.pause(&admin);
+        client.execute_due_autosaves(&ids);
+        client.execute_due_autosaves(&ids);
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.consecutive_failures, 2);
+        assert!(schedule.is_active);
+
+        client.execute_due_autosaves(&ids);
+        let schedule = client.get_autosave(&id).unwrap();
+        assert_eq!(schedule.consecutive_failures, 3);
+        assert!(!schedule.is_active);
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let (env, client, user) = setup_test_contract();
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+        client.set_autosave_failure_threshold(&admin, &5);
+
+        let id = client.create_autosave(&user, &1000, &86400, &env.ledger().timestamp());
+        let ids = soroban_sdk::vec![&env, id];
+
+        client.pause(&admin);
+        client.execute_due_autosaves(&ids);
+        client.unpause(&admin);
+        assert_eq!(client.get_autosave(&id).unwrap().consecutive_failures, 1);
+
+        let results = client.execute_due_autosaves(&ids);
+        assert!(results.get(0).unwrap());
+        assert_eq!(client.get_autosave(&id).unwrap().consecutive_failures, 0);
+    }
+
+    // ========== Due Index Tests ==========
+
+    #[test]
+    fn test_get_due_autosaves_lists_only_due_schedules() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let now = env.ledger().timestamp();
+
+        let id1 = client.create_autosave(&user, &100, &86400, &(now - 7200));
+        let id2 = client.create_autosave(&user, &100, &86400, &now);
+        let _later = client.create_autosave(&user, &100, &86400, &(now + 60));
+        let _far = client.create_autosave(&user, &100, &86400, &(now + 86400));
+
+        let page = client.get_due_autosaves(&now, &AutoSaveCursor::default(), &10);
+        assert_eq!(page.schedule_ids, soroban_sdk::vec![&env, id1, id2]);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_get_due_autosaves_paginates() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let now = env.ledger().timestamp();
+
+        let mut expected = soroban_sdk::Vec::new(&env);
+        for i in 0..5u64 {
+            expected.push_back(client.create_autosave(&user, &100, &86400, &(now - i * 3600)));
+        }
+
+        let mut seen = soroban_sdk::Vec::new(&env);
+        let mut cursor = AutoSaveCursor::default();
+        loop {
+            let page = client.get_due_autosaves(&now, &cursor, &2);
+            assert!(page.schedule_ids.len() <= 2);
+            seen.append(&page.schedule_ids);
+            if !page.has_more {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        assert_eq!(seen.len(), 5);
+        for id in expected.iter() {
+            assert!(seen.contains(id));
+        }
+    }
+
+    #[test]
+    fn test_get_due_autosaves_zero_limit_is_final() {
+        let (env, client, user) = setup_test_contract();
+        let now = env.ledger().timestamp();
+        client.create_autosave(&user, &100, &86400, &now);
+
+        let page = client.get_due_autosaves(&now, &AutoSaveCursor::default(), &0);
+        assert_eq!(page.schedule_ids.len(), 0);
+        assert!(!page.has_more);
+    }
+
+    #[test]
+    fn test_get_due_autosaves_skips_empty_buckets() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 10_000_000);
+        let now = env.ledger().timestamp();
+
+        // Hundreds of empty hours lie between the schedules
+        let old = client.create_autosave(&user, &100, &86400, &(now - 500 * 3600));
+        let recent = client.create_autosave(&user, &100, &86400, &now);
+        let later = client.create_autosave(&user, &100, &86400, &(now + 300 * 3600));
+
+        let page = client.get_due_autosaves(&now, &AutoSaveCursor::default(), &10);
+        assert_eq!(page.schedule_ids, soroban_sdk::vec![&env, old, recent]);
+        assert!(!page.has_more);
+
+        // A page boundary resumes at the next non-empty bucket
+        let first = client.get_due_autosaves(&now, &AutoSaveCursor::default(), &1);
+        assert_eq!(first.schedule_ids, soroban_sdk::vec![&env, old]);
+        assert!(first.has_more);
+        let second = client.get_due_autosaves(&now, &first.next_cursor, &1);
+        assert_eq!(second.schedule_ids, soroban_sdk::vec![&env, recent]);
+
+        // Executed schedules leave their emptied buckets out of the index
+        client.execute_due_autosaves(&soroban_sdk::vec![&env, old]);
+        let page = client.get_due_autosaves(&now, &AutoSaveCursor::default(), &10);
+        assert_eq!(page.schedule_ids, soroban_sdk::vec![&env, recent]);
+        let far = now + 400 * 3600;
+        let page = client.get_due_autosaves(&far, &AutoSaveCursor::default(), &10);
+        assert_eq!(
+            page.schedule_ids,
+            soroban_sdk::vec![&env, recent, old, later]
+        );
+    }
+
+    #[test]
+    fn test_due_index_tracks_execution_pause_and_cancel() {
+        let (env, client, user) = setup_test_contract();
+        env.ledger().with_mut(|li| li.timestamp = 1_000_000);
+        let now = env.ledger().timestamp();
+
+        let id1 = client.create_autosave(&user, &100, &86400, &now);
+        let id2 = client.create_autosave(&user, &100, &86400, &now);
+        let id3 = client.create_autosave(&user, &100, &86400, &now);
+
+        client.execute_autosave(&id1);
+        client.pause_autosave(&user, &id2);
+        client.cancel_autosave(&user, &id3);
+        assert_eq!(
+            client
+                .get_due_autosaves(&now, &AutoSaveCursor::default(), &10)
+                .schedule_ids
+                .len(),
+            0
+        );
+
+        // Executed schedule reappears at its next slot, resumed schedule at its recomputed slot
+        let next_day = now + 86400;
+        env.ledger().with_mut(|li| li.timestamp = next_day);
+        client.resume_autosave(&user, &id2);
+        let page = client.get_due_autosaves(&next_day, &AutoSaveCursor::default(), &10);
+        assert_eq!(page.schedule_ids, soroban_sdk::vec![&env, id1, id2]);
+    }
+
+    /// `AutoSave` as stored before targets, keepers and run limits were added
+    #[soroban_sdk::contracttype]
+    struct LegacyAutoSave {
+        id: u64,
+        user: Address,
+        amount: i128,
+        interval_seconds: u64,
+        next_execution_time: u64,
+        is_active: bool,
+    }
+
+    #[test]
+    fn test_legacy_schedule_still_reads_and_runs() {
+        let (env, client, user) = setup_test_contract();
+        let now = env.ledger().timestamp();
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(
+                &DataKey::AutoSave(1),
+                &LegacyAutoSave {
+                    id: 1,
+                    user: user.clone(),
+                    amount: 500,
+                    interval_seconds: 86400,
+                    next_execution_time: now,
+                    is_active: true,
+                },
+            );
+        });
+
+        let schedule = client.get_autosave(&1).unwrap();
+        assert_eq!(schedule.user, user);
+        assert_eq!(schedule.target, AutoSaveTarget::Flexi);
+        assert_eq!(schedule.catch_up, CatchUpPolicy::SkipMissed);
+        assert!(schedule.is_active && !schedule.is_paused);
+
+        client.execute_autosave(&1);
+        assert_eq!(client.get_flexi_balance(&user), 500);
+        let schedule = client.get_autosave(&1).unwrap();
+        assert_eq!(schedule.runs_completed, 1);
+        assert_eq!(schedule.next_execution_time, now + 86400);
+
+        // Saving it in the current layout also adds it to the due index
+        let page = client.get_due_autosaves(&(now + 86400), &AutoSaveCursor::default(), &10);
+        assert_eq!(page.schedule_ids, soroban_sdk::vec![&env, 1]);
+    }
 }
@@ -0,0 +1,291 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use p256::ecdsa::Signature;
+use soroban_sdk::{testutils::Address as _, Address, Bytes, BytesN, Env, String, Vec};
+use Nestera::{
+    Ballot, GovernanceError, NesteraContract, NesteraContractClient, PlanType, SignedBallot,
+    SignerKey, SignerProof, WebAuthnAssertion, WebAuthnRelyingParty,
+};
+
+fn setup() -> (Env, NesteraContractClient<'static>, u64) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &BytesN::from_array(&env, &[1u8; 32]));
+    client.init_voting_config(&admin, &1000, &604800, &86400, &100, &1_000_000);
+    client.set_webauthn_relying_party(
+        &admin,
+        &WebAuthnRelyingParty {
+            rp_id_hash: env
+                .crypto()
+                .sha256(&Bytes::from_slice(&env, b"nestera.app"))
+                .into(),
+            origin: String::from_str(&env, "https://nestera.app"),
+        },
+    );
+
+    let creator = saver(&env, &client, 1000);
+    let proposal_id = client.create_proposal(&creator, &String::from_str(&env, "Gasless"));
+    (env, client, proposal_id)
+}
+
+fn saver(env: &Env, client: &NesteraContractClient, amount: i128) -> Address {
+    let user = Address::generate(env);
+    client.initialize_user(&user, &None);
+    client.create_savings_plan(&user, &PlanType::Flexi, &amount);
+    user
+}
+
+fn message_bytes(message: &Bytes) -> std::vec::Vec<u8> {
+    let mut buf = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    buf
+}
+
+fn base64url(bytes: &[u8]) -> std::string::String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = std::string::String::new();
+    for chunk in bytes.chunks(3) {
+        let mut block = [0u8; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let n = (block[0] as u32) << 16 | (block[1] as u32) << 8 | block[2] as u32;
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// A passkey's WebAuthn assertion with the SHA-256 of `message` as the challenge
+fn webauthn_assertion(
+    env: &Env,
+    key: &p256::ecdsa::SigningKey,
+    message: &Bytes,
+) -> WebAuthnAssertion {
+    let challenge = env.crypto().sha256(message).to_array();
+    let client_data_json = std::format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://nestera.app"}}"#,
+        base64url(&challenge)
+    );
+    let client_data_json = Bytes::from_slice(env, client_data_json.as_bytes());
+    let mut authenticator_data = env
+        .crypto()
+        .sha256(&Bytes::from_slice(env, b"nestera.app"))
+        .to_array()
+        .to_vec();
+    authenticator_data.extend_from_slice(&[0x05, 0, 0, 0, 9]);
+
+    let mut signed = authenticator_data.clone();
+    signed.extend_from_slice(&env.crypto().sha256(&client_data_json).to_array());
+    let signature: Signature = p256::ecdsa::signature::Signer::sign(key, &signed);
+    WebAuthnAssertion {
+        authenticator_data: Bytes::from_slice(env, &authenticator_data),
+        client_data_json,
+        signature: BytesN::from_array(env, &signature.to_bytes().into()),
+    }
+}
+
+/// A saver who has registered `key` as their ballot key
+fn ed25519_voter(env: &Env, client: &NesteraContractClient, key: &SigningKey) -> Address {
+    let voter = saver(env, client, 2000);
+    let public_key = BytesN::from_array(env, &key.verifying_key().to_bytes());
+    client.set_ballot_key(&voter, &Some(SignerKey::Ed25519(public_key)));
+    voter
+}
+
+fn ballot(voter: &Address, proposal_id: u64, vote_type: u32, nonce: u64) -> Ballot {
+    Ballot {
+        voter: voter.clone(),
+        proposal_id,
+        vote_type,
+        nonce,
+    }
+}
+
+fn sign(
+    env: &Env,
+    client: &NesteraContractClient,
+    key: &SigningKey,
+    ballot: Ballot,
+) -> SignedBallot {
+    let message = message_bytes(&client.get_ballot_signing_message(&ballot));
+    SignedBallot {
+        ballot,
+        signature: SignerProof::Ed25519(BytesN::from_array(env, &key.sign(&message).to_bytes())),
+    }
+}
+
+#[test]
+fn test_relayed_ballots_are_counted() {
+    let (env, client, proposal_id) = setup();
+    let alice_key = SigningKey::from_bytes(&[11u8; 32]);
+    let alice = ed25519_voter(&env, &client, &alice_key);
+
+    let bob_key = p256::ecdsa::SigningKey::from_bytes(&[0x42u8; 32].into()).unwrap();
+    let bob = saver(&env, &client, 500);
+    let point = bob_key.verifying_key().to_encoded_point(false);
+    client.set_ballot_key(
+        &bob,
+        &Some(SignerKey::Secp256r1(BytesN::from_array(
+            &env,
+            point.as_bytes().try_into().unwrap(),
+        ))),
+    );
+    let bob_ballot = ballot(&bob, proposal_id, 2, 1);
+    let message = client.get_ballot_signing_message(&bob_ballot);
+
+    let ballots = Vec::from_array(
+        &env,
+        [
+            sign(&env, &client, &alice_key, ballot(&alice, proposal_id, 1, 1)),
+            SignedBallot {
+                ballot: bob_ballot,
+                signature: SignerProof::WebAuthn(webauthn_assertion(&env, &bob_key, &message)),
+            },
+        ],
+    );
+    client.submit_ballots(&ballots);
+
+    assert!(client.has_voted(&proposal_id, &alice));
+    assert!(client.has_voted(&proposal_id, &bob));
+    assert!(client.is_ballot_nonce_used(&alice, &1));
+    assert_eq!(client.get_proposal_votes(&proposal_id), (2000, 500, 0));
+}
+
+#[test]
+fn test_ballot_cannot_be_replayed() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+    let ballots = Vec::from_array(
+        &env,
+        [sign(&env, &client, &key, ballot(&voter, proposal_id, 1, 7))],
+    );
+
+    client.submit_ballots(&ballots);
+    assert_eq!(
+        client.try_submit_ballots(&ballots),
+        Err(Ok(GovernanceError::BallotNonceUsed))
+    );
+
+    let fresh_nonce = Vec::from_array(
+        &env,
+        [sign(&env, &client, &key, ballot(&voter, proposal_id, 1, 8))],
+    );
+    assert_eq!(
+        client.try_submit_ballots(&fresh_nonce),
+        Err(Ok(GovernanceError::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_tampered_ballot_is_rejected() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+
+    let mut signed = sign(&env, &client, &key, ballot(&voter, proposal_id, 1, 1));
+    signed.ballot.vote_type = 2;
+    assert!(client
+        .try_submit_ballots(&Vec::from_array(&env, [signed]))
+        .is_err());
+
+    let other_key = SigningKey::from_bytes(&[12u8; 32]);
+    let forged = sign(&env, &client, &other_key, ballot(&voter, proposal_id, 1, 1));
+    assert!(client
+        .try_submit_ballots(&Vec::from_array(&env, [forged]))
+        .is_err());
+    assert!(!client.has_voted(&proposal_id, &voter));
+}
+
+#[test]
+fn test_proof_must_match_ballot_key_type() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+    let passkey = p256::ecdsa::SigningKey::from_bytes(&[0x42u8; 32].into()).unwrap();
+
+    let ballot = ballot(&voter, proposal_id, 1, 1);
+    let message = client.get_ballot_signing_message(&ballot);
+    let signed = SignedBallot {
+        ballot,
+        signature: SignerProof::WebAuthn(webauthn_assertion(&env, &passkey, &message)),
+    };
+    assert_eq!(
+        client.try_submit_ballots(&Vec::from_array(&env, [signed])),
+        Err(Ok(GovernanceError::InvalidBallotSignature))
+    );
+    assert!(!client.has_voted(&proposal_id, &voter));
+}
+
+#[test]
+fn test_ballot_is_bound_to_contract() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+
+    let other_id = env.register(NesteraContract, ());
+    let other = NesteraContractClient::new(&env, &other_id);
+    let ballot = ballot(&voter, proposal_id, 1, 1);
+    assert_ne!(
+        other.get_ballot_signing_message(&ballot),
+        client.get_ballot_signing_message(&ballot)
+    );
+
+    let signed_elsewhere = sign(&env, &other, &key, ballot);
+    assert!(client
+        .try_submit_ballots(&Vec::from_array(&env, [signed_elsewhere]))
+        .is_err());
+}
+
+#[test]
+fn test_failing_ballot_rejects_whole_batch() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+    let unregistered = saver(&env, &client, 2000);
+
+    let ballots = Vec::from_array(
+        &env,
+        [
+            sign(&env, &client, &key, ballot(&voter, proposal_id, 1, 1)),
+            sign(
+                &env,
+                &client,
+                &key,
+                ballot(&unregistered, proposal_id, 1, 1),
+            ),
+        ],
+    );
+    assert_eq!(
+        client.try_submit_ballots(&ballots),
+        Err(Ok(GovernanceError::NoBallotKey))
+    );
+    assert!(!client.has_voted(&proposal_id, &voter));
+    assert!(!client.is_ballot_nonce_used(&voter, &1));
+}
+
+#[test]
+fn test_ballot_batch_size_is_limited() {
+    let (env, client, proposal_id) = setup();
+    let key = SigningKey::from_bytes(&[11u8; 32]);
+    let voter = ed25519_voter(&env, &client, &key);
+
+    let mut ballots = Vec::new(&env);
+    for nonce in 0..21 {
+        ballots.push_back(sign(
+            &env,
+            &client,
+            &key,
+            ballot(&voter, proposal_id, 1, nonce),
+        ));
+    }
+    assert_eq!(
+        client.try_submit_ballots(&ballots),
+        Err(Ok(GovernanceError::TooManyBallots))
+    );
+}
@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, IntoVal,
+};
+use Nestera::{rewards::PointsExpired, NesteraContract, NesteraContractClient, SavingsError};
+
+const DAY: u64 = 86400;
+
+fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[0u8; 32]);
+    client.initialize(&admin, &admin_pk);
+
+    let user = Address::generate(&env);
+    client.init_user(&user);
+
+    client.init_rewards_config(
+        &admin, &10,        // points_per_token
+        &0,         // streak_bonus_bps
+        &0,         // long_lock_bonus_bps
+        &0,         // goal_completion_bonus
+        &true,      // enabled
+        &0,         // min_deposit_for_rewards
+        &0,         // action_cooldown_seconds
+        &1_000_000, // max_daily_points
+        &0,         // max_streak_multiplier
+    );
+
+    (env, client, admin, user)
+}
+
+fn set_day(env: &Env, day: u64) {
+    env.ledger().with_mut(|li| li.timestamp = day * DAY);
+}
+
+#[test]
+fn test_set_points_expiry_requires_admin() {
+    let (env, client, _admin, _user) = create_test_env();
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_points_expiry_days(&stranger, &30),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_points_never_expire_by_default() {
+    let (env, client, _admin, user) = create_test_env();
+    client.deposit_flexi(&user, &100);
+
+    set_day(&env, 1_000);
+    assert_eq!(client.expire_points(&user), 0);
+    assert_eq!(client.get_user_rewards(&user).total_points, 1_000);
+}
+
+#[test]
+fn test_points_expire_after_configured_days() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+
+    client.deposit_flexi(&user, &100); // 1,000 points on day 0
+    set_day(&env, 29);
+    assert_eq!(client.expire_points(&user), 0);
+
+    set_day(&env, 30);
+    assert_eq!(client.expire_points(&user), 1_000);
+
+    let events = env.events().all();
+    let expired: std::vec::Vec<_> = events
+        .iter()
+        .filter(|e| {
+            e.0 == client.address
+                && e.1
+                    == (
+                        symbol_short!("rewards"),
+                        symbol_short!("expired"),
+                        user.clone(),
+                    )
+                        .into_val(&env)
+        })
+        .collect();
+    assert_eq!(expired.len(), 1);
+    let event: PointsExpired = expired[0].2.clone().into_val(&env);
+    assert_eq!(event.amount, 1_000);
+
+    assert_eq!(client.get_user_rewards(&user).total_points, 0);
+    assert_eq!(client.get_point_lots(&user).len(), 0);
+    assert_eq!(client.get_user_rank(&user), 0);
+}
+
+#[test]
+fn test_deposit_expires_lapsed_points_first() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+
+    client.deposit_flexi(&user, &100); // 1,000 points on day 0
+    set_day(&env, 45);
+    client.deposit_flexi(&user, &50); // 500 points on day 45
+
+    assert_eq!(client.get_user_rewards(&user).total_points, 500);
+    assert_eq!(client.get_point_lots(&user).len(), 1);
+}
+
+#[test]
+fn test_redeem_spends_oldest_points_first() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+
+    client.deposit_flexi(&user, &100); // 1,000 points on day 0
+    set_day(&env, 10);
+    client.deposit_flexi(&user, &200); // 2,000 points on day 10
+
+    client.redeem_points(&user, &1_500);
+    let lots = client.get_point_lots(&user);
+    assert_eq!(lots.len(), 1);
+    assert_eq!(lots.get(0).unwrap().earned_day, 10);
+    assert_eq!(lots.get(0).unwrap().amount, 1_500);
+
+    // The day-0 lot was fully spent, so nothing lapses on day 30
+    set_day(&env, 30);
+    assert_eq!(client.expire_points(&user), 0);
+
+    set_day(&env, 40);
+    assert_eq!(client.expire_points(&user), 1_500);
+    assert_eq!(client.get_user_rewards(&user).total_points, 0);
+}
+
+#[test]
+fn test_redeem_rejects_expired_points() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+
+    client.deposit_flexi(&user, &100);
+    set_day(&env, 31);
+
+    assert_eq!(
+        client.try_redeem_points(&user, &500),
+        Err(Ok(SavingsError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_points_from_before_expiry_are_spent_first_and_kept() {
+    let (env, client, admin, user) = create_test_env();
+    client.deposit_flexi(&user, &100); // 1,000 untracked points
+
+    client.set_points_expiry_days(&admin, &30);
+    client.deposit_flexi(&user, &50); // 500 tracked points
+
+    client.redeem_points(&user, &600);
+    assert_eq!(client.get_point_lots(&user).get(0).unwrap().amount, 500);
+
+    set_day(&env, 30);
+    assert_eq!(client.expire_points(&user), 500);
+    assert_eq!(client.get_user_rewards(&user).total_points, 400);
+}
+
+#[test]
+fn test_get_expiring_points_view() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+
+    client.deposit_flexi(&user, &100); // 1,000 points on day 0
+    set_day(&env, 20);
+    client.deposit_flexi(&user, &200); // 2,000 points on day 20
+
+    assert_eq!(client.get_expiring_points(&user, &(5 * DAY)), 0);
+    assert_eq!(client.get_expiring_points(&user, &(10 * DAY)), 1_000);
+    assert_eq!(client.get_expiring_points(&user, &(30 * DAY)), 3_000);
+}
+
+#[test]
+fn test_expiry_is_fixed_when_points_are_earned() {
+    let (env, client, admin, user) = create_test_env();
+    client.set_points_expiry_days(&admin, &30);
+    client.deposit_flexi(&user, &100); // 1,000 points on day 0, lapsing on day 30
+    assert_eq!(client.get_point_lots(&user).get(0).unwrap().expires_day, 30);
+
+    // Disabling expiry does not rescue points already earned
+    set_day(&env, 5);
+    client.set_points_expiry_days(&admin, &0);
+    client.deposit_flexi(&user, &50); // 500 untracked points
+    assert_eq!(client.get_expiring_points(&user, &(25 * DAY)), 1_000);
+
+    set_day(&env, 30);
+    assert_eq!(client.expire_points(&user), 1_000);
+    assert_eq!(client.get_user_rewards(&user).total_points, 500);
+
+    // Re-enabling it does not reach back to points earned while it was off
+    client.set_points_expiry_days(&admin, &10);
+    client.deposit_flexi(&user, &20); // 200 points on day 30, lapsing on day 40
+    set_day(&env, 100);
+    assert_eq!(client.expire_points(&user), 200);
+    assert_eq!(client.get_user_rewards(&user).total_points, 500);
+}
@@ -41,7 +41,7 @@ fn test_user_lifecycle() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
     // Initialize user
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Query user data
     let user_data = client.get_user(&user1);
@@ -58,10 +58,10 @@ fn test_duplicate_user_initialization_fails() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
     // First initialization should succeed
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Second initialization should fail (panic)
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 }
 
 #[test]
@@ -82,7 +82,7 @@ fn test_flexi_plan_full_flow() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
     // Initialize user
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Deposit funds
     let deposit_amount = 1000i128;
@@ -110,7 +110,7 @@ fn test_flexi_plan_full_flow() {
 fn test_flexi_withdraw_insufficient_balance() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &500);
 
     // Try to withdraw more than balance - should panic
@@ -122,7 +122,7 @@ fn test_flexi_withdraw_insufficient_balance() {
 fn test_flexi_invalid_zero_deposit() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Zero deposit - should panic
     client.deposit_flexi(&user1, &0);
@@ -133,7 +133,7 @@ fn test_flexi_invalid_zero_deposit() {
 fn test_flexi_invalid_negative_deposit() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Negative deposit - should panic
     client.deposit_flexi(&user1, &-100);
@@ -147,7 +147,7 @@ fn test_flexi_invalid_negative_deposit() {
 fn test_goal_plan_full_flow() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     let goal_name = Symbol::new(&env, "vacation");
@@ -195,7 +195,7 @@ fn test_goal_early_withdrawal_with_penalty() {
     let treasury = Address::generate(&env);
     client.set_fee_recipient(&treasury);
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     let goal_name = Symbol::new(&env, "house");
@@ -223,7 +223,7 @@ fn test_goal_early_withdrawal_with_penalty() {
 fn test_lock_save_full_flow() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     let lock_amount = 5000i128;
@@ -255,7 +255,7 @@ fn test_lock_save_full_flow() {
 fn test_lock_save_early_withdrawal_fails() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &5000);
 
     let lock_id = client.create_lock_save(&user1, &3000, &60);
@@ -268,7 +268,7 @@ fn test_lock_save_early_withdrawal_fails() {
 fn test_multiple_lock_saves() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &20000);
 
     // Create multiple locks
@@ -290,9 +290,9 @@ fn test_group_save_full_flow() {
     let (env, client, _admin, user1, user2, user3) = setup_env();
 
     // Initialize all users
-    client.initialize_user(&user1);
-    client.initialize_user(&user2);
-    client.initialize_user(&user3);
+    client.initialize_user(&user1, &None);
+    client.initialize_user(&user2, &None);
+    client.initialize_user(&user3, &None);
 
     // Deposit initial funds for all users
     client.deposit_flexi(&user1, &10000);
@@ -335,8 +335,8 @@ fn test_group_save_full_flow() {
 fn test_group_save_non_member_cannot_contribute() {
     let (env, client, _admin, user1, user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
-    client.initialize_user(&user2);
+    client.initialize_user(&user1, &None);
+    client.initialize_user(&user2, &None);
     client.deposit_flexi(&user1, &10000);
     client.deposit_flexi(&user2, &10000);
 
@@ -365,7 +365,7 @@ fn test_group_save_non_member_cannot_contribute() {
 fn test_autosave_full_flow() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &50000);
 
     let amount = 1000i128;
@@ -401,7 +401,7 @@ fn test_autosave_full_flow() {
 fn test_autosave_early_execution_fails() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     let start_time = env.ledger().timestamp() + 1000;
@@ -415,7 +415,7 @@ fn test_autosave_early_execution_fails() {
 fn test_autosave_batch_execution() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &100000);
 
     let current_time = env.ledger().timestamp();
@@ -443,7 +443,7 @@ fn test_autosave_batch_execution() {
 fn test_autosave_cancel() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     let schedule_id = client.create_autosave(&user1, &1000, &3600, &env.ledger().timestamp());
@@ -492,7 +492,7 @@ fn test_fee_configuration_invalid() {
 fn test_pause_and_unpause() {
     let (_env, client, admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     // Pause contract
@@ -512,7 +512,7 @@ fn test_pause_and_unpause() {
 fn test_operations_paused() {
     let (_env, client, admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     // Pause contract
@@ -559,7 +559,7 @@ fn test_interest_rate_configuration() {
 fn test_invalid_zero_amount() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Zero amount - should panic
     client.deposit_flexi(&user1, &0);
@@ -570,7 +570,7 @@ fn test_invalid_zero_amount() {
 fn test_invalid_negative_amount() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
 
     // Negative amount - should panic
     client.deposit_flexi(&user1, &-500);
@@ -581,7 +581,7 @@ fn test_invalid_negative_amount() {
 fn test_insufficient_balance_withdrawal() {
     let (_env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &1000);
 
     // Try to withdraw more than balance - should panic
@@ -602,7 +602,7 @@ fn test_operations_on_non_existent_user() {
 fn test_autosave_invalid_interval() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &10000);
 
     // Create autosave with zero interval - should panic
@@ -618,9 +618,9 @@ fn test_multi_user_multi_plan_scenario() {
     let (env, client, _admin, user1, user2, user3) = setup_env();
 
     // Initialize all users
-    client.initialize_user(&user1);
-    client.initialize_user(&user2);
-    client.initialize_user(&user3);
+    client.initialize_user(&user1, &None);
+    client.initialize_user(&user2, &None);
+    client.initialize_user(&user3, &None);
 
     // Each user deposits
     client.deposit_flexi(&user1, &20000);
@@ -673,7 +673,7 @@ fn test_complete_user_journey() {
     let (env, client, _admin, user1, _user2, _user3) = setup_env();
 
     // Step 1: Initialize and deposit
-    client.initialize_user(&user1);
+    client.initialize_user(&user1, &None);
     client.deposit_flexi(&user1, &50000);
     assert_eq!(client.get_flexi_balance(&user1), 50000);
 
@@ -0,0 +1,552 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    xdr::ToXdr,
+    Address, BytesN, Env, Vec,
+};
+use Nestera::{
+    MintConfig, MintMessage, MintPayload, MintSignature, MintSignerSet, MintTarget,
+    NesteraContract, NesteraContractClient, SavingsError, SignerKey, SignerProof,
+};
+
+fn setup() -> (Env, NesteraContractClient<'static>, SigningKey) {
+    let (env, client, key, _admin) = setup_with_admin();
+    (env, client, key)
+}
+
+fn setup_with_admin() -> (Env, NesteraContractClient<'static>, SigningKey, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+    client.initialize(&admin, &admin_pk);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    (env, client, signing_key, admin)
+}
+
+fn new_user(env: &Env, client: &NesteraContractClient) -> Address {
+    let user = Address::generate(env);
+    client.init_user(&user);
+    user
+}
+
+fn payload(user: &Address, amount: i128, nonce: u64) -> MintPayload {
+    MintPayload {
+        user: user.clone(),
+        amount,
+        timestamp: 1_000,
+        expiry_duration: 3_600,
+        nonce,
+    }
+}
+
+fn sign_with(
+    env: &Env,
+    client: &NesteraContractClient,
+    keys: &[&SigningKey],
+    payload: &MintPayload,
+) -> Vec<MintSignature> {
+    let message = client.get_mint_message(payload);
+    let mut buf = [0u8; 512];
+    let len = message.len() as usize;
+    message.copy_into_slice(&mut buf[..len]);
+
+    let mut signatures = Vec::new(env);
+    for key in keys {
+        signatures.push_back(MintSignature {
+            public_key: SignerKey::Ed25519(BytesN::from_array(
+                env,
+                &key.verifying_key().to_bytes(),
+            )),
+            signature: SignerProof::Ed25519(BytesN::from_array(
+                env,
+                &key.sign(&buf[..len]).to_bytes(),
+            )),
+        });
+    }
+    signatures
+}
+
+fn sign(
+    env: &Env,
+    client: &NesteraContractClient,
+    key: &SigningKey,
+    payload: &MintPayload,
+) -> Vec<MintSignature> {
+    sign_with(env, client, &[key], payload)
+}
+
+#[test]
+fn test_mint_with_valid_signature() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+    let signature = sign(&env, &client, &key, &payload);
+
+    assert!(!client.is_mint_nonce_used(&user, &1));
+    assert_eq!(client.mint(&payload, &signature), 500);
+    assert!(client.is_mint_nonce_used(&user, &1));
+}
+
+#[test]
+fn test_mint_rejects_replayed_payload() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+    let signature = sign(&env, &client, &key, &payload);
+
+    client.mint(&payload, &signature);
+    let replay = client.try_mint(&payload, &signature);
+    assert_eq!(
+        replay.unwrap_err().unwrap(),
+        soroban_sdk::Error::from_contract_error(5)
+    );
+}
+
+#[test]
+fn test_mint_accepts_new_nonce_for_same_user() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+
+    let first = payload(&user, 500, 1);
+    client.mint(&first, &sign(&env, &client, &key, &first));
+
+    // Nonces need not be sequential
+    let second = payload(&user, 500, 42);
+    assert_eq!(
+        client.mint(&second, &sign(&env, &client, &key, &second)),
+        500
+    );
+    assert!(!client.is_mint_nonce_used(&user, &2));
+}
+
+#[test]
+fn test_signature_is_bound_to_contract() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+    let signature = sign(&env, &client, &key, &payload);
+
+    // The same admin key signs for a second deployment
+    let other_id = env.register(NesteraContract, ());
+    let other = NesteraContractClient::new(&env, &other_id);
+    let admin = Address::generate(&env);
+    other.initialize(
+        &admin,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+    );
+
+    assert!(other.try_mint(&payload, &signature).is_err());
+    assert!(!other.is_mint_nonce_used(&user, &1));
+}
+
+#[test]
+fn test_signature_over_bare_payload_is_rejected() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+
+    // Old format: signature over the payload alone, without domain binding
+    let bare = payload.clone().to_xdr(&env);
+    let mut buf = [0u8; 512];
+    let len = bare.len() as usize;
+    bare.copy_into_slice(&mut buf[..len]);
+    let signature = Vec::from_array(
+        &env,
+        [MintSignature {
+            public_key: public_key(&env, &key),
+            signature: SignerProof::Ed25519(BytesN::from_array(
+                &env,
+                &key.sign(&buf[..len]).to_bytes(),
+            )),
+        }],
+    );
+
+    assert!(client.try_mint(&payload, &signature).is_err());
+}
+
+#[test]
+fn test_mint_message_binds_network() {
+    let (env, client, _key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+
+    let expected = MintMessage {
+        contract: client.address.clone(),
+        network_id: env.ledger().network_id(),
+        payload: payload.clone(),
+    }
+    .to_xdr(&env);
+    assert_eq!(client.get_mint_message(&payload), expected);
+
+    env.ledger().set_network_id([9u8; 32]);
+    assert_ne!(client.get_mint_message(&payload), expected);
+}
+
+#[test]
+fn test_mint_rejects_expired_payload() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+    let signature = sign(&env, &client, &key, &payload);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 3_601);
+    assert_eq!(
+        client.try_mint(&payload, &signature).unwrap_err().unwrap(),
+        soroban_sdk::Error::from_contract_error(4)
+    );
+    assert!(!client.is_mint_nonce_used(&user, &1));
+}
+
+#[test]
+fn test_mint_credits_flexi_balance_by_default() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 500, 1);
+
+    client.mint(&payload, &sign(&env, &client, &key, &payload));
+    assert_eq!(client.get_flexi_balance(&user), 500);
+    assert_eq!(client.get_user(&user).total_balance, 500);
+    assert_eq!(client.get_user_minted(&user), 500);
+    assert_eq!(client.get_total_minted(), 500);
+}
+
+#[test]
+fn test_mint_issues_reward_token() {
+    let (env, client, key, admin) = setup_with_admin();
+    let token_admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(&env, &token).set_admin(&client.address);
+    client.set_mint_config(
+        &admin,
+        &MintConfig {
+            target: MintTarget::Token(token.clone()),
+            per_user_cap: 0,
+            global_cap: 0,
+        },
+    );
+
+    // Token payouts do not require a registered user
+    let user = Address::generate(&env);
+    let payload = payload(&user, 750, 1);
+    client.mint(&payload, &sign(&env, &client, &key, &payload));
+
+    assert_eq!(TokenClient::new(&env, &token).balance(&user), 750);
+    assert_eq!(client.get_total_minted(), 750);
+}
+
+#[test]
+fn test_mint_enforces_per_user_cap() {
+    let (env, client, key, admin) = setup_with_admin();
+    client.set_mint_config(
+        &admin,
+        &MintConfig {
+            target: MintTarget::Flexi,
+            per_user_cap: 1_000,
+            global_cap: 0,
+        },
+    );
+    let user = new_user(&env, &client);
+
+    let first = payload(&user, 600, 1);
+    client.mint(&first, &sign(&env, &client, &key, &first));
+    let second = payload(&user, 500, 2);
+    assert_eq!(
+        client
+            .try_mint(&second, &sign(&env, &client, &key, &second))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(7)
+    );
+    assert!(!client.is_mint_nonce_used(&user, &2));
+
+    let third = payload(&user, 400, 3);
+    client.mint(&third, &sign(&env, &client, &key, &third));
+    assert_eq!(client.get_user_minted(&user), 1_000);
+}
+
+#[test]
+fn test_mint_config_closed_to_direct_calls_once_governance_is_active() {
+    let (env, client, _key, admin) = setup_with_admin();
+    client.activate_governance(&admin);
+    let attacker = Address::generate(&env);
+    let config = MintConfig {
+        target: MintTarget::Token(Address::generate(&env)),
+        per_user_cap: 0,
+        global_cap: 0,
+    };
+
+    for caller in [&attacker, &admin] {
+        assert_eq!(
+            client.try_set_mint_config(caller, &config),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+    assert_eq!(client.get_mint_config().target, MintTarget::Flexi);
+}
+
+#[test]
+fn test_mint_enforces_global_cap() {
+    let (env, client, key, admin) = setup_with_admin();
+    client.set_mint_config(
+        &admin,
+        &MintConfig {
+            target: MintTarget::Flexi,
+            per_user_cap: 0,
+            global_cap: 1_000,
+        },
+    );
+    let alice = new_user(&env, &client);
+    let bob = new_user(&env, &client);
+
+    let first = payload(&alice, 800, 1);
+    client.mint(&first, &sign(&env, &client, &key, &first));
+    let second = payload(&bob, 300, 1);
+    assert_eq!(
+        client
+            .try_mint(&second, &sign(&env, &client, &key, &second))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(8)
+    );
+    assert_eq!(client.get_total_minted(), 800);
+}
+
+#[test]
+fn test_mint_rejects_non_positive_amount() {
+    let (env, client, key) = setup();
+    let user = new_user(&env, &client);
+    let payload = payload(&user, 0, 1);
+
+    assert_eq!(
+        client
+            .try_mint(&payload, &sign(&env, &client, &key, &payload))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(6)
+    );
+}
+
+#[test]
+fn test_set_mint_config_requires_admin() {
+    let (env, client, _key) = setup();
+    let stranger = Address::generate(&env);
+    let config = MintConfig {
+        target: MintTarget::Flexi,
+        per_user_cap: 0,
+        global_cap: 0,
+    };
+
+    assert_eq!(
+        client.try_set_mint_config(&stranger, &config),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
+
+fn public_key(env: &Env, key: &SigningKey) -> SignerKey {
+    SignerKey::Ed25519(BytesN::from_array(env, &key.verifying_key().to_bytes()))
+}
+
+#[test]
+fn test_rotated_key_keeps_old_key_valid_during_grace_period() {
+    let (env, client, old_key, admin) = setup_with_admin();
+    let new_key = SigningKey::from_bytes(&[8u8; 32]);
+    let user = new_user(&env, &client);
+
+    client.rotate_admin_public_key(&admin, &public_key(&env, &new_key), &600);
+    assert_eq!(
+        client.get_admin_public_key(),
+        Some(public_key(&env, &new_key))
+    );
+    assert_eq!(
+        client.get_retired_admin_public_key().unwrap().valid_until,
+        1_600
+    );
+
+    let old_signed = payload(&user, 100, 1);
+    client.mint(&old_signed, &sign(&env, &client, &old_key, &old_signed));
+    let new_signed = payload(&user, 100, 2);
+    client.mint(&new_signed, &sign(&env, &client, &new_key, &new_signed));
+
+    // Once the grace period ends only the new key is accepted
+    env.ledger().with_mut(|li| li.timestamp = 1_601);
+    let late = payload(&user, 100, 3);
+    assert_eq!(
+        client
+            .try_mint(&late, &sign(&env, &client, &old_key, &late))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(9)
+    );
+    client.mint(&late, &sign(&env, &client, &new_key, &late));
+}
+
+#[test]
+fn test_rotate_admin_key_requires_admin() {
+    let (env, client, _key) = setup();
+    let stranger = Address::generate(&env);
+    let new_key = SigningKey::from_bytes(&[8u8; 32]);
+
+    assert_eq!(
+        client.try_rotate_admin_public_key(&stranger, &public_key(&env, &new_key), &600),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_mint_keys_closed_to_direct_calls_once_governance_is_active() {
+    let (env, client, _key, admin) = setup_with_admin();
+    client.activate_governance(&admin);
+    let attacker = Address::generate(&env);
+    let attacker_key = public_key(&env, &SigningKey::from_bytes(&[8u8; 32]));
+    let signer_set = MintSignerSet {
+        signers: Vec::from_array(&env, [attacker_key.clone()]),
+        threshold: 1,
+        large_amount: 1,
+    };
+
+    for caller in [&attacker, &admin] {
+        assert_eq!(
+            client.try_rotate_admin_public_key(caller, &attacker_key, &0),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_mint_signers(caller, &signer_set),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_clear_mint_signers(caller),
+            Err(Ok(SavingsError::Unauthorized))
+        );
+    }
+    assert_ne!(client.get_admin_public_key(), Some(attacker_key));
+    assert_eq!(client.get_mint_signers(), None);
+}
+
+fn setup_signers() -> (
+    Env,
+    NesteraContractClient<'static>,
+    SigningKey,
+    [SigningKey; 3],
+) {
+    let (env, client, admin_key, admin) = setup_with_admin();
+    let signers = [
+        SigningKey::from_bytes(&[11u8; 32]),
+        SigningKey::from_bytes(&[12u8; 32]),
+        SigningKey::from_bytes(&[13u8; 32]),
+    ];
+    client.set_mint_signers(
+        &admin,
+        &MintSignerSet {
+            signers: Vec::from_array(
+                &env,
+                [
+                    public_key(&env, &signers[0]),
+                    public_key(&env, &signers[1]),
+                    public_key(&env, &signers[2]),
+                ],
+            ),
+            threshold: 2,
+            large_amount: 10_000,
+        },
+    );
+    (env, client, admin_key, signers)
+}
+
+#[test]
+fn test_large_mint_requires_threshold_signers() {
+    let (env, client, admin_key, signers) = setup_signers();
+    let user = new_user(&env, &client);
+
+    // Small mints still use the admin key alone
+    let small = payload(&user, 9_999, 1);
+    client.mint(&small, &sign(&env, &client, &admin_key, &small));
+
+    let large = payload(&user, 10_000, 2);
+    assert_eq!(
+        client
+            .try_mint(&large, &sign(&env, &client, &admin_key, &large))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(9)
+    );
+    assert_eq!(
+        client
+            .try_mint(&large, &sign(&env, &client, &signers[0], &large))
+            .unwrap_err()
+            .unwrap(),
+        soroban_sdk::Error::from_contract_error(10)
+    );
+
+    let signatures = sign_with(&env, &client, &[&signers[0], &signers[2]], &large);
+    assert!(client.verify_signature(&large, &signatures));
+    assert_eq!(client.mint(&large, &signatures), 10_000);
+}
+
+#[test]
+fn test_large_mint_counts_each_signer_once() {
+    let (env, client, _admin_key, signers) = setup_signers();
+    let user = new_user(&env, &client);
+    let large = payload(&user, 20_000, 1);
+
+    let signatures = sign_with(&env, &client, &[&signers[1], &signers[1]], &large);
+    assert_eq!(
+        client.try_mint(&large, &signatures).unwrap_err().unwrap(),
+        soroban_sdk::Error::from_contract_error(10)
+    );
+}
+
+#[test]
+fn test_set_mint_signers_validates_threshold() {
+    let (env, client, _key, admin) = setup_with_admin();
+    let signer = SigningKey::from_bytes(&[11u8; 32]);
+    let signers = Vec::from_array(&env, [public_key(&env, &signer)]);
+
+    assert_eq!(
+        client.try_set_mint_signers(
+            &admin,
+            &MintSignerSet {
+                signers: signers.clone(),
+                threshold: 2,
+                large_amount: 10_000,
+            }
+        ),
+        Err(Ok(SavingsError::InvalidSignerSet))
+    );
+    assert_eq!(
+        client.try_set_mint_signers(
+            &admin,
+            &MintSignerSet {
+                signers: Vec::from_array(
+                    &env,
+                    [public_key(&env, &signer), public_key(&env, &signer)]
+                ),
+                threshold: 1,
+                large_amount: 10_000,
+            }
+        ),
+        Err(Ok(SavingsError::InvalidSignerSet))
+    );
+
+    client.set_mint_signers(
+        &admin,
+        &MintSignerSet {
+            signers,
+            threshold: 1,
+            large_amount: 10_000,
+        },
+    );
+    client.clear_mint_signers(&admin);
+    assert_eq!(client.get_mint_signers(), None);
+}
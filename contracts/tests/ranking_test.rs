@@ -1,6 +1,12 @@
 #![cfg(test)]
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
-use Nestera::{NesteraContract, NesteraContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env, Vec,
+};
+use Nestera::{
+    rewards::ranking::{LEADERBOARD_RESERVE_SIZE, LEADERBOARD_SIZE},
+    NesteraContract, NesteraContractClient, SavingsError,
+};
 
 fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Vec<Address>) {
     let env = Env::default();
@@ -43,7 +49,7 @@ fn test_get_top_users_empty() {
     let (_env, client, admin, _users) = create_test_env();
     setup_rewards_config(&client, &admin);
 
-    let top_users = client.get_top_users(&5);
+    let top_users = client.get_top_users(&None, &5);
     assert_eq!(
         top_users.len(),
         0,
@@ -64,7 +70,7 @@ fn test_get_top_users_basic() {
     client.deposit_flexi(&users.get(2).unwrap().clone(), &2000); // 20,000 points
     client.deposit_flexi(&users.get(3).unwrap().clone(), &1500); // 15,000 points
 
-    let top_users = client.get_top_users(&10);
+    let top_users = client.get_top_users(&None, &10);
     assert_eq!(top_users.len(), 4, "Should return 4 users with points");
 
     // Check ordering (descending by points)
@@ -98,7 +104,7 @@ fn test_get_top_users_limit() {
     }
 
     // Request only top 3
-    let top_users = client.get_top_users(&3);
+    let top_users = client.get_top_users(&None, &3);
     assert_eq!(top_users.len(), 3, "Should return only 3 users");
 
     // Top 3 should be users with most deposits (indices 4, 3, 2)
@@ -183,7 +189,7 @@ fn test_ranking_with_ties() {
     client.deposit_flexi(&users.get(1).unwrap(), &1000); // 10,000 points
     client.deposit_flexi(&users.get(2).unwrap(), &2000); // 20,000 points
 
-    let top_users = client.get_top_users(&10);
+    let top_users = client.get_top_users(&None, &10);
     assert_eq!(top_users.len(), 3, "Should have 3 users");
 
     // First should be user with 20k points
@@ -243,7 +249,7 @@ fn test_large_user_set_safety() {
     }
 
     // Should not panic with large user set
-    let top_users = client.get_top_users(&20);
+    let top_users = client.get_top_users(&None, &20);
     assert!(top_users.len() <= 20, "Should respect limit");
     assert!(!top_users.is_empty(), "Should return some users");
 
@@ -254,6 +260,122 @@ fn test_large_user_set_safety() {
     }
 }
 
+#[test]
+fn test_leaderboard_bounded_beyond_capacity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[0u8; 32]);
+    client.initialize(&admin, &admin_pk);
+    setup_rewards_config(&client, &admin);
+
+    let total = LEADERBOARD_SIZE + 5;
+    let mut users = Vec::new(&env);
+    for i in 0..total {
+        let user = Address::generate(&env);
+        client.init_user(&user);
+        client.deposit_flexi(&user, &((i + 1) as i128 * 100));
+        users.push_back(user);
+    }
+
+    let top_users = client.get_top_users(&None, &(total * 2));
+    assert_eq!(top_users.len(), LEADERBOARD_SIZE);
+    assert_eq!(top_users.get(0).unwrap().0, users.get(total - 1).unwrap());
+    assert_eq!(
+        top_users.get(LEADERBOARD_SIZE - 1).unwrap().0,
+        users.get(5).unwrap()
+    );
+
+    // Users in the reserve below the board still get exact ranks
+    let lowest = users.get(0).unwrap();
+    assert_eq!(client.get_user_rank(&lowest), total);
+    assert_eq!(client.get_leaderboard_cutoff(), 0);
+    assert_eq!(
+        client.get_user_ranking_details(&users.get(total - 1).unwrap()),
+        Some((1, total as u128 * 1_000, total))
+    );
+
+    // Earning enough points moves a user onto the board
+    client.deposit_flexi(&lowest, &1_000_000);
+    assert_eq!(client.get_user_rank(&lowest), 1);
+}
+
+#[test]
+fn test_ranks_stay_exact_when_points_drop_on_full_board() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &BytesN::from_array(&env, &[0u8; 32]));
+    setup_rewards_config(&client, &admin);
+
+    // users[i] holds (i + 1) * 1_000 points; users[0] is left off the board
+    // and its reserve
+    let total = LEADERBOARD_SIZE + LEADERBOARD_RESERVE_SIZE + 1;
+    let mut users = Vec::new(&env);
+    for i in 0..total {
+        let user = Address::generate(&env);
+        client.init_user(&user);
+        client.deposit_flexi(&user, &((i + 1) as i128 * 100));
+        users.push_back(user);
+    }
+    assert_eq!(client.get_leaderboard_cutoff(), 1_000);
+    assert_eq!(client.get_user_rank(&users.get(0).unwrap()), 0);
+    let first_reserved = users.get(total - 1 - LEADERBOARD_SIZE).unwrap();
+    assert_eq!(client.get_user_rank(&first_reserved), LEADERBOARD_SIZE + 1);
+
+    // The leader drops to 1_500 points: only users[0] has fewer
+    let leader = users.get(total - 1).unwrap();
+    client.redeem_points(&leader, &(total as u128 * 1_000 - 1_500));
+    assert_eq!(client.get_user_rank(&leader), total - 1);
+    assert_eq!(client.get_user_rank(&users.get(total - 2).unwrap()), 1);
+
+    // Dropping to the cutoff leaves the rankings, and the reserve refills
+    // the board
+    let second = users.get(total - 2).unwrap();
+    client.redeem_points(&second, &((total - 1) as u128 * 1_000 - 900));
+    assert_eq!(client.get_user_rank(&second), 0);
+    assert_eq!(client.get_user_rank(&first_reserved), LEADERBOARD_SIZE - 1);
+    assert_eq!(client.get_top_users(&None, &total).len(), LEADERBOARD_SIZE);
+
+    // A user rising above the cutoff is ranked again
+    let outsider = users.get(0).unwrap();
+    client.deposit_flexi(&outsider, &100);
+    assert_eq!(client.get_user_rank(&outsider), total - 2);
+    assert_eq!(client.get_user_rank(&leader), total - 1);
+
+    let board = client.get_top_users(&None, &total);
+    for i in 1..board.len() {
+        assert!(board.get(i - 1).unwrap().1 >= board.get(i).unwrap().1);
+    }
+}
+
+#[test]
+fn test_rank_drops_after_redeeming_points() {
+    let (_env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    let alice = users.get(0).unwrap();
+    let bob = users.get(1).unwrap();
+
+    client.deposit_flexi(&alice, &2_000); // 20,000 points
+    client.deposit_flexi(&bob, &1_000); // 10,000 points
+    assert_eq!(client.get_user_rank(&alice), 1);
+
+    client.redeem_points(&alice, &15_000);
+    assert_eq!(client.get_user_rank(&alice), 2);
+    assert_eq!(client.get_user_rank(&bob), 1);
+
+    client.redeem_points(&alice, &5_000);
+    assert_eq!(client.get_user_rank(&alice), 0);
+    assert_eq!(client.get_top_users(&None, &10).len(), 1);
+}
+
 #[test]
 fn test_ranking_read_only() {
     let (_env, client, admin, users) = create_test_env();
@@ -264,11 +386,133 @@ fn test_ranking_read_only() {
     client.deposit_flexi(&users.get(0).unwrap(), &1000);
 
     // Multiple calls should return consistent results (no state mutation)
-    let top1 = client.get_top_users(&5);
-    let top2 = client.get_top_users(&5);
+    let top1 = client.get_top_users(&None, &5);
+    let top2 = client.get_top_users(&None, &5);
     assert_eq!(top1.len(), top2.len(), "Should be consistent");
 
     let rank1 = client.get_user_rank(&users.get(0).unwrap());
     let rank2 = client.get_user_rank(&users.get(0).unwrap());
     assert_eq!(rank1, rank2, "Rank should be consistent");
 }
+
+// ========== Reward Seasons ==========
+
+const SEASON_LENGTH: u64 = 30 * 24 * 60 * 60;
+
+#[test]
+fn test_season_ranks_only_points_earned_during_season() {
+    let (env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    let veteran = users.get(0).unwrap();
+    let newcomer = users.get(1).unwrap();
+
+    client.deposit_flexi(&veteran, &5_000); // 50,000 all-time points before the season
+
+    let season = client.start_season(&admin, &SEASON_LENGTH, &vec![&env]);
+    assert_eq!(client.get_current_season(), season);
+
+    client.deposit_flexi(&veteran, &100); // 1,000 season points
+    client.deposit_flexi(&newcomer, &300); // 3,000 season points
+
+    let season_top = client.get_top_users(&Some(season), &10);
+    assert_eq!(season_top.len(), 2);
+    assert_eq!(season_top.get(0).unwrap(), (newcomer.clone(), 3_000));
+    assert_eq!(season_top.get(1).unwrap(), (veteran.clone(), 1_000));
+
+    // All-time leaderboard is unaffected
+    let all_time = client.get_top_users(&None, &10);
+    assert_eq!(all_time.get(0).unwrap().0, veteran);
+}
+
+#[test]
+fn test_season_leaderboard_frozen_after_finalize() {
+    let (env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    let alice = users.get(0).unwrap();
+    let bob = users.get(1).unwrap();
+
+    let season = client.start_season(&admin, &SEASON_LENGTH, &vec![&env]);
+    client.deposit_flexi(&alice, &200);
+    client.deposit_flexi(&bob, &100);
+
+    assert_eq!(
+        client.try_finalize_season(&season),
+        Err(Ok(SavingsError::SeasonNotEnded))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = SEASON_LENGTH);
+    client.finalize_season(&season);
+    assert!(client.get_season(&season).unwrap().finalized);
+
+    // Points earned after the season closed do not change its standings
+    client.deposit_flexi(&bob, &1_000);
+    let frozen = client.get_top_users(&Some(season), &10);
+    assert_eq!(frozen.get(0).unwrap(), (alice, 2_000));
+    assert_eq!(frozen.get(1).unwrap(), (bob, 1_000));
+
+    assert_eq!(
+        client.try_finalize_season(&season),
+        Err(Ok(SavingsError::SeasonFinalized))
+    );
+}
+
+#[test]
+fn test_season_prizes_paid_by_rank() {
+    let (env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    let alice = users.get(0).unwrap();
+    let bob = users.get(1).unwrap();
+    let carol = users.get(2).unwrap();
+
+    let season = client.start_season(&admin, &SEASON_LENGTH, &vec![&env, 5_000u128, 1_000u128]);
+    client.deposit_flexi(&alice, &100);
+    client.deposit_flexi(&bob, &300);
+    client.deposit_flexi(&carol, &200);
+
+    env.ledger().with_mut(|li| li.timestamp = SEASON_LENGTH);
+    client.finalize_season(&season);
+
+    assert_eq!(client.get_user_rewards(&bob).total_points, 3_000 + 5_000);
+    assert_eq!(client.get_user_rewards(&carol).total_points, 2_000 + 1_000);
+    assert_eq!(client.get_user_rewards(&alice).total_points, 1_000);
+}
+
+#[test]
+fn test_new_season_requires_previous_finalized() {
+    let (env, client, admin, users) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    let alice = users.get(0).unwrap();
+
+    let first = client.start_season(&admin, &SEASON_LENGTH, &vec![&env]);
+    assert_eq!(
+        client.try_start_season(&admin, &(2 * SEASON_LENGTH), &vec![&env]),
+        Err(Ok(SavingsError::SeasonActive))
+    );
+
+    client.deposit_flexi(&alice, &100);
+    env.ledger().with_mut(|li| li.timestamp = SEASON_LENGTH);
+    client.finalize_season(&first);
+
+    let second = client.start_season(&admin, &(2 * SEASON_LENGTH), &vec![&env]);
+    assert_eq!(second, first + 1);
+    client.deposit_flexi(&alice, &400);
+
+    // Each season keeps its own counter and past seasons stay queryable
+    assert_eq!(client.get_season_points(&first, &alice), 1_000);
+    assert_eq!(client.get_season_points(&second, &alice), 4_000);
+    assert_eq!(
+        client.get_top_users(&Some(first), &1).get(0).unwrap().1,
+        1_000
+    );
+}
+
+#[test]
+fn test_start_season_requires_admin() {
+    let (env, client, _admin, users) = create_test_env();
+    let stranger = users.get(0).unwrap();
+
+    assert_eq!(
+        client.try_start_season(&stranger, &SEASON_LENGTH, &vec![&env]),
+        Err(Ok(SavingsError::Unauthorized))
+    );
+}
@@ -1,7 +1,14 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
-use Nestera::{NesteraContract, NesteraContractClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env,
+};
+use Nestera::{
+    NesteraContract, NesteraContractClient, RatePlan, RedemptionBenefit, RedemptionItem,
+    SavingsError,
+};
 
 fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
     let env = Env::default();
@@ -257,3 +264,210 @@ fn test_redemption_overflow_protection() {
     let rewards_after = client.get_user_rewards(&user);
     assert_eq!(rewards_after.total_points, rewards.total_points);
 }
+
+// ========== Redemption Catalog ==========
+
+fn catalog_item(benefit: RedemptionBenefit, cost: u128) -> RedemptionItem {
+    RedemptionItem {
+        benefit,
+        cost,
+        duration_seconds: 7 * 24 * 60 * 60,
+        available_until: 0,
+        max_per_user: 0,
+        active: true,
+    }
+}
+
+#[test]
+fn test_add_redemption_item_requires_admin() {
+    let (env, client, _admin, _user) = create_test_env();
+    let stranger = Address::generate(&env);
+
+    let result = client.try_add_redemption_item(
+        &stranger,
+        &catalog_item(RedemptionBenefit::EarlyBreakWaiver, 1_000),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+}
+
+#[test]
+fn test_add_redemption_item_rejects_invalid_bps() {
+    let (_env, client, admin, _user) = create_test_env();
+
+    let result = client.try_add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::FeeDiscount(10_001), 1_000),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidFeeBps)));
+}
+
+#[test]
+fn test_redeem_fee_discount_lowers_protocol_fee() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    client.set_protocol_fee_bps(&1_000); // 10%
+
+    add_points_directly(&env, &client, &user, 50_000);
+    let item_id = client.add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::FeeDiscount(5_000), 10_000),
+    );
+    let points_before = client.get_user_rewards(&user).total_points;
+
+    client.redeem_benefit(&user, &item_id);
+    assert_eq!(
+        client.get_user_rewards(&user).total_points,
+        points_before - 10_000
+    );
+
+    // Half of the 10% fee is waived: 1_000 deposit pays 50 instead of 100
+    let balance_before = client.get_flexi_balance(&user);
+    client.deposit_flexi(&user, &1_000);
+    assert_eq!(client.get_flexi_balance(&user), balance_before + 950);
+
+    // Once the benefit lapses the full fee applies again
+    env.ledger()
+        .with_mut(|li| li.timestamp += 7 * 24 * 60 * 60 + 1);
+    let balance_before = client.get_flexi_balance(&user);
+    client.deposit_flexi(&user, &1_000);
+    assert_eq!(client.get_flexi_balance(&user), balance_before + 900);
+}
+
+#[test]
+fn test_redeem_rate_boost_applies_to_plan_only() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    client.set_flexi_rate(&admin, &400);
+    client.set_goal_rate(&admin, &600);
+
+    add_points_directly(&env, &client, &user, 50_000);
+    let item_id = client.add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::RateBoost(RatePlan::Flexi, 150), 5_000),
+    );
+    client.redeem_benefit(&user, &item_id);
+
+    assert_eq!(client.get_effective_rate(&user, &RatePlan::Flexi), 550);
+    assert_eq!(client.get_effective_rate(&user, &RatePlan::Goal), 600);
+
+    let other = Address::generate(&env);
+    assert_eq!(client.get_effective_rate(&other, &RatePlan::Flexi), 400);
+}
+
+/// 365.25 days, so a lock's yield is exactly its rate
+const LOCK_YEAR: u64 = 31_557_600;
+
+#[test]
+fn test_rate_boost_is_paid_on_lock_withdrawal() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    client.set_lock_rate(&admin, &365, &2_500);
+
+    add_points_directly(&env, &client, &user, 50_000);
+    let item_id = client.add_redemption_item(
+        &admin,
+        &catalog_item(
+            RedemptionBenefit::RateBoost(RatePlan::Lock(365), 2_500),
+            5_000,
+        ),
+    );
+    client.redeem_benefit(&user, &item_id);
+    let boosted = client.create_lock_save(&user, &10_000, &LOCK_YEAR);
+
+    let other = Address::generate(&env);
+    client.init_user(&other);
+    let plain = client.create_lock_save(&other, &10_000, &LOCK_YEAR);
+
+    // The boost lapses after a week, but the lock keeps the rate it opened with
+    env.ledger().with_mut(|li| li.timestamp += LOCK_YEAR);
+    assert_eq!(client.withdraw_lock_save(&user, &boosted), 15_000);
+    assert_eq!(client.withdraw_lock_save(&other, &plain), 12_500);
+}
+
+#[test]
+fn test_redeem_early_break_waiver_is_single_use() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    client.set_early_break_fee_bps(&500); // 5%
+
+    add_points_directly(&env, &client, &user, 50_000);
+    let item_id = client.add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::EarlyBreakWaiver, 5_000),
+    );
+    client.redeem_benefit(&user, &item_id);
+    assert_eq!(client.get_active_benefits(&user).len(), 1);
+
+    let first = client.create_goal_save(&user, &symbol_short!("car"), &10_000, &2_000);
+    assert_eq!(client.break_goal_save(&user, &first), 2_000);
+    assert_eq!(client.get_active_benefits(&user).len(), 0);
+
+    let second = client.create_goal_save(&user, &symbol_short!("bike"), &10_000, &2_000);
+    assert_eq!(client.break_goal_save(&user, &second), 1_900);
+}
+
+#[test]
+fn test_redeem_benefit_respects_per_user_limit() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+
+    add_points_directly(&env, &client, &user, 50_000);
+    let mut item = catalog_item(RedemptionBenefit::FeeDiscount(1_000), 1_000);
+    item.max_per_user = 2;
+    let item_id = client.add_redemption_item(&admin, &item);
+
+    client.redeem_benefit(&user, &item_id);
+    client.redeem_benefit(&user, &item_id);
+    assert_eq!(client.get_redemption_count(&user, &item_id), 2);
+
+    let result = client.try_redeem_benefit(&user, &item_id);
+    assert_eq!(result, Err(Ok(SavingsError::RedemptionLimitReached)));
+}
+
+#[test]
+fn test_redeem_benefit_unavailable_when_retired_or_expired() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    add_points_directly(&env, &client, &user, 50_000);
+
+    let retired = client.add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::FeeDiscount(1_000), 1_000),
+    );
+    client.set_redemption_item_active(&admin, &retired, &false);
+    assert_eq!(
+        client.try_redeem_benefit(&user, &retired),
+        Err(Ok(SavingsError::RedemptionUnavailable))
+    );
+
+    let mut item = catalog_item(RedemptionBenefit::FeeDiscount(1_000), 1_000);
+    item.available_until = env.ledger().timestamp() + 100;
+    let limited = client.add_redemption_item(&admin, &item);
+    env.ledger().with_mut(|li| li.timestamp += 101);
+    assert_eq!(
+        client.try_redeem_benefit(&user, &limited),
+        Err(Ok(SavingsError::RedemptionUnavailable))
+    );
+
+    assert_eq!(
+        client.try_redeem_benefit(&user, &99),
+        Err(Ok(SavingsError::RedemptionItemNotFound))
+    );
+}
+
+#[test]
+fn test_redeem_benefit_insufficient_points() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&env, &client, &admin);
+    add_points_directly(&env, &client, &user, 1_000);
+
+    let item_id = client.add_redemption_item(
+        &admin,
+        &catalog_item(RedemptionBenefit::EarlyBreakWaiver, 1_000_000),
+    );
+    assert_eq!(
+        client.try_redeem_benefit(&user, &item_id),
+        Err(Ok(SavingsError::InsufficientBalance))
+    );
+    assert_eq!(client.get_redemption_count(&user, &item_id), 0);
+}
@@ -0,0 +1,155 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Env};
+use Nestera::{NesteraContract, NesteraContractClient, ReferralConfig, SavingsError};
+
+fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[0u8; 32]);
+    client.initialize(&admin, &admin_pk);
+
+    let referrer = Address::generate(&env);
+    client.initialize_user(&referrer, &None);
+
+    (env, client, admin, referrer)
+}
+
+fn referral_config(fee_share_bps: u32) -> ReferralConfig {
+    ReferralConfig {
+        deposit_threshold: 1_000,
+        referrer_points: 500,
+        fee_share_bps,
+    }
+}
+
+#[test]
+fn test_initialize_user_records_referrer() {
+    let (env, client, _admin, referrer) = create_test_env();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.initialize_user(&alice, &Some(referrer.clone()));
+    client.initialize_user(&bob, &Some(referrer.clone()));
+
+    assert_eq!(client.get_referrer(&alice), Some(referrer.clone()));
+    assert_eq!(client.get_referrals(&referrer), vec![&env, alice, bob]);
+    assert_eq!(client.get_referral_stats(&referrer).referrals, 2);
+    assert_eq!(client.get_referrer(&referrer), None);
+}
+
+#[test]
+fn test_self_referral_rejected() {
+    let (env, client, _admin, _referrer) = create_test_env();
+    let alice = Address::generate(&env);
+
+    let result = client.try_initialize_user(&alice, &Some(alice.clone()));
+    assert_eq!(result, Err(Ok(SavingsError::InvalidReferrer)));
+    assert!(!client.user_exists(&alice));
+}
+
+#[test]
+fn test_unregistered_referrer_rejected() {
+    let (env, client, _admin, _referrer) = create_test_env();
+    let alice = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    let result = client.try_initialize_user(&alice, &Some(stranger));
+    assert_eq!(result, Err(Ok(SavingsError::InvalidReferrer)));
+}
+
+#[test]
+fn test_referrer_cannot_be_re_referred() {
+    let (env, client, _admin, referrer) = create_test_env();
+    let alice = Address::generate(&env);
+    client.initialize_user(&alice, &Some(referrer.clone()));
+
+    // Referrers are fixed at sign-up, so the referrer cannot later join under alice
+    let result = client.try_initialize_user(&referrer, &Some(alice));
+    assert_eq!(result, Err(Ok(SavingsError::UserAlreadyExists)));
+}
+
+#[test]
+fn test_set_referral_config_requires_admin() {
+    let (env, client, _admin, _referrer) = create_test_env();
+    let stranger = Address::generate(&env);
+
+    let result = client.try_set_referral_config(&stranger, &referral_config(0));
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+}
+
+#[test]
+fn test_referrer_rewarded_once_threshold_passed() {
+    let (env, client, admin, referrer) = create_test_env();
+    client.set_referral_config(&admin, &referral_config(0));
+    let alice = Address::generate(&env);
+    client.initialize_user(&alice, &Some(referrer.clone()));
+
+    client.deposit_flexi(&alice, &600);
+    assert_eq!(client.get_user_rewards(&referrer).total_points, 0);
+    assert_eq!(client.get_referral_stats(&referrer).qualified, 0);
+
+    client.deposit_flexi(&alice, &600);
+    assert_eq!(client.get_user_rewards(&referrer).total_points, 500);
+
+    // Only the first crossing pays out
+    client.deposit_flexi(&alice, &5_000);
+    let stats = client.get_referral_stats(&referrer);
+    assert_eq!(client.get_user_rewards(&referrer).total_points, 500);
+    assert_eq!(stats.qualified, 1);
+    assert_eq!(stats.points_earned, 500);
+}
+
+#[test]
+fn test_referrer_receives_fee_share() {
+    let (env, client, admin, referrer) = create_test_env();
+    let treasury = Address::generate(&env);
+    client.set_fee_recipient(&treasury);
+    client.set_protocol_fee_bps(&1_000); // 10%
+    client.set_referral_config(&admin, &referral_config(2_000)); // 20% of fees
+
+    let alice = Address::generate(&env);
+    client.initialize_user(&alice, &Some(referrer.clone()));
+
+    client.deposit_flexi(&alice, &1_000);
+
+    assert_eq!(client.get_flexi_balance(&alice), 900);
+    assert_eq!(client.get_flexi_balance(&referrer), 20);
+    assert_eq!(client.get_referral_stats(&referrer).fees_earned, 20);
+}
+
+#[test]
+fn test_referrer_receives_share_of_early_break_fee() {
+    let (env, client, admin, referrer) = create_test_env();
+    let treasury = Address::generate(&env);
+    client.set_fee_recipient(&treasury);
+    client.set_early_break_fee_bps(&1_000); // 10%
+    client.set_referral_config(&admin, &referral_config(2_000)); // 20% of fees
+
+    let alice = Address::generate(&env);
+    client.initialize_user(&alice, &Some(referrer.clone()));
+    let goal_id = client.create_goal_save(&alice, &symbol_short!("car"), &10_000, &1_000);
+
+    assert_eq!(client.break_goal_save(&alice, &goal_id), 900);
+    assert_eq!(client.get_flexi_balance(&referrer), 20);
+    assert_eq!(client.get_referral_stats(&referrer).fees_earned, 20);
+}
+
+#[test]
+fn test_unreferred_user_pays_full_fee_to_treasury() {
+    let (env, client, admin, referrer) = create_test_env();
+    client.set_protocol_fee_bps(&1_000);
+    client.set_referral_config(&admin, &referral_config(2_000));
+
+    let alice = Address::generate(&env);
+    client.initialize_user(&alice, &None);
+    client.deposit_flexi(&alice, &1_000);
+
+    assert_eq!(client.get_flexi_balance(&referrer), 0);
+    assert_eq!(client.get_referral_stats(&referrer).fees_earned, 0);
+}
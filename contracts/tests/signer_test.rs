@@ -0,0 +1,441 @@
+#![cfg(test)]
+
+use ed25519_dalek::Signer as _;
+use p256::ecdsa::Signature;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Bytes, BytesN, Env, String, Vec,
+};
+use Nestera::{
+    DataKey, MintPayload, MintSignature, MintSignerSet, NesteraContract, NesteraContractClient,
+    SavingsError, SignerKey, SignerProof, WebAuthnAssertion, WebAuthnRelyingParty,
+};
+
+// RFC 8032 section 7.1, TEST 1 (empty message)
+const ED25519_PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const ED25519_SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+const MESSAGE: &[u8] = b"nestera passkey test";
+
+const RP_ID: &[u8] = b"nestera.app";
+const ORIGIN: &str = "https://nestera.app";
+
+/// Authenticator data flags with only user presence set
+const USER_PRESENT: u8 = 0x01;
+
+fn hex<const N: usize>(s: &str) -> [u8; N] {
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+    }
+    out
+}
+
+fn setup() -> (
+    Env,
+    NesteraContractClient<'static>,
+    Address,
+    ed25519_dalek::SigningKey,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let admin_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+    let admin = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &BytesN::from_array(&env, &admin_key.verifying_key().to_bytes()),
+    );
+    client.set_webauthn_relying_party(&admin, &relying_party(&env, RP_ID, ORIGIN));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    (env, client, admin, admin_key)
+}
+
+fn relying_party(env: &Env, rp_id: &[u8], origin: &str) -> WebAuthnRelyingParty {
+    WebAuthnRelyingParty {
+        rp_id_hash: env.crypto().sha256(&Bytes::from_slice(env, rp_id)).into(),
+        origin: String::from_str(env, origin),
+    }
+}
+
+fn passkey(env: &Env, key: &p256::ecdsa::SigningKey) -> SignerKey {
+    let point = key.verifying_key().to_encoded_point(false);
+    SignerKey::Secp256r1(BytesN::from_array(
+        env,
+        point.as_bytes().try_into().unwrap(),
+    ))
+}
+
+fn ed25519_key(env: &Env, key: &ed25519_dalek::SigningKey) -> SignerKey {
+    SignerKey::Ed25519(BytesN::from_array(env, &key.verifying_key().to_bytes()))
+}
+
+fn message_bytes(message: &Bytes) -> std::vec::Vec<u8> {
+    let mut buf = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    buf
+}
+
+fn base64url(bytes: &[u8]) -> std::string::String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    let mut out = std::string::String::new();
+    for chunk in bytes.chunks(3) {
+        let mut block = [0u8; 3];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let n = (block[0] as u32) << 16 | (block[1] as u32) << 8 | block[2] as u32;
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// What a browser returns for `navigator.credentials.get` with the SHA-256
+/// of `message` as the challenge
+fn webauthn_assertion(
+    env: &Env,
+    key: &p256::ecdsa::SigningKey,
+    message: &Bytes,
+    flags: u8,
+) -> WebAuthnAssertion {
+    assertion_for(env, key, message, flags, RP_ID, ORIGIN)
+}
+
+/// An assertion made on `origin` for the relying party `rp_id`
+fn assertion_for(
+    env: &Env,
+    key: &p256::ecdsa::SigningKey,
+    message: &Bytes,
+    flags: u8,
+    rp_id: &[u8],
+    origin: &str,
+) -> WebAuthnAssertion {
+    let challenge = env.crypto().sha256(message).to_array();
+    let client_data_json = std::format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"{}","crossOrigin":false}}"#,
+        base64url(&challenge),
+        origin
+    );
+    let client_data_json = Bytes::from_slice(env, client_data_json.as_bytes());
+
+    let mut authenticator_data = env
+        .crypto()
+        .sha256(&Bytes::from_slice(env, rp_id))
+        .to_array()
+        .to_vec();
+    authenticator_data.push(flags);
+    authenticator_data.extend_from_slice(&[0, 0, 0, 1]);
+
+    let mut signed = authenticator_data.clone();
+    signed.extend_from_slice(&env.crypto().sha256(&client_data_json).to_array());
+    let signature: Signature = key.sign(&signed);
+    let signature = signature.normalize_s().unwrap_or(signature);
+
+    WebAuthnAssertion {
+        authenticator_data: Bytes::from_slice(env, &authenticator_data),
+        client_data_json,
+        signature: BytesN::from_array(env, &signature.to_bytes().into()),
+    }
+}
+
+/// The same signature with S replaced by `n - S`
+fn high_s(env: &Env, signature: &BytesN<64>) -> BytesN<64> {
+    let signature = Signature::from_slice(&signature.to_array()).unwrap();
+    let (r, s) = signature.split_scalars();
+    let high = Signature::from_scalars(r, -s).unwrap();
+    assert!(high.normalize_s().is_some());
+    BytesN::from_array(env, &high.to_bytes().into())
+}
+
+fn passkey_signature(
+    env: &Env,
+    client: &NesteraContractClient,
+    key: &p256::ecdsa::SigningKey,
+    payload: &MintPayload,
+) -> MintSignature {
+    let message = client.get_mint_message(payload);
+    MintSignature {
+        public_key: passkey(env, key),
+        signature: SignerProof::WebAuthn(webauthn_assertion(env, key, &message, USER_PRESENT)),
+    }
+}
+
+fn ed25519_signature(
+    env: &Env,
+    client: &NesteraContractClient,
+    key: &ed25519_dalek::SigningKey,
+    payload: &MintPayload,
+) -> MintSignature {
+    let message = message_bytes(&client.get_mint_message(payload));
+    MintSignature {
+        public_key: ed25519_key(env, key),
+        signature: SignerProof::Ed25519(BytesN::from_array(env, &key.sign(&message).to_bytes())),
+    }
+}
+
+fn payload(user: &Address, amount: i128, nonce: u64) -> MintPayload {
+    MintPayload {
+        user: user.clone(),
+        amount,
+        timestamp: 1_000,
+        expiry_duration: 3_600,
+        nonce,
+    }
+}
+
+fn passkey_signer() -> p256::ecdsa::SigningKey {
+    p256::ecdsa::SigningKey::from_bytes(&[0x42u8; 32].into()).unwrap()
+}
+
+#[test]
+fn test_ed25519_rfc8032_vector() {
+    let (env, client, _admin, _key) = setup();
+
+    client.verify_signed_message(
+        &SignerKey::Ed25519(BytesN::from_array(&env, &hex(ED25519_PUBLIC_KEY))),
+        &Bytes::new(&env),
+        &SignerProof::Ed25519(BytesN::from_array(&env, &hex(ED25519_SIGNATURE))),
+    );
+}
+
+#[test]
+fn test_passkey_webauthn_assertion() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+
+    client.verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(webauthn_assertion(&env, &key, &message, USER_PRESENT)),
+    );
+}
+
+#[test]
+fn test_passkey_high_s_signature_is_normalized() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+    let mut assertion = webauthn_assertion(&env, &key, &message, USER_PRESENT);
+    assertion.signature = high_s(&env, &assertion.signature);
+
+    client.verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(assertion),
+    );
+}
+
+#[test]
+fn test_passkey_assertion_for_other_message_is_rejected() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let assertion = webauthn_assertion(&env, &key, &Bytes::from_slice(&env, MESSAGE), USER_PRESENT);
+
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &key),
+        &Bytes::from_slice(&env, b"nestera passkey tests"),
+        &SignerProof::WebAuthn(assertion),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+}
+
+#[test]
+fn test_passkey_assertion_without_user_presence_is_rejected() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(webauthn_assertion(&env, &key, &message, 0)),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+}
+
+#[test]
+fn test_passkey_assertion_with_tampered_authenticator_data_is_rejected() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+    let mut assertion = webauthn_assertion(&env, &key, &message, USER_PRESENT);
+    assertion.authenticator_data.set(36, 2);
+
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(assertion),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_passkey_assertion_for_other_relying_party_is_rejected() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(assertion_for(
+            &env,
+            &key,
+            &message,
+            USER_PRESENT,
+            b"evil.example",
+            ORIGIN,
+        )),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+}
+
+#[test]
+fn test_passkey_assertion_from_other_origin_is_rejected() {
+    let (env, client, _admin, _key) = setup();
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+
+    for origin in ["https://evil.example", "https://nestera.app.evil.example"] {
+        let result = client.try_verify_signed_message(
+            &passkey(&env, &key),
+            &message,
+            &SignerProof::WebAuthn(assertion_for(
+                &env,
+                &key,
+                &message,
+                USER_PRESENT,
+                RP_ID,
+                origin,
+            )),
+        );
+        assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+    }
+}
+
+#[test]
+fn test_passkey_assertion_without_relying_party_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = NesteraContractClient::new(&env, &env.register(NesteraContract, ()));
+    client.initialize(
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1u8; 32]),
+    );
+    assert_eq!(client.get_webauthn_relying_party(), None);
+
+    let key = passkey_signer();
+    let message = Bytes::from_slice(&env, MESSAGE);
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &key),
+        &message,
+        &SignerProof::WebAuthn(webauthn_assertion(&env, &key, &message, USER_PRESENT)),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+}
+
+#[test]
+fn test_relying_party_requires_an_origin() {
+    let (env, client, admin, _key) = setup();
+
+    let result = client.try_set_webauthn_relying_party(&admin, &relying_party(&env, RP_ID, ""));
+    assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+    assert_eq!(
+        client.get_webauthn_relying_party(),
+        Some(relying_party(&env, RP_ID, ORIGIN))
+    );
+}
+
+#[test]
+fn test_ed25519_signature_is_not_accepted_for_passkey() {
+    let (env, client, _admin, _key) = setup();
+
+    // Right signature bytes, wrong key type
+    let result = client.try_verify_signed_message(
+        &passkey(&env, &passkey_signer()),
+        &Bytes::new(&env),
+        &SignerProof::Ed25519(BytesN::from_array(&env, &hex(ED25519_SIGNATURE))),
+    );
+    assert_eq!(result, Err(Ok(SavingsError::InvalidSignature)));
+}
+
+#[test]
+fn test_mint_with_passkey_admin_key() {
+    let (env, client, admin, _key) = setup();
+    let passkey_signer = passkey_signer();
+    client.rotate_admin_public_key(&admin, &passkey(&env, &passkey_signer), &0);
+    assert_eq!(
+        client.get_admin_public_key(),
+        Some(passkey(&env, &passkey_signer))
+    );
+
+    let user = Address::generate(&env);
+    client.init_user(&user);
+    let payload = payload(&user, 500, 1);
+    let signatures = Vec::from_array(
+        &env,
+        [passkey_signature(&env, &client, &passkey_signer, &payload)],
+    );
+
+    assert_eq!(client.mint(&payload, &signatures), 500);
+    assert_eq!(client.get_flexi_balance(&user), 500);
+}
+
+#[test]
+fn test_large_mint_with_mixed_signer_types() {
+    let (env, client, admin, _key) = setup();
+    let passkey_signer = passkey_signer();
+    let ed25519_signer = ed25519_dalek::SigningKey::from_bytes(&[9u8; 32]);
+    client.set_mint_signers(
+        &admin,
+        &MintSignerSet {
+            signers: Vec::from_array(
+                &env,
+                [
+                    passkey(&env, &passkey_signer),
+                    ed25519_key(&env, &ed25519_signer),
+                ],
+            ),
+            threshold: 2,
+            large_amount: 1_000,
+        },
+    );
+
+    let user = Address::generate(&env);
+    client.init_user(&user);
+    let payload = payload(&user, 5_000, 1);
+    let signatures = Vec::from_array(
+        &env,
+        [
+            passkey_signature(&env, &client, &passkey_signer, &payload),
+            ed25519_signature(&env, &client, &ed25519_signer, &payload),
+        ],
+    );
+
+    assert_eq!(client.mint(&payload, &signatures), 5_000);
+}
+
+#[test]
+fn test_admin_key_stored_as_bare_ed25519_bytes_is_still_read() {
+    let (env, client, _admin, admin_key) = setup();
+    let raw = BytesN::from_array(&env, &admin_key.verifying_key().to_bytes());
+    env.as_contract(&client.address, || {
+        env.storage().instance().set(&DataKey::AdminPublicKey, &raw);
+    });
+
+    assert_eq!(client.get_admin_public_key(), Some(SignerKey::Ed25519(raw)));
+
+    let user = Address::generate(&env);
+    client.init_user(&user);
+    let payload = payload(&user, 500, 1);
+    let signatures = Vec::from_array(
+        &env,
+        [ed25519_signature(&env, &client, &admin_key, &payload)],
+    );
+    assert_eq!(client.mint(&payload, &signatures), 500);
+}
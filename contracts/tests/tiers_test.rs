@@ -0,0 +1,198 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, BytesN, Env, IntoVal, Vec,
+};
+use Nestera::{
+    rewards::TierChanged, NesteraContract, NesteraContractClient, RatePlan, SavingsError,
+    SavingsTier,
+};
+
+fn create_test_env() -> (Env, NesteraContractClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(NesteraContract, ());
+    let client = NesteraContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let admin_pk = BytesN::from_array(&env, &[0u8; 32]);
+    client.initialize(&admin, &admin_pk);
+
+    let user = Address::generate(&env);
+    client.init_user(&user);
+
+    (env, client, admin, user)
+}
+
+fn setup_rewards_config(client: &NesteraContractClient, admin: &Address) {
+    client.init_rewards_config(
+        admin, &10,        // points_per_token
+        &0,         // streak_bonus_bps
+        &0,         // long_lock_bonus_bps
+        &0,         // goal_completion_bonus
+        &true,      // enabled
+        &0,         // min_deposit_for_rewards
+        &0,         // action_cooldown_seconds
+        &1_000_000, // max_daily_points
+        &0,         // max_streak_multiplier
+    );
+}
+
+fn default_tiers(env: &Env) -> Vec<SavingsTier> {
+    vec![
+        env,
+        SavingsTier {
+            name: symbol_short!("bronze"),
+            min_lifetime_deposited: 1_000,
+            points_multiplier_bps: 10_000,
+            fee_discount_bps: 1_000,
+            rate_bonus_bps: 25,
+        },
+        SavingsTier {
+            name: symbol_short!("gold"),
+            min_lifetime_deposited: 5_000,
+            points_multiplier_bps: 15_000,
+            fee_discount_bps: 5_000,
+            rate_bonus_bps: 100,
+        },
+    ]
+}
+
+#[test]
+fn test_set_savings_tiers_requires_admin() {
+    let (env, client, _admin, _user) = create_test_env();
+    let stranger = Address::generate(&env);
+
+    let result = client.try_set_savings_tiers(&stranger, &default_tiers(&env));
+    assert_eq!(result, Err(Ok(SavingsError::Unauthorized)));
+}
+
+#[test]
+fn test_set_savings_tiers_rejects_unordered_thresholds() {
+    let (env, client, admin, _user) = create_test_env();
+    let mut tiers = default_tiers(&env);
+    let mut gold = tiers.get(1).unwrap();
+    gold.min_lifetime_deposited = 1_000;
+    tiers.set(1, gold);
+
+    let result = client.try_set_savings_tiers(&admin, &tiers);
+    assert_eq!(result, Err(Ok(SavingsError::InvalidAmount)));
+}
+
+#[test]
+fn test_set_savings_tiers_rejects_multiplier_below_one() {
+    let (env, client, admin, _user) = create_test_env();
+    let mut tiers = default_tiers(&env);
+    let mut bronze = tiers.get(0).unwrap();
+    bronze.points_multiplier_bps = 9_000;
+    tiers.set(0, bronze);
+
+    let result = client.try_set_savings_tiers(&admin, &tiers);
+    assert_eq!(result, Err(Ok(SavingsError::InvalidFeeBps)));
+}
+
+#[test]
+fn test_user_tier_follows_lifetime_deposits() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    client.set_savings_tiers(&admin, &default_tiers(&env));
+
+    client.deposit_flexi(&user, &500);
+    assert_eq!(client.get_user_tier(&user), None);
+
+    client.deposit_flexi(&user, &500);
+    assert_eq!(
+        client.get_user_tier(&user).unwrap().name,
+        symbol_short!("bronze")
+    );
+
+    client.deposit_flexi(&user, &4_000);
+    assert_eq!(
+        client.get_user_tier(&user).unwrap().name,
+        symbol_short!("gold")
+    );
+}
+
+#[test]
+fn test_tier_change_event_emitted_on_threshold() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    client.set_savings_tiers(&admin, &default_tiers(&env));
+
+    client.deposit_flexi(&user, &6_000);
+
+    let events = env.events().all();
+    let tier_events: std::vec::Vec<_> = events
+        .iter()
+        .filter(|e| {
+            e.0 == client.address
+                && e.1
+                    == (
+                        symbol_short!("rewards"),
+                        symbol_short!("tier"),
+                        user.clone(),
+                    )
+                        .into_val(&env)
+        })
+        .collect();
+
+    assert_eq!(tier_events.len(), 1);
+    let event: TierChanged = tier_events[0].2.clone().into_val(&env);
+    assert_eq!(event.old_level, 0);
+    assert_eq!(event.new_level, 2);
+}
+
+#[test]
+fn test_tier_multiplier_applied_to_deposit_points() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    client.set_savings_tiers(&admin, &default_tiers(&env));
+
+    // Reaches gold on this deposit; the multiplier applies from the next one
+    client.deposit_flexi(&user, &5_000);
+    assert_eq!(client.get_user_rewards(&user).total_points, 50_000);
+
+    client.deposit_flexi(&user, &100);
+    assert_eq!(client.get_user_rewards(&user).total_points, 51_500);
+}
+
+#[test]
+fn test_tier_fee_discount_and_rate_bonus() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    client.set_savings_tiers(&admin, &default_tiers(&env));
+    client.set_protocol_fee_bps(&1_000); // 10%
+    client.set_flexi_rate(&admin, &400);
+
+    client.deposit_flexi(&user, &1_000);
+    assert_eq!(client.get_flexi_balance(&user), 900);
+    assert_eq!(client.get_effective_rate(&user, &RatePlan::Flexi), 425);
+
+    // Bronze takes 10% off the 10% fee
+    client.deposit_flexi(&user, &1_000);
+    assert_eq!(client.get_flexi_balance(&user), 900 + 910);
+}
+
+#[test]
+fn test_tier_rate_bonus_is_paid_on_lock_withdrawal() {
+    let (env, client, admin, user) = create_test_env();
+    setup_rewards_config(&client, &admin);
+    client.set_savings_tiers(&admin, &default_tiers(&env));
+    client.set_lock_rate(&admin, &365, &525);
+
+    // Gold adds 100 bps to the 525 bps lock rate
+    client.deposit_flexi(&user, &5_000);
+    let lock_year = 31_557_600; // 365.25 days
+    let tiered = client.create_lock_save(&user, &10_000, &lock_year);
+
+    let other = Address::generate(&env);
+    client.init_user(&other);
+    let plain = client.create_lock_save(&other, &10_000, &lock_year);
+
+    env.ledger().with_mut(|li| li.timestamp += lock_year);
+    assert_eq!(client.withdraw_lock_save(&user, &tiered), 10_625);
+    assert_eq!(client.withdraw_lock_save(&other, &plain), 10_525);
+}